@@ -2,10 +2,13 @@ use gpui::prelude::*;
 use gpui::*;
 use gpui_chart::data_types::{ColorOp, PlotPoint};
 use gpui_chart::{
-    chart_view::{ToggleDebug, ToggleCrosshair},
+    chart_view::{
+        AutoFitY, JumpCrosshairLeft, JumpCrosshairRight, StepCrosshairLeft, StepCrosshairRight,
+        ToggleCrosshair, ToggleDebug,
+    },
     data_types::{
-        Annotation, AxisEdge, AxisId, AxisRange, HeatmapCell, PlotData, SharedPlotState,
-        StreamingDataSource,
+        Annotation, AnnotationLayer, AxisEdge, AxisRange, HeatmapCell, LineStyle, PlotData,
+        SharedPlotState, StreamingDataSource, TickCount,
     },
     navigator_view::NavigatorView,
     AnnotationPlot, AreaPlot, AxisState, BarPlot, CandlestickPlot, Chart, ChartView, HeatmapPlot,
@@ -80,13 +83,19 @@ impl DemoApp {
                 x: now - 10.0 * hour_ms,
                 color: gpui::white().alpha(0.5),
                 width: 1.0,
+                style: LineStyle::Dashed,
                 label: Some("Signal".into()),
+                layer: AnnotationLayer::Foreground,
+                affects_autofit: false,
             },
             Annotation::HLine {
                 y: 110.0,
                 color: gpui::blue().alpha(0.5),
                 width: 1.0,
+                style: LineStyle::Solid,
                 label: Some("Resistance".into()),
+                layer: AnnotationLayer::Foreground,
+                affects_autofit: true,
             },
             Annotation::Rect {
                 x_min: now - 30.0 * hour_ms,
@@ -95,6 +104,8 @@ impl DemoApp {
                 y_max: 100.0,
                 color: gpui::green().alpha(0.1),
                 fill: true,
+                layer: AnnotationLayer::Background,
+                affects_autofit: false,
             },
         ];
 
@@ -109,6 +120,7 @@ impl DemoApp {
                     height: 10.0,
                     color: gpui::blue().alpha(0.1 * (ix + iy) as f32),
                     text: None,
+                    value: None,
                 });
             }
         }
@@ -129,8 +141,12 @@ impl DemoApp {
                 label: "Time".into(),
                 format: gpui_chart::data_types::AxisFormat::Time(
                     gpui_chart::data_types::TimeUnit::Milliseconds,
+                    None,
                 ),
                 min_label_spacing: px(20.0),
+                tick_count: TickCount::Auto,
+                derived: None,
+                name: None,
             });
 
             // Pane 1: Price
@@ -147,6 +163,9 @@ impl DemoApp {
                 label: "Price".into(),
                 format: gpui_chart::data_types::AxisFormat::Numeric,
                 min_label_spacing: px(20.0),
+                tick_count: TickCount::Auto,
+                derived: None,
+                name: None,
             });
             p1.series
                 .push(Series::new("Price", CandlestickPlot::new(candles.clone())));
@@ -165,15 +184,14 @@ impl DemoApp {
                 label: "Volume".into(),
                 format: gpui_chart::data_types::AxisFormat::Numeric,
                 min_label_spacing: px(20.0),
+                tick_count: TickCount::Auto,
+                derived: None,
+                name: None,
             });
             p2.series
                 .push(Series::new("Volume", BarPlot::new(volume_data)));
-            p2.series.push(Series {
-                id: "Momentum".into(),
-                plot: momentum_plot.clone(),
-                x_axis_id: AxisId(0),
-                y_axis_id: AxisId(0),
-            });
+            p2.series
+                .push(Series::from_shared("Momentum", momentum_plot.clone()));
             c.panes.push(p2);
 
             // Pane 3: Indicator
@@ -186,6 +204,9 @@ impl DemoApp {
                 label: "Indicator".into(),
                 format: gpui_chart::data_types::AxisFormat::Numeric,
                 min_label_spacing: px(20.0),
+                tick_count: TickCount::Auto,
+                derived: None,
+                name: None,
             });
             p3.series
                 .push(Series::new("Step", StepLinePlot::new(step_data)));
@@ -267,7 +288,7 @@ fn stream_update(
         color_op: ColorOp::None,
     }));
 
-    chart.update(cx, |_, cx| cx.notify());
+    chart.update(cx, |c, cx| c.request_render(cx));
     cx.notify();
 
     let app_entity = cx.entity().clone();
@@ -314,6 +335,11 @@ fn main() {
             KeyBinding::new("cmd-d", ToggleDebug, None),
             KeyBinding::new("ctrl-h", ToggleCrosshair, None),
             KeyBinding::new("cmd-h", ToggleCrosshair, None),
+            KeyBinding::new("f", AutoFitY, None),
+            KeyBinding::new("left", StepCrosshairLeft, None),
+            KeyBinding::new("right", StepCrosshairRight, None),
+            KeyBinding::new("shift-left", JumpCrosshairLeft, None),
+            KeyBinding::new("shift-right", JumpCrosshairRight, None),
         ]);
         cx.open_window(WindowOptions::default(), |window, cx| {
             cx.new(|cx| DemoApp::new(window, cx))
@@ -1,7 +1,10 @@
-use crate::data_types::{AxisEdge, AxisFormat, AxisRange};
+use crate::data_types::{
+    percent_of_range_ticks, visible_tick_labels, AxisEdge, AxisFormat, AxisRange, TickCount,
+};
 use crate::gaps::GapIndex;
 use crate::scales::ChartScale;
 use crate::theme::ChartTheme;
+use crate::transform::snap_line_to_pixel;
 use crate::utils::PixelsExt;
 use gpui::prelude::FluentBuilder;
 use gpui::*;
@@ -10,6 +13,20 @@ use std::sync::Arc;
 pub struct AxisRenderer;
 
 impl AxisRenderer {
+    /// Tick values of an axis, gap-aware. The grid uses the same list so
+    /// that grid lines sit under the labels.
+    pub fn ticks(
+        range: &mut AxisRange,
+        format: &AxisFormat,
+        count: usize,
+        gaps: Option<&GapIndex>,
+    ) -> Vec<f64> {
+        match format {
+            AxisFormat::PercentOfRange => percent_of_range_ticks(range.clamped_bounds(), count),
+            _ => range.ticks(count, gaps).to_vec(),
+        }
+    }
+
     fn paint_axis(
         range: &mut AxisRange,
         is_vertical: bool,
@@ -18,6 +35,7 @@ impl AxisRenderer {
         label: &str,
         format: &AxisFormat,
         min_label_spacing: Pixels,
+        tick_count: TickCount,
         gaps: Option<&GapIndex>,
         bounds: Bounds<Pixels>,
         window: &mut Window,
@@ -44,16 +62,10 @@ impl AxisRenderer {
             bounds.size.width.as_f32()
         };
 
-        // Dynamic density calculation
-        let margin = min_label_spacing.as_f32();
-        let label_size_est = match format {
-            AxisFormat::Time(..) => 80.0 + margin,
-            AxisFormat::Numeric => 50.0 + margin,
-        };
-        let count = (max_px / label_size_est).floor() as usize;
-        let count = count.clamp(2, 20);
+        let count = tick_count.resolve(max_px, format, min_label_spacing.as_f32());
 
-        let ticks = range.ticks(count, gaps);
+        let ticks = Self::ticks(range, format, count, gaps);
+        let ticks = ticks.as_slice();
 
         // 1. Axis Border Line
         // ... (existing code for border line)
@@ -83,8 +95,16 @@ impl AxisRenderer {
         let font_size = theme.axis_label_size;
         let font = TextStyle::default().font();
 
+        // Keep the labels centered on the grid lines when those are snapped
+        let axis_origin = if is_vertical { bounds.origin.y } else { bounds.origin.x }.as_f32();
+        let scale_factor = window.scale_factor();
+        let mut shaped = Vec::with_capacity(ticks.len());
         for tick in ticks {
-            let tick_px = scale.map(*tick) as f32;
+            let mut tick_px = scale.map(*tick) as f32;
+            if theme.snap_to_pixels {
+                let snapped = snap_line_to_pixel(axis_origin + tick_px, 1.0, scale_factor);
+                tick_px = snapped - axis_origin;
+            }
             let tick_text = scale.format_tick(*tick, format);
 
             let run = TextRun {
@@ -96,30 +116,50 @@ impl AxisRenderer {
                 strikethrough: None,
             };
 
-            if let Ok(lines) =
-                window
-                    .text_system()
-                    .shape_text(tick_text.into(), font_size, &[run], None, None)
-            {
-                for line in lines {
-                    let origin = if is_vertical {
-                        let y_centered = px(tick_px) - font_size / 2.0;
-                        let line_width = line.width();
-                        // Center horizontally
-                        let x_text = (bounds.size.width - line_width) / 2.0;
-                        bounds.origin + point(x_text, y_centered)
-                    } else {
-                        // Horizontal: Center on tick horizontally
-                        let line_width = line.width();
-                        let x_centered = px(tick_px) - line_width / 2.0;
-                        // Center vertically
-                        let y_text = (bounds.size.height - font_size) / 2.0;
-                        bounds.origin + point(x_centered, y_text)
-                    };
-
-                    let _ =
-                        line.paint(origin, font_size, TextAlign::Left, Some(bounds), window, cx);
+            let lines = window
+                .text_system()
+                .shape_text(tick_text.into(), font_size, &[run], None, None)
+                .map(|lines| lines.into_vec())
+                .unwrap_or_default();
+            shaped.push((tick_px, lines));
+        }
+
+        // Hide labels that would crowd each other; grid lines still cover every tick
+        let tick_pxs: Vec<f32> = shaped.iter().map(|(p, _)| *p).collect();
+        let extents: Vec<f32> = shaped
+            .iter()
+            .map(|(_, lines)| {
+                if is_vertical {
+                    font_size.as_f32()
+                } else {
+                    lines.iter().map(|l| l.width().as_f32()).fold(0.0, f32::max)
                 }
+            })
+            .collect();
+        let visible =
+            visible_tick_labels(ticks, &tick_pxs, &extents, min_label_spacing.as_f32());
+
+        for ((tick_px, lines), show) in shaped.into_iter().zip(visible) {
+            if !show {
+                continue;
+            }
+            for line in lines {
+                let origin = if is_vertical {
+                    let y_centered = px(tick_px) - font_size / 2.0;
+                    let line_width = line.width();
+                    // Center horizontally
+                    let x_text = (bounds.size.width - line_width) / 2.0;
+                    bounds.origin + point(x_text, y_centered)
+                } else {
+                    // Horizontal: Center on tick horizontally
+                    let line_width = line.width();
+                    let x_centered = px(tick_px) - line_width / 2.0;
+                    // Center vertically
+                    let y_text = (bounds.size.height - font_size) / 2.0;
+                    bounds.origin + point(x_centered, y_text)
+                };
+
+                let _ = line.paint(origin, font_size, TextAlign::Left, Some(bounds), window, cx);
             }
         }
 
@@ -179,10 +219,6 @@ impl AxisRenderer {
 
         size: Pixels,
 
-        h_pct: f32,
-
-        current_top_pct: f32,
-
         x_pos: Pixels,
 
         label: String,
@@ -191,6 +227,8 @@ impl AxisRenderer {
 
         min_label_spacing: Pixels,
 
+        tick_count: TickCount,
+
         theme: &ChartTheme,
 
         gaps: Option<Arc<GapIndex>>,
@@ -209,8 +247,8 @@ impl AxisRenderer {
                 pane_idx, axis_idx
             )))
             .absolute()
-            .top(relative(current_top_pct))
-            .h(relative(h_pct))
+            .top_0()
+            .h_full()
             .w(size)
             .when(is_left, |d| d.left(x_pos))
             .when(!is_left, |d| d.right(x_pos))
@@ -227,6 +265,7 @@ impl AxisRenderer {
                             &label,
                             &format,
                             min_label_spacing,
+                            tick_count,
                             gaps.as_deref(),
                             bounds,
                             window,
@@ -258,6 +297,8 @@ impl AxisRenderer {
 
         min_label_spacing: Pixels,
 
+        tick_count: TickCount,
+
         theme: &ChartTheme,
 
         gaps: Option<Arc<GapIndex>>,
@@ -297,6 +338,7 @@ impl AxisRenderer {
                                 &label,
                                 &format,
                                 min_label_spacing,
+                                tick_count,
                                 gaps.as_deref(),
                                 bounds,
                                 window,
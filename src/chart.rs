@@ -7,12 +7,69 @@
 //! As a GPUI Entity, it can be updated from any context and notifies its observers
 //! (like `ChartView`) of any changes.
 
-use crate::data_types::{AxisEdge, AxisFormat, AxisId, AxisRange, SharedPlotState};
-use crate::theme::ChartTheme;
+use crate::data_types::{
+    AggregationMode, Annotation, AnnotationId, AnnotationLayer, AxisEdge, AxisFormat, AxisId, AxisRange, CrosshairScope, DataOverflow, EmphasisConfig, FollowWindow, HoverReadout, LineStyle, PlotData, RenderHistory,
+    RenderStats, SharedPlotState, TickCount,
+};
+use crate::spread_source::{SpreadDataSource, SpreadOp};
+use crate::theme::{ChartTheme, GridVisibility};
+use crate::view_controller::ViewController;
 use crate::Series;
 use gpui::*;
 use std::collections::HashSet;
 
+/// Mapping between two axis value spaces (e.g. °C -> °F).
+pub type AxisMapping = std::sync::Arc<dyn Fn(f64) -> f64 + Send + Sync>;
+
+/// (source, derived) domains written by the last `sync_derived_axes`.
+type SyncedDomains = Option<((f64, f64), (f64, f64))>;
+
+/// `(a, b)` as a (min, max) domain.
+fn ordered(a: f64, b: f64) -> (f64, f64) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// A Y axis that mirrors another axis of the same pane through a transform.
+/// No series are plotted on it: its domain is recomputed from the source
+/// axis whenever the chart is notified, and dragging it moves the source
+/// through `inverse`. A decreasing mapping gets its bounds swapped.
+#[derive(Clone)]
+pub struct DerivedAxis {
+    /// Index of the source axis in the pane's `y_axes`
+    pub source_axis: usize,
+    pub forward: AxisMapping,
+    pub inverse: AxisMapping,
+    synced: std::sync::Arc<parking_lot::RwLock<SyncedDomains>>,
+}
+
+impl DerivedAxis {
+    pub fn new(
+        source_axis: usize,
+        forward: impl Fn(f64) -> f64 + Send + Sync + 'static,
+        inverse: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            source_axis,
+            forward: std::sync::Arc::new(forward),
+            inverse: std::sync::Arc::new(inverse),
+            synced: Default::default(),
+        }
+    }
+
+    /// `derived = source * scale + offset`. `scale` must not be zero.
+    pub fn affine(source_axis: usize, scale: f64, offset: f64) -> Self {
+        Self::new(
+            source_axis,
+            move |v| v * scale + offset,
+            move |v| (v - offset) / scale,
+        )
+    }
+}
+
 #[derive(Clone)]
 pub struct AxisState {
     pub entity: Entity<AxisRange>,
@@ -21,6 +78,12 @@ pub struct AxisState {
     pub label: String,
     pub format: AxisFormat,
     pub min_label_spacing: Pixels,
+    pub tick_count: TickCount,
+    /// Set for axes mirroring another axis (see `Chart::add_derived_y_axis`).
+    pub derived: Option<DerivedAxis>,
+    /// Stable name series can refer to with `Series::on_named_axis`, see
+    /// `Chart::set_y_axis_name`.
+    pub name: Option<String>,
 }
 
 impl AxisState {
@@ -32,6 +95,9 @@ impl AxisState {
             label,
             format: AxisFormat::Numeric,
             min_label_spacing: px(20.0),
+            tick_count: TickCount::Auto,
+            derived: None,
+            name: None,
         }
     }
 }
@@ -40,9 +106,23 @@ impl AxisState {
 pub struct PaneState {
     pub id: String,
     pub weight: f32,
+    /// Height in pixels overriding `weight`, see `Chart::set_pane_fixed_height`.
+    pub fixed_height: Option<Pixels>,
     pub y_axes: Vec<AxisState>,
     pub series: Vec<Series>,
     pub hidden_series: HashSet<String>,
+    /// Value of the first Y axis drawn as a reference line across the pane
+    /// (e.g. 0 for oscillators), see `Chart::set_pane_baseline`.
+    pub baseline: Option<f64>,
+    /// X axis of this pane when it does not follow `Chart::shared_x_axis`,
+    /// see `Chart::set_pane_x_axis`.
+    pub x_axis: Option<Entity<AxisRange>>,
+    /// Annotations managed through `Chart::add_annotation`, painted by the
+    /// pane's `ANNOTATIONS_SERIES_ID` series.
+    pub annotations: Vec<(AnnotationId, Annotation)>,
+    /// HLine annotations whose value is tagged on the Y axis of the
+    /// annotations series, see `Chart::add_price_level`.
+    pub price_levels: Vec<AnnotationId>,
 
     /// Local interaction states
     pub drag_start: Option<Point<Pixels>>,
@@ -57,9 +137,14 @@ impl PaneState {
         Self {
             id,
             weight,
+            fixed_height: None,
             y_axes: vec![],
             series: vec![],
             hidden_series: HashSet::new(),
+            baseline: None,
+            x_axis: None,
+            annotations: vec![],
+            price_levels: vec![],
             drag_start: None,
             initial_drag_start: None,
             drag_button: None,
@@ -67,6 +152,130 @@ impl PaneState {
             last_drag_time: None,
         }
     }
+
+    /// X axis driving this pane: its own one if set, `shared` otherwise.
+    pub fn x_axis_or(&self, shared: &Entity<AxisRange>) -> Entity<AxisRange> {
+        self.x_axis.clone().unwrap_or_else(|| shared.clone())
+    }
+
+    /// Recomputes the domain of every derived Y axis from its source axis.
+    /// When only the derived axis was moved since the last sync (e.g. dragged
+    /// by the user), the source follows it through the inverse mapping.
+    pub fn sync_derived_axes(&self, cx: &mut App) {
+        for axis in &self.y_axes {
+            let Some(derived) = &axis.derived else {
+                continue;
+            };
+            let Some(source) = self.y_axes.get(derived.source_axis) else {
+                continue;
+            };
+            let range = |r: &AxisRange| (r.min, r.max);
+            let mut src = range(source.entity.read(cx));
+            let current = range(axis.entity.read(cx));
+            let last = *derived.synced.read();
+
+            if let Some((last_src, last_derived)) = last {
+                if src == last_src && current != last_derived {
+                    src = ordered((derived.inverse)(current.0), (derived.inverse)(current.1));
+                    if src.0.is_finite() && src.1.is_finite() {
+                        source.entity.update(cx, |r, _| {
+                            r.min = src.0;
+                            r.max = src.1;
+                            r.cached_ticks.clear();
+                        });
+                    } else {
+                        src = last_src;
+                    }
+                }
+            }
+
+            let target = ordered((derived.forward)(src.0), (derived.forward)(src.1));
+            if !(target.0.is_finite() && target.1.is_finite()) {
+                continue;
+            }
+            if target != current {
+                axis.entity.update(cx, |r, _| {
+                    r.min = target.0;
+                    r.max = target.1;
+                    r.cached_ticks.clear();
+                });
+            }
+            *derived.synced.write() = Some((src, target));
+        }
+    }
+
+    /// Index of the Y axis named `name`.
+    pub fn y_axis_by_name(&self, name: &str) -> Option<usize> {
+        self.y_axes
+            .iter()
+            .position(|a| a.name.as_deref() == Some(name))
+    }
+
+    /// Points every series attached with `Series::on_named_axis` at the
+    /// current index of its axis. Series whose name matches no axis keep
+    /// their index.
+    pub fn resolve_named_axes(&mut self) {
+        for i in 0..self.series.len() {
+            let Some(name) = &self.series[i].y_axis_name else {
+                continue;
+            };
+            if let Some(idx) = self.y_axis_by_name(name) {
+                self.series[i].y_axis_id = AxisId(idx);
+            }
+        }
+    }
+
+    /// Union of the Y ranges of the series on `axis_idx` within `x_min..x_max`.
+    /// Hidden series are skipped unless `include_hidden`, as are non-finite
+    /// ranges.
+    pub fn y_range_on_axis(
+        &self,
+        axis_idx: usize,
+        x_min: f64,
+        x_max: f64,
+        include_hidden: bool,
+    ) -> Option<(f64, f64)> {
+        self.series
+            .iter()
+            .filter(|s| s.y_axis_id.0 == axis_idx)
+            .filter(|s| include_hidden || !self.hidden_series.contains(&s.id))
+            .filter_map(|s| s.plot.read().get_y_range(x_min, x_max))
+            .filter(|(lo, hi)| lo.is_finite() && hi.is_finite())
+            .reduce(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)))
+    }
+
+    /// Union of the full data bounds (x_min, x_max, y_min, y_max) of the
+    /// series on `axis_idx` (every axis if None). Hidden series are skipped
+    /// unless `include_hidden`, as are non-finite bounds.
+    pub fn data_bounds(
+        &self,
+        axis_idx: Option<usize>,
+        include_hidden: bool,
+    ) -> Option<(f64, f64, f64, f64)> {
+        self.series
+            .iter()
+            .filter(|s| axis_idx.is_none_or(|a| s.y_axis_id.0 == a))
+            .filter(|s| include_hidden || !self.hidden_series.contains(&s.id))
+            .filter_map(|s| s.plot.read().get_min_max())
+            .filter(|b| [b.0, b.1, b.2, b.3].iter().all(|v| v.is_finite()))
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1), a.2.min(b.2), a.3.max(b.3)))
+    }
+
+    /// Whether any series of the pane has data to show.
+    pub fn has_data(&self) -> bool {
+        self.series.iter().any(|s| s.plot.read().get_min_max().is_some())
+    }
+}
+
+/// Ranges an auto-fit would give the axes, see `Chart::compute_autofit_bounds`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AutoFitBounds {
+    /// Shared X axis, None when no pane on it has data
+    pub x: Option<(f64, f64)>,
+    /// X axes of the panes that have their own: (pane index, range)
+    pub pane_x: Vec<(usize, (f64, f64))>,
+    /// Y axes fitted over the new X window: (pane index, axis index, range)
+    pub y: Vec<(usize, usize, (f64, f64))>,
 }
 
 #[derive(Clone, Debug)]
@@ -78,46 +287,239 @@ pub struct AxisDragInfo {
     pub pivot_pct: f64,
 }
 
+/// Annotation being moved with the mouse (see `Chart::begin_annotation_drag`).
+#[derive(Clone, Debug)]
+pub struct AnnotationDrag {
+    pub id: AnnotationId,
+    pub pane_id: String,
+    pub last_pos: Point<Pixels>,
+    /// The annotation moved since the drag started.
+    pub moved: bool,
+}
+
+/// Events emitted by the `Chart` entity. Subscribe with `cx.subscribe(&chart, ...)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChartEvent {
+    /// The selected series changed (`None` when the selection was cleared).
+    SelectionChanged(Option<String>),
+    /// A series was removed (`remove_series_by_id`, legend ✕) from the pane
+    /// at `pane_idx`.
+    SeriesRemoved { series_id: String, pane_idx: usize },
+    /// A series moved to another pane (`move_series`, legend ▲/▼).
+    SeriesMoved {
+        series_id: String,
+        from_pane: usize,
+        to_pane: usize,
+    },
+    /// A series got its own Y axis (`isolated`) or went back to the pane's
+    /// main axis (`toggle_series_isolation`, legend S).
+    SeriesIsolationToggled {
+        series_id: String,
+        pane_idx: usize,
+        isolated: bool,
+    },
+    /// A splitter drag changed the pane sizes, reported once the mouse is
+    /// released. `weights` are the pane weights in pane order, normalized to
+    /// sum to 1; `fixed_heights` the heights of fixed panes (see
+    /// `Chart::set_pane_fixed_height`). Hosts can store both to restore the
+    /// layout.
+    PanesResized {
+        weights: Vec<f32>,
+        fixed_heights: Vec<Option<Pixels>>,
+    },
+    /// A mouse press landed on an annotation (see
+    /// `Chart::set_annotation_hit_tolerance`).
+    AnnotationSelected(AnnotationId),
+    /// A drag moved an annotation, reported once the mouse is released.
+    /// Read the new position with `Chart::annotation`.
+    AnnotationMoved(AnnotationId),
+}
+
 pub struct Chart {
     pub shared_x_axis: Entity<AxisRange>,
     pub shared_state: Entity<SharedPlotState>,
     pub panes: Vec<PaneState>,
     pub x_axes: Vec<AxisState>,
     pub theme: ChartTheme,
+    /// Series selected by a click; it stays emphasized until cleared.
+    pub selected_series: Option<String>,
+    /// Series under the cursor (or its legend entry) in this chart.
+    pub hovered_series: Option<String>,
+    /// Stop zooming out once all data is visible (see `set_snap_zoom_to_data`).
+    pub snap_zoom_to_data: bool,
+    /// Padding around the data when fitting an axis, as a fraction of the
+    /// data span (see `set_autofit_padding`).
+    pub autofit_padding: f64,
+    /// Data is still being fetched: panes show "Loading…" instead of their
+    /// content (see `set_loading`).
+    pub loading: bool,
+    /// Shows the move/add/close buttons in the corner of each pane
+    /// (see `set_show_pane_controls`).
+    pub show_pane_controls: bool,
+    /// Compact inline mode: series fill the whole bounds with no axes, legend,
+    /// controls or crosshair, and the view is refitted on every update (see
+    /// `Chart::sparkline`).
+    pub sparkline: bool,
+    /// Space around the axes, added to the axis-derived gutters (see
+    /// `set_plot_padding`).
+    pub plot_padding: Edges<Pixels>,
+    /// Window kept on the newest data by `data_appended` (see `set_follow_latest`).
+    pub follow_latest: Option<FollowWindow>,
+    /// False once the user moved the view (`user_interacted`);
+    /// `resume_following` turns it back on.
+    pub following: bool,
+    /// Fit the view once, when `data_appended` first sees data (see
+    /// `set_auto_fit_on_first_data`).
+    pub auto_fit_on_first_data: bool,
+    /// The one-time fit of `auto_fit_on_first_data` happened.
+    first_data_fitted: bool,
+    /// The view was last moved by the user (drag, wheel, box zoom, keyboard)
+    /// rather than fitted: set by the input handlers, cleared by
+    /// `fit_to_data`, `fit_y_to_visible_x`, `fit_to_series` and axis
+    /// double-click fits. Lets hosts keep a live view following new data
+    /// unless the user took over.
+    pub user_interacted: bool,
+    /// Draw chevrons at the pane edges beyond which series have data (see
+    /// `set_show_overflow_indicators`).
+    pub show_overflow_indicators: bool,
+    /// A coalesced `request_render` is scheduled.
+    render_pending: bool,
+    /// Next id handed out by `add_annotation`.
+    next_annotation_id: u64,
+    /// Max distance (pixels) between a press and an annotation for the press
+    /// to grab it; None turns annotation hit-testing off (see
+    /// `set_annotation_hit_tolerance`).
+    pub annotation_hit_tolerance: Option<f32>,
+    /// Filled by the renderer on every frame, read by `render_stats`.
+    pub(crate) render_history: std::sync::Arc<parking_lot::RwLock<RenderHistory>>,
 
     pub dragging_splitter: Option<usize>,
+    /// Pane sizes when the current splitter drag started.
+    splitter_drag_start: Option<(Vec<f32>, Vec<Option<Pixels>>)>,
     pub dragging_axis: Option<AxisDragInfo>,
+    pub dragging_annotation: Option<AnnotationDrag>,
     pub last_mouse_pos: Option<Point<Pixels>>,
     pub last_mouse_y: Option<Pixels>,
 }
 
+/// ID of the series painting the annotations added with `Chart::add_annotation`.
+pub const ANNOTATIONS_SERIES_ID: &str = "Annotations";
+
+/// Minimum delay between two updates triggered by `Chart::request_render` (~60 fps).
+pub const RENDER_COALESCE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(16);
+
 impl Chart {
+    /// Creates a chart driving `shared_x_axis` and `shared_state`.
+    ///
+    /// The shared state is (re)initialized with the default theme and the crosshair
+    /// enabled. To add a chart to a group that already shares a state, use
+    /// [`Chart::with_shared_state`] instead.
     pub fn new(
         shared_x_axis: Entity<AxisRange>,
         shared_state: Entity<SharedPlotState>,
         cx: &mut Context<Self>,
     ) -> Self {
-        cx.observe(&shared_x_axis, |_, _, cx| cx.notify()).detach();
-
         let theme = ChartTheme::default();
         shared_state.update(cx, |s, _| {
             s.theme = theme.clone();
             s.crosshair_enabled = true;
+            s.tooltip_enabled = true;
         });
 
+        Self::with_shared_state(shared_x_axis, shared_state, cx)
+    }
+
+    /// Creates a chart joining an existing group: the state is used as-is and the
+    /// chart adopts its theme.
+    ///
+    /// Everything in `SharedPlotState` is shared by all charts holding it: hover and
+    /// crosshair position, gaps, but also the theme, the debug overlay and the
+    /// emphasis/selection. Give a chart its own state to keep those separate; the X
+    /// axis entity can be shared independently to only synchronize panning and zoom.
+    pub fn with_shared_state(
+        shared_x_axis: Entity<AxisRange>,
+        shared_state: Entity<SharedPlotState>,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        cx.observe(&shared_x_axis, |_, _, cx| cx.notify()).detach();
+        // Every change notifies the chart: bring the axes in line with it
+        // before the next render
+        cx.observe_self(|chart, cx| {
+            chart.resolve_named_axes();
+            chart.sync_derived_axes(cx);
+        })
+        .detach();
+
+        let theme = shared_state.read(cx).theme.clone();
+
         Self {
             shared_x_axis,
             shared_state,
             panes: vec![],
             x_axes: vec![],
             theme,
+            selected_series: None,
+            hovered_series: None,
+            snap_zoom_to_data: false,
+            autofit_padding: 0.05,
+            loading: false,
+            show_pane_controls: true,
+            sparkline: false,
+            plot_padding: Edges::default(),
+            follow_latest: None,
+            following: false,
+            auto_fit_on_first_data: false,
+            first_data_fitted: false,
+            show_overflow_indicators: false,
+            user_interacted: false,
+            render_pending: false,
+            next_annotation_id: 0,
+            annotation_hit_tolerance: Some(5.0),
+            render_history: Default::default(),
             dragging_splitter: None,
+            splitter_drag_start: None,
             dragging_axis: None,
+            dragging_annotation: None,
             last_mouse_pos: None,
             last_mouse_y: None,
         }
     }
 
+    /// Creates a chart with its own X axis over `x_min..x_max` and its own shared
+    /// state, for a chart that isn't part of a group. It still needs an `App`;
+    /// the logic that runs without one lives on `PaneState` and `ViewController`.
+    pub fn standalone(x_min: f64, x_max: f64, cx: &mut App) -> Entity<Self> {
+        let shared_x_axis = cx.new(|_| AxisRange::new(x_min, x_max));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Self::new(shared_x_axis, shared_state, cx))
+    }
+
+    /// Creates a chart in sparkline mode, for micro-charts embedded in table
+    /// cells or tooltips (see `set_sparkline`). Add panes and series as usual.
+    pub fn sparkline(
+        shared_x_axis: Entity<AxisRange>,
+        shared_state: Entity<SharedPlotState>,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let mut chart = Self::new(shared_x_axis, shared_state, cx);
+        chart.set_sparkline(true, cx);
+        chart
+    }
+
+    /// Switches sparkline mode: no axes, gutters, legend, pane controls,
+    /// splitters or crosshair, no mouse or keyboard interaction, and the view
+    /// is fitted to the data now and whenever data is appended
+    /// (`data_appended`, `request_render`). Turning it off restores the pane
+    /// controls and the crosshair. Charts sharing the state are unaffected.
+    pub fn set_sparkline(&mut self, sparkline: bool, cx: &mut Context<Self>) {
+        self.sparkline = sparkline;
+        self.show_pane_controls = !sparkline;
+        if sparkline {
+            self.fit_all(cx);
+        }
+        self.notify_render(cx);
+    }
+
     pub fn add_pane_at(&mut self, idx: usize, weight: f32, cx: &mut Context<Self>) {
         let id = format!(
             "new_pane_{}",
@@ -126,21 +528,61 @@ impl Chart {
                 .unwrap()
                 .as_millis()
         );
-        let mut ps = PaneState::new(id.clone(), weight);
+        let ps = Self::new_pane(id, weight, "New", cx);
+        if idx >= self.panes.len() {
+            self.panes.push(ps);
+        } else {
+            self.panes.insert(idx, ps);
+        }
+        self.notify_render(cx);
+    }
+
+    /// Empty pane with a single Y axis on the right.
+    fn new_pane(id: String, weight: f32, label: &str, cx: &mut Context<Self>) -> PaneState {
+        let mut ps = PaneState::new(id, weight);
         let default_y = cx.new(|_| AxisRange::new(0.0, 100.0));
         ps.y_axes.push(AxisState::new(
             default_y,
             AxisEdge::Right,
             px(60.0),
-            "New".to_string(),
+            label.to_string(),
         ));
+        ps
+    }
 
-        if idx >= self.panes.len() {
-            self.panes.push(ps);
-        } else {
-            self.panes.insert(idx, ps);
+    /// Adds a pane below the others plotting `op` applied to two existing
+    /// series (e.g. their spread), on the shared X axis. The derived series
+    /// follows both inputs as they stream; see `SpreadDataSource` for how
+    /// their X samples are aligned. Returns the id of the new pane, or None if
+    /// either series does not exist or the same spread already has a pane.
+    pub fn add_spread_pane(
+        &mut self,
+        a_id: &str,
+        b_id: &str,
+        op: SpreadOp,
+        cx: &mut Context<Self>,
+    ) -> Option<String> {
+        let find = |id: &str| {
+            self.panes
+                .iter()
+                .flat_map(|p| &p.series)
+                .find(|s| s.id == id)
+                .map(|s| s.plot.clone())
+        };
+        let (a, b) = (find(a_id)?, find(b_id)?);
+        let label = op.label(a_id, b_id);
+        let pane_id = format!("spread:{label}");
+        if self.panes.iter().any(|p| p.id == pane_id) {
+            return None;
         }
+        let mut ps = Self::new_pane(pane_id.clone(), 1.0, &label, cx);
+        let source = SpreadDataSource::new(a, b, op);
+        ps.series
+            .push(Series::new(label, crate::LinePlot::with_source(Box::new(source))));
+        self.panes.push(ps);
+        self.fit_pane_y(self.panes.len() - 1, cx);
         self.notify_render(cx);
+        Some(pane_id)
     }
 
     pub fn remove_pane_by_id(&mut self, id: String, cx: &mut Context<Self>) {
@@ -148,11 +590,124 @@ impl Chart {
         self.notify_render(cx);
     }
 
+    /// Removes the series from every pane; emits `ChartEvent::SeriesRemoved`
+    /// for each pane it was in.
     pub fn remove_series_by_id(&mut self, id: String, cx: &mut Context<Self>) {
-        for pane in &mut self.panes {
+        let mut removed_from = Vec::new();
+        for (pane_idx, pane) in self.panes.iter_mut().enumerate() {
+            let before = pane.series.len();
             pane.series.retain(|s| s.id != id);
+            if pane.series.len() != before {
+                removed_from.push(pane_idx);
+            }
+        }
+        if self.selected_series.as_deref() == Some(id.as_str()) {
+            self.set_selected_series(None, cx);
+        }
+        self.notify_render(cx);
+        for pane_idx in removed_from {
+            cx.emit(ChartEvent::SeriesRemoved {
+                series_id: id.clone(),
+                pane_idx,
+            });
+        }
+    }
+
+    /// Removes every series, annotation and price level from every pane and
+    /// resets the axes to 0..1 (limits and formats are kept), for switching
+    /// datasets: panes then show the empty-state placeholder. The view is treated as never fitted, so
+    /// `set_auto_fit_on_first_data` fits the next data again. Emits
+    /// `ChartEvent::SeriesRemoved` for each removed series. Use `Series::clear`
+    /// to empty a series but keep it.
+    pub fn clear_series(&mut self, cx: &mut Context<Self>) {
+        let mut removed = Vec::new();
+        for (pane_idx, pane) in self.panes.iter_mut().enumerate() {
+            for series in pane.series.drain(..) {
+                removed.push((series.id, pane_idx));
+            }
+            pane.hidden_series.clear();
+            pane.annotations.clear();
+            pane.price_levels.clear();
+        }
+        self.dragging_annotation = None;
+        self.set_selected_series(None, cx);
+
+        let y_axes = self.panes.iter().flat_map(|p| p.y_axes.iter().map(|a| a.entity.clone()));
+        let axes: Vec<_> = self.x_axis_entities().into_iter().chain(y_axes).collect();
+        for axis in axes {
+            axis.update(cx, |r, _| {
+                r.min = 0.0;
+                r.max = 1.0;
+                r.data_extent = None;
+                r.clamp();
+                r.update_ticks_if_needed(10, None);
+            });
         }
+        self.user_interacted = false;
+        self.first_data_fitted = false;
+        self.following = self.follow_latest.is_some();
+
+        self.notify_render(cx);
+        for (series_id, pane_idx) in removed {
+            cx.emit(ChartEvent::SeriesRemoved {
+                series_id,
+                pane_idx,
+            });
+        }
+    }
+
+    /// Pins a pane to `height` pixels, or back to its weight with None. Fixed
+    /// panes are laid out first and the weighted panes share the remaining
+    /// height. Returns false if the pane does not exist.
+    pub fn set_pane_fixed_height(
+        &mut self,
+        pane_idx: usize,
+        height: Option<Pixels>,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(pane) = self.panes.get_mut(pane_idx) else {
+            return false;
+        };
+        pane.fixed_height = height;
         self.notify_render(cx);
+        true
+    }
+
+    /// Starts dragging the splitter below pane `index` from `y`.
+    pub fn begin_splitter_drag(&mut self, index: usize, y: Pixels) {
+        self.dragging_splitter = Some(index);
+        self.last_mouse_y = Some(y);
+        self.splitter_drag_start = Some(self.pane_sizes());
+    }
+
+    /// Ends a splitter drag, emitting `ChartEvent::PanesResized` if it
+    /// changed the pane sizes.
+    pub fn end_splitter_drag(&mut self, cx: &mut Context<Self>) {
+        self.dragging_splitter = None;
+        self.last_mouse_y = None;
+        let Some(start) = self.splitter_drag_start.take() else {
+            return;
+        };
+        let (weights, fixed_heights) = self.pane_sizes();
+        if (weights.clone(), fixed_heights.clone()) == start {
+            return;
+        }
+        let total: f32 = weights.iter().sum();
+        let weights = weights
+            .iter()
+            .map(|w| if total > 0.0 { w / total } else { 0.0 })
+            .collect();
+        cx.emit(ChartEvent::PanesResized {
+            weights,
+            fixed_heights,
+        });
+    }
+
+    fn pane_sizes(&self) -> (Vec<f32>, Vec<Option<Pixels>>) {
+        (
+            self.panes.iter().map(|p| p.weight).collect(),
+            self.panes.iter().map(|p| p.fixed_height).collect(),
+        )
     }
 
     pub fn move_pane_up(&mut self, idx: usize, cx: &mut Context<Self>) {
@@ -189,6 +744,7 @@ impl Chart {
         }
 
         // 2. Insert into destination
+        let moved = series_to_move.is_some();
         if let Some(series) = series_to_move {
             if let Some(dst_pane) = self.panes.get_mut(to_idx) {
                 dst_pane.series.push(series);
@@ -196,6 +752,13 @@ impl Chart {
         }
 
         self.notify_render(cx);
+        if moved {
+            cx.emit(ChartEvent::SeriesMoved {
+                series_id: series_id.to_string(),
+                from_pane: from_idx,
+                to_pane: to_idx,
+            });
+        }
     }
 
     pub fn toggle_series_isolation(
@@ -207,9 +770,15 @@ impl Chart {
         if let Some(ps) = self.panes.get_mut(pane_idx) {
             let mut current_y = 0;
             // Find current axis
-            if let Some(s) = ps.series.iter().find(|s| s.id == series_id) {
+            let found = ps.series.iter().find(|s| s.id == series_id);
+            if let Some(s) = found {
                 current_y = s.y_axis_id.0;
             }
+            let event = found.map(|_| ChartEvent::SeriesIsolationToggled {
+                series_id: series_id.to_string(),
+                pane_idx,
+                isolated: current_y == 0,
+            });
 
             if current_y == 0 {
                 // Isolate
@@ -234,17 +803,26 @@ impl Chart {
 
                 if let Some(s) = ps.series.iter_mut().find(|s| s.id == series_id) {
                     s.y_axis_id = new_axis_id;
+                    s.y_axis_name = None;
                 }
             } else {
                 // Reintegrate to Axis 0
                 if let Some(s) = ps.series.iter_mut().find(|s| s.id == series_id) {
                     s.y_axis_id = AxisId(0);
+                    s.y_axis_name = None;
                 }
 
-                // Clean up orphaned axes
+                // Clean up orphaned axes, keeping those series refer to by name
                 let mut axes_to_remove = Vec::new();
                 for i in 1..ps.y_axes.len() {
-                    let in_use = ps.series.iter().any(|s| s.y_axis_id.0 == i);
+                    let name = ps.y_axes[i].name.as_deref();
+                    let in_use = ps.series.iter().any(|s| s.y_axis_id.0 == i)
+                        || ps.series.iter().any(|s| name.is_some() && s.y_axis_name() == name)
+                        || ps.y_axes[i].derived.is_some()
+                        || ps
+                            .y_axes
+                            .iter()
+                            .any(|a| a.derived.as_ref().is_some_and(|d| d.source_axis == i));
                     if !in_use {
                         axes_to_remove.push(i);
                     }
@@ -257,9 +835,18 @@ impl Chart {
                             s.y_axis_id.0 -= 1;
                         }
                     }
+                    for d in ps.y_axes.iter_mut().filter_map(|a| a.derived.as_mut()) {
+                        if d.source_axis > idx {
+                            d.source_axis -= 1;
+                        }
+                    }
                 }
+                ps.resolve_named_axes();
             }
             self.notify_render(cx);
+            if let Some(event) = event {
+                cx.emit(event);
+            }
         }
     }
 
@@ -297,68 +884,1096 @@ impl Chart {
         self.notify_render(cx);
     }
 
+    /// Selects the grid lines drawn (`ChartTheme::with_grid`), keeping the rest
+    /// of the theme.
+    pub fn set_grid_visibility(&mut self, grid: GridVisibility, cx: &mut Context<Self>) {
+        let mut theme = self.theme.clone();
+        theme.grid = grid;
+        self.set_theme(theme, cx);
+    }
+
+    /// Snaps grid lines and axis ticks to device pixels
+    /// (`ChartTheme::with_snap_to_pixels`), keeping the rest of the theme.
+    pub fn set_snap_to_pixels(&mut self, snap: bool, cx: &mut Context<Self>) {
+        let mut theme = self.theme.clone();
+        theme.snap_to_pixels = snap;
+        self.set_theme(theme, cx);
+    }
+
     pub fn set_crosshair_enabled(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        self.set_crosshair_visible(enabled, cx);
+    }
+
+    /// Shows or hides the crosshair of every chart sharing this chart's
+    /// state. Same switch as the `ToggleCrosshair` action; hiding it also
+    /// drops the hover position and a keyboard-pinned crosshair.
+    pub fn set_crosshair_visible(&mut self, visible: bool, cx: &mut Context<Self>) {
+        self.shared_state.update(cx, |s, _| {
+            s.crosshair_enabled = visible;
+            if !visible {
+                s.hover_x = None;
+                s.mouse_pos = None;
+                s.crosshair_pinned = false;
+            }
+        });
+        self.notify_render(cx);
+    }
+
+    pub fn crosshair_visible(&self, cx: &App) -> bool {
+        self.shared_state.read(cx).crosshair_enabled
+    }
+
+    /// Shows or hides the value readout drawn next to the crosshair. The
+    /// crosshair itself is controlled by `set_crosshair_visible`.
+    pub fn set_tooltip_visible(&mut self, visible: bool, cx: &mut Context<Self>) {
+        self.shared_state.update(cx, |s, _| s.tooltip_enabled = visible);
+        self.notify_render(cx);
+    }
+
+    pub fn tooltip_visible(&self, cx: &App) -> bool {
+        self.shared_state.read(cx).tooltip_enabled
+    }
+
+    /// Shows the value readout while the mouse hovers the chart, in the
+    /// hovered pane or in all panes at the hovered X (`HoverReadout`).
+    pub fn set_hover_readout(&mut self, mode: HoverReadout, cx: &mut Context<Self>) {
+        self.shared_state.update(cx, |s, _| s.hover_readout = mode);
+        self.notify_render(cx);
+    }
+
+    pub fn hover_readout(&self, cx: &App) -> HoverReadout {
+        self.shared_state.read(cx).hover_readout
+    }
+
+    /// Draws the crosshair on every chart sharing this chart's state, or
+    /// only on the active one (`CrosshairScope`).
+    pub fn set_crosshair_scope(&mut self, scope: CrosshairScope, cx: &mut Context<Self>) {
+        self.shared_state.update(cx, |s, _| s.crosshair_scope = scope);
+        self.notify_render(cx);
+    }
+
+    pub fn crosshair_scope(&self, cx: &App) -> CrosshairScope {
+        self.shared_state.read(cx).crosshair_scope
+    }
+
+    /// Entity ID of the chart last hovered or stepped with the keyboard,
+    /// among those sharing this chart's state, e.g. to route keyboard
+    /// actions to it.
+    pub fn active_chart_id(&self, cx: &App) -> Option<EntityId> {
+        self.shared_state.read(cx).active_chart_id
+    }
+
+    pub fn set_emphasis_config(&mut self, config: EmphasisConfig, cx: &mut Context<Self>) {
+        if !config.enabled {
+            self.hovered_series = None;
+        }
+        self.shared_state.update(cx, |s, _| s.emphasis = config);
+        self.notify_render(cx);
+    }
+
+    /// Moves the crosshair `steps` data points to the right (positive) or left
+    /// (negative), across all visible series, and pins it so it stays visible
+    /// without a mouse. Scrolls the X axis if the new point is off-screen.
+    pub fn step_crosshair(&mut self, steps: isize, cx: &mut Context<Self>) {
+        let state = self.shared_state.read(cx);
+        if !state.crosshair_enabled || steps == 0 {
+            return;
+        }
+        let x_range = self.shared_x_axis.read(cx);
+        let (view_min, view_max) = x_range.clamped_bounds();
+        let mut x = state.hover_x.unwrap_or((view_min + view_max) / 2.0);
+        let direction = steps.signum();
+
+        for _ in 0..steps.unsigned_abs() {
+            let next = self
+                .panes
+                .iter()
+                .flat_map(|ps| {
+                    ps.series
+                        .iter()
+                        .filter(|s| !ps.hidden_series.contains(&s.id))
+                })
+                .filter_map(|s| s.plot.read().data_source()?.step_x(x, direction))
+                .reduce(|a, b| if direction > 0 { a.min(b) } else { a.max(b) });
+            match next {
+                Some(n) => x = n,
+                None => break,
+            }
+        }
+
+        if x < view_min || x > view_max {
+            self.shared_x_axis.update(cx, |r, _| {
+                ViewController::move_to_center(r, x, None);
+            });
+        }
+        let chart_id = cx.entity_id();
         self.shared_state.update(cx, |s, _| {
-            s.crosshair_enabled = enabled;
+            s.hover_x = Some(x);
+            s.crosshair_pinned = true;
+            s.active_chart_id = Some(chart_id);
+        });
+        self.notify_render(cx);
+    }
+
+    /// Keeps the X axis on the newest data as it is appended, or stops with
+    /// `None`. Panes with their own X axis (see `set_pane_x_axis`) keep it
+    /// on their own newest data. Following pauses as soon as the user moves the view (see
+    /// `user_interacted`; hosts moving the axis from their own controls, e.g.
+    /// a navigator, set it too) until `resume_following` is called.
+    pub fn set_follow_latest(&mut self, window: Option<FollowWindow>, cx: &mut Context<Self>) {
+        self.follow_latest = window;
+        self.resume_following(cx);
+    }
+
+    /// Jumps back to the newest data and follows it again.
+    pub fn resume_following(&mut self, cx: &mut Context<Self>) {
+        self.following = self.follow_latest.is_some();
+        self.user_interacted = false;
+        self.apply_follow(cx);
+        self.notify_render(cx);
+    }
+
+    /// Fits the whole view to the data the first time `data_appended` (or
+    /// `request_render`) runs with data in a pane, then leaves the axes to the
+    /// user. The window shown before that is the one the axes were created
+    /// with (`AxisRange::new`). Enabling it again re-arms the fit.
+    pub fn set_auto_fit_on_first_data(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        self.auto_fit_on_first_data = enabled;
+        self.first_data_fitted = false;
+        self.notify_render(cx);
+    }
+
+    /// Draws a chevron at each pane edge beyond which a visible series has
+    /// data, outside the visible range or past the axis limits, so users know
+    /// there is more to scroll to. Colored by `ChartTheme::overflow_indicator`.
+    pub fn set_show_overflow_indicators(&mut self, show: bool, cx: &mut Context<Self>) {
+        self.show_overflow_indicators = show;
+        self.notify_render(cx);
+    }
+
+    /// Pane edges beyond which a visible series of pane `pane_idx` has data,
+    /// against the currently rendered (limit-clamped) axis domains.
+    pub fn data_overflow(&self, pane_idx: usize, cx: &App) -> Option<DataOverflow> {
+        let ps = self.panes.get(pane_idx)?;
+        let x_domains = [ps.x_axis_or(&self.shared_x_axis).read(cx).clamped_bounds()];
+        let y_domains: Vec<(f64, f64)> = ps
+            .y_axes
+            .iter()
+            .map(|a| a.entity.read(cx).clamped_bounds())
+            .collect();
+        let visible: Vec<Series> = ps
+            .series
+            .iter()
+            .filter(|s| !ps.hidden_series.contains(&s.id))
+            .cloned()
+            .collect();
+        Some(DataOverflow::of_series(&visible, &x_domains, &y_domains))
+    }
+
+    /// To be called after appending data to a series: fits the view on the
+    /// first data if `auto_fit_on_first_data` is set, moves the X axis to the
+    /// newest data when following, then re-renders.
+    pub fn data_appended(&mut self, cx: &mut Context<Self>) {
+        if self.auto_fit_on_first_data
+            && !self.first_data_fitted
+            && self.panes.iter().any(|p| p.has_data())
+        {
+            self.fit_to_data(cx);
+            self.first_data_fitted = true;
+        }
+        self.apply_follow(cx);
+        if self.sparkline {
+            self.fit_all(cx);
+        }
+        self.notify_render(cx);
+    }
+
+    /// Coalescing variant of `data_appended` for high-frequency feeds: all the
+    /// calls made within one frame interval result in a single update, applied
+    /// at the end of the interval so the last appended data is always shown.
+    pub fn request_render(&mut self, cx: &mut Context<Self>) {
+        if self.render_pending {
+            return;
+        }
+        self.render_pending = true;
+        cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(RENDER_COALESCE_INTERVAL).await;
+            this.update(cx, |c, cx| {
+                c.render_pending = false;
+                c.data_appended(cx);
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn apply_follow(&mut self, cx: &mut Context<Self>) {
+        let (true, Some(window)) = (self.following, self.follow_latest) else {
+            return;
+        };
+        if self.user_interacted {
+            self.following = false;
+            return;
+        }
+        // Panes with their own X axis follow their own data, on their own axis
+        let shared = Self::latest_window(self.panes.iter().filter(|p| p.x_axis.is_none()), window);
+        let own: Vec<_> = self
+            .panes
+            .iter()
+            .filter_map(|ps| {
+                let axis = ps.x_axis.clone()?;
+                Some((axis, Self::latest_window([ps], window)?))
+            })
+            .collect();
+        let windows = shared.map(|w| (self.shared_x_axis.clone(), w)).into_iter().chain(own);
+        for (axis, (x_min, x_max)) in windows {
+            axis.update(cx, |r, _| {
+                r.min = x_min;
+                r.max = x_max;
+                r.clamp();
+            });
+        }
+    }
+
+    /// X range covering `window` at the end of the visible series of `panes`.
+    pub(crate) fn latest_window<'a>(
+        panes: impl IntoIterator<Item = &'a PaneState>,
+        window: FollowWindow,
+    ) -> Option<(f64, f64)> {
+        let (newest, data_start, plot) = panes
+            .into_iter()
+            .flat_map(|ps| ps.series.iter().filter(|s| !ps.hidden_series.contains(&s.id)))
+            .filter_map(|s| {
+                let (x_min, x_max, _, _) = s.plot.read().get_min_max()?;
+                Some((x_max, x_min, s.plot.clone()))
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0))?;
+
+        let start = match window {
+            FollowWindow::Span(span) => newest - span,
+            FollowWindow::Points(n) => {
+                let back = n.saturating_sub(1).max(1) as isize;
+                plot.read()
+                    .data_source()
+                    .and_then(|src| src.step_x(newest, -back))
+                    .unwrap_or(data_start)
+            }
+        };
+        (start < newest).then_some((start, newest))
+    }
+
+    /// Paint timings of this chart's panes and render timings, with averages
+    /// over the last `RENDER_STATS_WINDOW` frames. Recorded whether or not
+    /// `debug_mode` is on.
+    pub fn render_stats(&self, cx: &App) -> RenderStats {
+        let pane_paint: std::collections::HashMap<String, std::time::Duration> = {
+            let times = self.shared_state.read(cx).pane_paint_times.clone();
+            let times = times.read();
+            self.panes
+                .iter()
+                .filter_map(|ps| {
+                    let nanos = times.get(&ps.id)?;
+                    Some((ps.id.clone(), std::time::Duration::from_nanos(*nanos)))
+                })
+                .collect()
+        };
+        let history = self.render_history.read();
+        RenderStats {
+            total_paint: pane_paint.values().sum(),
+            pane_paint,
+            last_render: history.last_render,
+            avg_render: history.avg_render(),
+            avg_total_paint: history.avg_paint(),
+            frames: history.renders.len(),
+        }
+    }
+
+    /// Shows a "Loading…" placeholder in every pane until called with `false`.
+    pub fn set_loading(&mut self, loading: bool, cx: &mut Context<Self>) {
+        self.loading = loading;
+        self.notify_render(cx);
+    }
+
+    /// Switches the selected series (every series without a selection) to
+    /// the next aggregation mode, M4 -> MinMax -> LTTB -> StableLTTB -> M4, to
+    /// compare them on live data. Returns the mode the first of them applied
+    /// (sources with a fixed mode keep theirs), or None if no series has a
+    /// data source.
+    pub fn cycle_aggregation_mode(&mut self, cx: &mut Context<Self>) -> Option<AggregationMode> {
+        let targets: Vec<&Series> = self
+            .panes
+            .iter()
+            .flat_map(|ps| ps.series.iter())
+            .filter(|s| self.selected_series.as_ref().is_none_or(|id| *id == s.id))
+            .filter(|s| s.plot.read().data_source().is_some())
+            .collect();
+        let current = targets.first()?.plot.read().data_source()?.aggregation_mode();
+        let next = match current {
+            AggregationMode::M4 => AggregationMode::MinMax,
+            AggregationMode::MinMax => AggregationMode::LTTB,
+            AggregationMode::LTTB => AggregationMode::StableLTTB,
+            AggregationMode::StableLTTB | AggregationMode::Custom => AggregationMode::M4,
+        };
+        for series in &targets {
+            if let Some(source) = series.plot.write().data_source_mut() {
+                source.set_aggregation_mode(next);
+            }
+        }
+        let applied = targets[0].plot.read().data_source()?.aggregation_mode();
+        self.notify_render(cx);
+        Some(applied)
+    }
+
+    /// Draws a reference line at `baseline` (on the first Y axis) across a
+    /// pane, or removes it with None.
+    pub fn set_pane_baseline(
+        &mut self,
+        pane_idx: usize,
+        baseline: Option<f64>,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(pane) = self.panes.get_mut(pane_idx) {
+            pane.baseline = baseline;
+            self.notify_render(cx);
+        }
+    }
+
+    /// Adds an annotation to a pane. The returned id stays valid until the
+    /// annotation is removed, e.g. to move it from a drag callback.
+    pub fn add_annotation(
+        &mut self,
+        pane_idx: usize,
+        annotation: Annotation,
+        cx: &mut Context<Self>,
+    ) -> Option<AnnotationId> {
+        let pane = self.panes.get_mut(pane_idx)?;
+        let id = AnnotationId(self.next_annotation_id);
+        self.next_annotation_id += 1;
+        pane.annotations.push((id, annotation));
+        Self::sync_annotation_series(pane);
+        self.notify_render(cx);
+        Some(id)
+    }
+
+    /// Draws a horizontal line at `y` (on the Y axis of the pane's
+    /// annotations series) with a tag on that axis showing its value, e.g.
+    /// an alert level. The tag follows the line as the axis pans and zooms,
+    /// or when the line is dragged, and hides with the series. Returns
+    /// the id of the line's `Annotation::HLine`, for `remove_annotation`.
+    pub fn add_price_level(
+        &mut self,
+        pane_idx: usize,
+        y: f64,
+        color: Hsla,
+        cx: &mut Context<Self>,
+    ) -> Option<AnnotationId> {
+        let line = Annotation::HLine {
+            y,
+            color,
+            width: 1.0,
+            style: LineStyle::Dashed,
+            label: None,
+            layer: AnnotationLayer::Foreground,
+            affects_autofit: false,
+        };
+        let id = self.add_annotation(pane_idx, line, cx)?;
+        self.panes[pane_idx].price_levels.push(id);
+        Some(id)
+    }
+
+    /// Removes an annotation. Returns false if no pane holds `id`.
+    pub fn remove_annotation(&mut self, id: AnnotationId, cx: &mut Context<Self>) -> bool {
+        for pane in &mut self.panes {
+            if let Some(pos) = pane.annotations.iter().position(|(a_id, _)| *a_id == id) {
+                pane.annotations.remove(pos);
+                pane.price_levels.retain(|l| *l != id);
+                Self::sync_annotation_series(pane);
+                self.notify_render(cx);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Replaces an annotation, keeping its id. Returns false if no pane holds `id`.
+    pub fn update_annotation(
+        &mut self,
+        id: AnnotationId,
+        annotation: Annotation,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        for pane in &mut self.panes {
+            if let Some(entry) = pane.annotations.iter_mut().find(|(a_id, _)| *a_id == id) {
+                entry.1 = annotation;
+                Self::sync_annotation_series(pane);
+                self.notify_render(cx);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Annotation added with `add_annotation`, if still present.
+    pub fn annotation(&self, id: AnnotationId) -> Option<&Annotation> {
+        self.panes
+            .iter()
+            .flat_map(|p| &p.annotations)
+            .find(|(a_id, _)| *a_id == id)
+            .map(|(_, a)| a)
+    }
+
+    /// Sets how close (pixels) a press must be to an annotation to grab it.
+    ///
+    /// A press in a pane is handled in this order: an annotation within the
+    /// tolerance is selected (`ChartEvent::AnnotationSelected`) and dragged
+    /// with the left button; otherwise the right button starts a box zoom and
+    /// the left or middle button a pan. Filled rectangles are hit anywhere
+    /// inside, other annotations near their outline. None disables the
+    /// hit-testing so every press pans or zooms.
+    pub fn set_annotation_hit_tolerance(&mut self, tolerance_px: Option<f32>) {
+        self.annotation_hit_tolerance = tolerance_px;
+    }
+
+    /// Starts dragging annotation `id` of pane `pane_id` from `position`.
+    pub fn begin_annotation_drag(
+        &mut self,
+        id: AnnotationId,
+        pane_id: String,
+        position: Point<Pixels>,
+    ) {
+        self.dragging_annotation = Some(AnnotationDrag {
+            id,
+            pane_id,
+            last_pos: position,
+            moved: false,
         });
+    }
+
+    /// Ends an annotation drag, emitting `ChartEvent::AnnotationMoved` if the
+    /// annotation moved.
+    pub fn end_annotation_drag(&mut self, cx: &mut Context<Self>) {
+        if let Some(drag) = self.dragging_annotation.take() {
+            if drag.moved {
+                cx.emit(ChartEvent::AnnotationMoved(drag.id));
+            }
+        }
+    }
+
+    /// Rebuilds the pane's annotation series from `PaneState::annotations`,
+    /// removing it once the last annotation is gone.
+    fn sync_annotation_series(pane: &mut PaneState) {
+        let existing = pane.series.iter().position(|s| s.id == ANNOTATIONS_SERIES_ID);
+        if pane.annotations.is_empty() {
+            if let Some(pos) = existing {
+                pane.series.remove(pos);
+            }
+            return;
+        }
+        let plot = crate::plot_types::AnnotationPlot::new(
+            pane.annotations.iter().map(|(_, a)| a.clone()).collect(),
+        );
+        match existing {
+            Some(pos) => {
+                pane.series[pos].plot = std::sync::Arc::new(parking_lot::RwLock::new(plot));
+            }
+            None => pane.series.push(Series::new(ANNOTATIONS_SERIES_ID, plot)),
+        }
+    }
+
+    /// Gives a pane its own X axis (e.g. to compare two time ranges side by
+    /// side), or puts it back on the shared X axis with None. Pan and zoom
+    /// in that pane then only move its own axis.
+    pub fn set_pane_x_axis(
+        &mut self,
+        pane_idx: usize,
+        x_axis: Option<Entity<AxisRange>>,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(pane) = self.panes.get_mut(pane_idx) {
+            if let Some(axis) = &x_axis {
+                cx.observe(axis, |_, _, cx| cx.notify()).detach();
+            }
+            pane.x_axis = x_axis;
+            self.notify_render(cx);
+        }
+    }
+
+    /// X axis driving a pane, see `set_pane_x_axis`.
+    pub fn pane_x_axis(&self, pane_idx: usize) -> Option<Entity<AxisRange>> {
+        self.panes
+            .get(pane_idx)
+            .map(|p| p.x_axis_or(&self.shared_x_axis))
+    }
+
+    /// The points a series is drawn from in the current view: its data source
+    /// decimated over the pane's visible X range, with the point budget and
+    /// gap handling the renderer used for the last paint of the pane. X values
+    /// are in data units. None if the series is unknown, has no data source,
+    /// or its pane has not been painted yet.
+    pub fn visible_decimated(&self, series_id: &str, cx: &App) -> Option<Vec<PlotData>> {
+        let (ps, series) = self.panes.iter().find_map(|ps| {
+            ps.series.iter().find(|s| s.id == series_id).map(|s| (ps, s))
+        })?;
+        let state = self.shared_state.read(cx);
+        let width = state.pane_widths.read().get(&ps.id).copied()?;
+        let (x_min, x_max) = ps.x_axis_or(&self.shared_x_axis).read(cx).clamped_bounds();
+        let plot = series.plot.read();
+        let source = plot.data_source()?;
+        let mut output = Vec::new();
+        source.get_aggregated_data(
+            x_min,
+            x_max,
+            plot.max_points(width),
+            &mut output,
+            state.gap_index.as_deref(),
+        );
+        Some(output)
+    }
+
+    /// Overrides whether the view re-renders on every frame (None: only in
+    /// debug mode). Some(false) keeps the debug overlay on a stable frame.
+    pub fn set_continuous_repaint(&mut self, continuous: Option<bool>, cx: &mut Context<Self>) {
+        self.shared_state.update(cx, |s, _| s.continuous_repaint = continuous);
+        self.notify_render(cx);
+    }
+
+    /// Insets the axes from the chart border, and the panes from the axes,
+    /// by `padding` on each side.
+    pub fn set_plot_padding(&mut self, padding: Edges<Pixels>, cx: &mut Context<Self>) {
+        self.plot_padding = padding;
+        self.notify_render(cx);
+    }
+
+    /// Shows or hides the per-pane move/add/close buttons, e.g. for read-only
+    /// dashboards. Pan and zoom are unaffected.
+    pub fn set_show_pane_controls(&mut self, show: bool, cx: &mut Context<Self>) {
+        self.show_pane_controls = show;
         self.notify_render(cx);
     }
 
+    /// Selects a series (or clears the selection with `None`) and emits
+    /// `ChartEvent::SelectionChanged` if it changed.
+    pub fn set_selected_series(&mut self, series_id: Option<String>, cx: &mut Context<Self>) {
+        if self.selected_series == series_id {
+            return;
+        }
+        self.selected_series = series_id.clone();
+        cx.emit(ChartEvent::SelectionChanged(series_id));
+        self.notify_render(cx);
+    }
+
+    /// Sets the label format (numeric or time with unit/timezone) of an X axis
+    /// and forces its ticks to be recomputed. Returns false if there is no X
+    /// axis at `axis_idx`.
     pub fn set_x_axis_format(
         &mut self,
         axis_idx: usize,
         format: crate::data_types::AxisFormat,
         cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(axis) = self.x_axes.get_mut(axis_idx) else {
+            return false;
+        };
+        axis.format = format;
+        axis.entity.update(cx, |r, _| r.cached_ticks.clear());
+        self.notify_render(cx);
+        true
+    }
+
+    /// Y-axis counterpart of [`Chart::set_x_axis_format`]. Returns false if
+    /// the pane or the axis does not exist.
+    pub fn set_y_axis_format(
+        &mut self,
+        pane_idx: usize,
+        axis_idx: usize,
+        format: crate::data_types::AxisFormat,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(axis) = self
+            .panes
+            .get_mut(pane_idx)
+            .and_then(|p| p.y_axes.get_mut(axis_idx))
+        else {
+            return false;
+        };
+        axis.format = format;
+        axis.entity.update(cx, |r, _| r.cached_ticks.clear());
+        self.notify_render(cx);
+        true
+    }
+
+    /// Names a Y axis of a pane so series can attach to it with
+    /// `Series::on_named_axis` regardless of its position. Returns false if
+    /// the pane or the axis does not exist.
+    pub fn set_y_axis_name(
+        &mut self,
+        pane_idx: usize,
+        axis_idx: usize,
+        name: impl Into<String>,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(pane) = self.panes.get_mut(pane_idx) else {
+            return false;
+        };
+        let Some(axis) = pane.y_axes.get_mut(axis_idx) else {
+            return false;
+        };
+        axis.name = Some(name.into());
+        pane.resolve_named_axes();
+        self.notify_render(cx);
+        true
+    }
+
+    /// Points named series at the current index of their axis in every
+    /// pane, so inserting, removing or reordering axes never moves a named
+    /// series to another axis. Runs whenever the chart is notified.
+    pub fn resolve_named_axes(&mut self) {
+        for pane in &mut self.panes {
+            pane.resolve_named_axes();
+        }
+    }
+
+    /// Recomputes the derived Y axes of every pane from their source axes.
+    fn sync_derived_axes(&self, cx: &mut App) {
+        for pane in &self.panes {
+            pane.sync_derived_axes(cx);
+        }
+    }
+
+    /// Adds a Y axis to a pane that mirrors `derived.source_axis` through
+    /// its mapping (e.g. a °F scale next to a °C one). Returns the new axis
+    /// index, or None if the pane or the source axis does not exist.
+    pub fn add_derived_y_axis(
+        &mut self,
+        pane_idx: usize,
+        derived: DerivedAxis,
+        edge: AxisEdge,
+        label: impl Into<String>,
+        cx: &mut Context<Self>,
+    ) -> Option<usize> {
+        let pane = self.panes.get_mut(pane_idx)?;
+        pane.y_axes.get(derived.source_axis)?;
+        let entity = cx.new(|_| AxisRange::new(0.0, 1.0));
+        cx.observe(&entity, |_, _, cx| cx.notify()).detach();
+        let mut axis = AxisState::new(entity, edge, px(60.0), label.into());
+        axis.derived = Some(derived);
+        pane.y_axes.push(axis);
+        pane.sync_derived_axes(cx);
+        let idx = pane.y_axes.len() - 1;
+        self.notify_render(cx);
+        Some(idx)
+    }
+
+    /// Adds an X axis on `edge` showing the shared X range with its own
+    /// format and tick density, e.g. a coarse strip of months above the day
+    /// ticks. It stacks with the other X axes of that edge, and its ticks
+    /// skip gaps like theirs. Returns the new axis index.
+    pub fn add_overview_x_axis(
+        &mut self,
+        edge: AxisEdge,
+        format: AxisFormat,
+        tick_count: TickCount,
+        cx: &mut Context<Self>,
+    ) -> usize {
+        let mut axis = AxisState::new(self.shared_x_axis.clone(), edge, px(20.0), String::new());
+        axis.format = format;
+        axis.tick_count = tick_count;
+        self.x_axes.push(axis);
+        self.notify_render(cx);
+        self.x_axes.len() - 1
+    }
+
+    pub fn set_x_axis_min_spacing(
+        &mut self,
+        axis_idx: usize,
+        spacing: Pixels,
+        cx: &mut Context<Self>,
     ) {
         if let Some(axis) = self.x_axes.get_mut(axis_idx) {
-            axis.format = format;
+            axis.min_label_spacing = spacing;
             self.notify_render(cx);
         }
     }
 
-    pub fn set_y_axis_format(
+    pub fn set_y_axis_min_spacing(
         &mut self,
         pane_idx: usize,
         axis_idx: usize,
-        format: crate::data_types::AxisFormat,
+        spacing: Pixels,
         cx: &mut Context<Self>,
     ) {
         if let Some(pane) = self.panes.get_mut(pane_idx) {
             if let Some(axis) = pane.y_axes.get_mut(axis_idx) {
-                axis.format = format;
+                axis.min_label_spacing = spacing;
                 self.notify_render(cx);
             }
         }
     }
 
-    pub fn set_x_axis_min_spacing(
+    /// Padding kept around the data by every fit (`ResetView`, `AutoFitY`,
+    /// `FitX`, axis double-clicks, `fit_to_series`...), as a fraction of the
+    /// data span: 0.0 frames the data tightly, 0.1 adds 10% on each side.
+    /// Defaults to 0.05. An axis overrides it with `AxisRange::autofit_padding`.
+    /// Box zoom frames the selected box exactly and ignores it.
+    pub fn set_autofit_padding(&mut self, padding: f64, cx: &mut Context<Self>) {
+        self.autofit_padding = padding.max(0.0);
+        self.notify_render(cx);
+    }
+
+    /// Enables or disables snapping zoom to the data bounds. When enabled,
+    /// zooming out (wheel, axis drag, actions, box-zoom) stops once the data
+    /// extent plus the auto-fit padding is visible.
+    pub fn set_snap_zoom_to_data(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        self.snap_zoom_to_data = enabled;
+        if !enabled {
+            // Clears the cached extents; `notify_render` refreshes them otherwise
+            self.refresh_data_extents(cx);
+        }
+        self.notify_render(cx);
+    }
+
+    /// Recomputes the data extent cached on every axis from `get_min_max`.
+    /// Called on structural changes; call it after appending data to a
+    /// streaming source if zoom snapping is enabled.
+    pub fn refresh_data_extents(&self, cx: &mut Context<Self>) {
+        // The same padding as a fit, so zooming out stops at the fitted view
+        let snap = self.snap_zoom_to_data;
+        let padding = self.autofit_padding;
+        let extent = |r: &AxisRange, min: f64, max: f64| {
+            let padding = r.autofit_padding.unwrap_or(padding);
+            (snap && min <= max).then(|| ViewController::compute_auto_fit(min, max, padding))
+        };
+
+        let mut x_min = f64::INFINITY;
+        let mut x_max = f64::NEG_INFINITY;
+        for ps in &self.panes {
+            for (a_idx, axis) in ps.y_axes.iter().enumerate() {
+                let y_bounds = ps.data_bounds(Some(a_idx), true);
+                axis.entity.update(cx, |r, _| {
+                    r.data_extent = y_bounds.and_then(|(_, _, lo, hi)| extent(r, lo, hi));
+                });
+            }
+            let pane_x = ps.data_bounds(None, true);
+            match &ps.x_axis {
+                Some(own) => {
+                    own.update(cx, |r, _| {
+                        r.data_extent = pane_x.and_then(|(lo, hi, _, _)| extent(r, lo, hi));
+                    });
+                }
+                None => {
+                    if let Some((lo, hi, _, _)) = pane_x {
+                        x_min = x_min.min(lo);
+                        x_max = x_max.max(hi);
+                    }
+                }
+            }
+        }
+        self.shared_x_axis.update(cx, |r, _| r.data_extent = extent(r, x_min, x_max));
+        for axis in &self.x_axes {
+            axis.entity.update(cx, |r, _| r.data_extent = extent(r, x_min, x_max));
+        }
+    }
+
+    pub fn set_x_axis_tick_count(
         &mut self,
         axis_idx: usize,
-        spacing: Pixels,
+        tick_count: TickCount,
         cx: &mut Context<Self>,
     ) {
         if let Some(axis) = self.x_axes.get_mut(axis_idx) {
-            axis.min_label_spacing = spacing;
+            axis.tick_count = tick_count;
             self.notify_render(cx);
         }
     }
 
-    pub fn set_y_axis_min_spacing(
+    pub fn set_y_axis_tick_count(
         &mut self,
         pane_idx: usize,
         axis_idx: usize,
-        spacing: Pixels,
+        tick_count: TickCount,
         cx: &mut Context<Self>,
     ) {
         if let Some(pane) = self.panes.get_mut(pane_idx) {
             if let Some(axis) = pane.y_axes.get_mut(axis_idx) {
-                axis.min_label_spacing = spacing;
+                axis.tick_count = tick_count;
                 self.notify_render(cx);
             }
         }
     }
 
+    /// Fits every Y axis of a pane to its visible series over the current X window,
+    /// leaving X untouched.
+    pub fn fit_pane_y(&self, pane_idx: usize, cx: &mut Context<Self>) {
+        let Some(ps) = self.panes.get(pane_idx) else {
+            return;
+        };
+        let x_range = ps.x_axis_or(&self.shared_x_axis).read(cx).clone();
+        let x_bounds = (x_range.min, x_range.max);
+        let padding = self.autofit_padding;
+        for (a_idx, y_axis_state) in ps.y_axes.iter().enumerate() {
+            if let Some((sy_min, sy_max)) = ps.y_range_on_axis(a_idx, x_bounds.0, x_bounds.1, false) {
+                y_axis_state.entity.update(cx, |y, _| {
+                    ViewController::auto_fit_axis_padded(y, sy_min, sy_max, padding);
+                    y.update_ticks_if_needed(10, None);
+                });
+            }
+        }
+    }
+
+    /// Fits the view to one series alone: the X axis of its pane to the
+    /// series' extent, and the Y axis it is assigned to to its values over
+    /// that extent, ignoring the other series. Returns false if no series has
+    /// this ID or it has no data.
+    pub fn fit_to_series(&mut self, series_id: &str, cx: &mut Context<Self>) -> bool {
+        let Some((ps, series)) = self.panes.iter().find_map(|ps| {
+            ps.series.iter().find(|s| s.id == series_id).map(|s| (ps, s))
+        }) else {
+            return false;
+        };
+        let plot = series.plot.read();
+        let Some((x_min, x_max, _, _)) = plot.get_min_max() else {
+            return false;
+        };
+        let gaps = self.shared_state.read(cx).gap_index.clone();
+        let padding = self.autofit_padding;
+        ps.x_axis_or(&self.shared_x_axis).update(cx, |r, _| {
+            ViewController::auto_fit_axis_padded(r, x_min, x_max, padding);
+            r.update_ticks_if_needed(10, gaps.as_deref());
+        });
+        if let (Some(y_axis), Some((y_min, y_max))) = (
+            ps.y_axes.get(series.y_axis_id.0),
+            plot.get_y_range(x_min, x_max),
+        ) {
+            y_axis.entity.update(cx, |y, _| {
+                ViewController::auto_fit_axis_padded(y, y_min, y_max, padding);
+                y.update_ticks_if_needed(10, None);
+            });
+        }
+        drop(plot);
+        self.user_interacted = false;
+        self.notify_render(cx);
+        true
+    }
+
+    /// Fits the shared X axis to the extent of the series of the panes on
+    /// it, and each pane's own X axis to that pane's series, leaving the Y
+    /// axes as they are (the X axis double-click).
+    pub fn fit_x_to_data(&mut self, cx: &mut Context<Self>) {
+        let mut shared: Option<(f64, f64)> = None;
+        let mut own = Vec::new();
+        for ps in &self.panes {
+            let Some((x_min, x_max, _, _)) = ps.data_bounds(None, true) else {
+                continue;
+            };
+            match &ps.x_axis {
+                Some(axis) => own.push((axis.clone(), (x_min, x_max))),
+                None => {
+                    shared = Some(shared.map_or((x_min, x_max), |(lo, hi)| {
+                        (lo.min(x_min), hi.max(x_max))
+                    }));
+                }
+            }
+        }
+        let gaps = self.shared_state.read(cx).gap_index.clone();
+        let padding = self.autofit_padding;
+        let fits = shared.map(|b| (self.shared_x_axis.clone(), b)).into_iter().chain(own);
+        for (axis, (x_min, x_max)) in fits {
+            let gaps = gaps.clone();
+            axis.update(cx, move |r, _| {
+                ViewController::auto_fit_axis_padded(r, x_min, x_max, padding);
+                r.update_ticks_if_needed(10, gaps.as_deref());
+            });
+        }
+        self.user_interacted = false;
+        self.notify_render(cx);
+    }
+
+    /// Fits the Y axes of every pane to the visible X window.
+    pub fn fit_y_to_visible_x(&mut self, cx: &mut Context<Self>) {
+        self.user_interacted = false;
+        for p_idx in 0..self.panes.len() {
+            self.fit_pane_y(p_idx, cx);
+        }
+        self.notify_render(cx);
+    }
+
+    /// Bounds panning and zooming on every X axis (the shared one, and those
+    /// given to panes with `set_pane_x_axis`), e.g. from the first data point
+    /// to now. The current view is moved inside the new limits; None removes
+    /// the limit on that side.
+    pub fn set_x_limits(
+        &mut self,
+        min_limit: Option<f64>,
+        max_limit: Option<f64>,
+        cx: &mut Context<Self>,
+    ) {
+        for axis in self.x_axis_entities() {
+            axis.update(cx, |r, _| r.set_limits(min_limit, max_limit));
+        }
+        self.notify_render(cx);
+    }
+
+    /// Every distinct X axis: the shared one, the extra ones and those given
+    /// to panes with `set_pane_x_axis`.
+    fn x_axis_entities(&self) -> Vec<Entity<AxisRange>> {
+        let mut axes = vec![self.shared_x_axis.clone()];
+        let others = self.x_axes.iter().map(|a| &a.entity);
+        for axis in others.chain(self.panes.iter().filter_map(|p| p.x_axis.as_ref())) {
+            if !axes.iter().any(|a| a.entity_id() == axis.entity_id()) {
+                axes.push(axis.clone());
+            }
+        }
+        axes
+    }
+
+    /// Bounds panning and zooming on a Y axis of a pane, see `set_x_limits`.
+    pub fn set_y_limits(
+        &mut self,
+        pane_idx: usize,
+        axis_idx: usize,
+        min_limit: Option<f64>,
+        max_limit: Option<f64>,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(axis) = self.panes.get(pane_idx).and_then(|p| p.y_axes.get(axis_idx)) {
+            axis.entity.update(cx, |r, _| r.set_limits(min_limit, max_limit));
+            self.notify_render(cx);
+        }
+    }
+
+    /// Enables or disables limit enforcement on an X axis without removing its limits.
+    pub fn set_x_axis_clamp_enabled(
+        &mut self,
+        axis_idx: usize,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(axis) = self.x_axes.get(axis_idx) {
+            axis.entity.update(cx, |r, _| {
+                r.clamp_enabled = enabled;
+                r.clamp();
+            });
+            self.notify_render(cx);
+        }
+    }
+
+    /// Enables or disables limit enforcement on a Y axis without removing its limits.
+    pub fn set_y_axis_clamp_enabled(
+        &mut self,
+        pane_idx: usize,
+        axis_idx: usize,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(axis) = self.panes.get(pane_idx).and_then(|p| p.y_axes.get(axis_idx)) {
+            axis.entity.update(cx, |r, _| {
+                r.clamp_enabled = enabled;
+                r.clamp();
+            });
+            self.notify_render(cx);
+        }
+    }
+
+    /// Fits each X axis to the data of its panes (the shared one to the panes
+    /// without their own) and then every Y axis to its visible series over the
+    /// new X window. This is what the `ResetView` action does.
+    pub fn fit_to_data(&mut self, cx: &mut Context<Self>) {
+        self.user_interacted = false;
+        self.fit_all(cx);
+    }
+
+    /// Ranges `fit_to_data` would set, computed without touching the axes
+    /// (padding and limits included), e.g. to preview a fit. Apply them with
+    /// `apply_autofit_bounds`.
+    pub fn compute_autofit_bounds(&self, cx: &App) -> AutoFitBounds {
+        let padding = self.autofit_padding;
+        let fitted = |axis: &Entity<AxisRange>, min: f64, max: f64| {
+            let mut r = axis.read(cx).clone();
+            ViewController::auto_fit_axis_padded(&mut r, min, max, padding);
+            (r.min, r.max)
+        };
+        let mut bounds = AutoFitBounds::default();
+        let mut x_min = f64::INFINITY;
+        let mut x_max = f64::NEG_INFINITY;
+        for (p_idx, ps) in self.panes.iter().enumerate() {
+            let Some((sx_min, sx_max, _, _)) = ps.data_bounds(None, true) else {
+                continue;
+            };
+            match &ps.x_axis {
+                Some(axis) => bounds.pane_x.push((p_idx, fitted(axis, sx_min, sx_max))),
+                None => {
+                    x_min = x_min.min(sx_min);
+                    x_max = x_max.max(sx_max);
+                }
+            }
+        }
+        if x_min != f64::INFINITY {
+            bounds.x = Some(fitted(&self.shared_x_axis, x_min, x_max));
+        }
+        for (p_idx, ps) in self.panes.iter().enumerate() {
+            let own_x = bounds.pane_x.iter().find(|(i, _)| *i == p_idx).map(|(_, r)| *r);
+            let (view_min, view_max) = match (&ps.x_axis, own_x, bounds.x) {
+                (Some(_), Some(r), _) | (None, _, Some(r)) => r,
+                _ => {
+                    let r = ps.x_axis_or(&self.shared_x_axis).read(cx);
+                    (r.min, r.max)
+                }
+            };
+            for (a_idx, y_axis_state) in ps.y_axes.iter().enumerate() {
+                if let Some((sy_min, sy_max)) =
+                    ps.y_range_on_axis(a_idx, view_min, view_max, true)
+                {
+                    bounds.y.push((p_idx, a_idx, fitted(&y_axis_state.entity, sy_min, sy_max)));
+                }
+            }
+        }
+        bounds
+    }
+
+    /// Sets the axes to ranges from `compute_autofit_bounds`, as `fit_to_data`
+    /// does. Entries for panes or axes that no longer exist are skipped.
+    pub fn apply_autofit_bounds(&mut self, bounds: &AutoFitBounds, cx: &mut Context<Self>) {
+        self.user_interacted = false;
+        self.apply_fit(bounds, cx);
+        self.notify_render(cx);
+    }
+
+    fn apply_fit(&self, bounds: &AutoFitBounds, cx: &mut Context<Self>) {
+        let gaps = self.shared_state.read(cx).gap_index.clone();
+        let set = |axis: &Entity<AxisRange>, (min, max): (f64, f64), is_x: bool, cx: &mut App| {
+            let gaps = if is_x { gaps.clone() } else { None };
+            axis.update(cx, move |r, _| {
+                r.min = min;
+                r.max = max;
+                r.update_ticks_if_needed(10, gaps.as_deref());
+            });
+        };
+        if let Some(x) = bounds.x {
+            set(&self.shared_x_axis, x, true, cx);
+        }
+        for &(p_idx, x) in &bounds.pane_x {
+            if let Some(axis) = self.panes.get(p_idx).and_then(|p| p.x_axis.as_ref()) {
+                set(axis, x, true, cx);
+            }
+        }
+        for &(p_idx, a_idx, y) in &bounds.y {
+            if let Some(axis) = self.panes.get(p_idx).and_then(|p| p.y_axes.get(a_idx)) {
+                set(&axis.entity, y, false, cx);
+            }
+        }
+    }
+
+    fn fit_all(&self, cx: &mut Context<Self>) {
+        let bounds = self.compute_autofit_bounds(cx);
+        self.apply_fit(&bounds, cx);
+    }
+
     pub fn notify_render(&self, cx: &mut Context<Self>) {
+        if self.snap_zoom_to_data {
+            self.refresh_data_extents(cx);
+        }
         cx.notify();
     }
 }
+
+impl EventEmitter<ChartEvent> for Chart {}
@@ -13,11 +13,23 @@ actions!(
         ZoomIn,
         ZoomOut,
         ResetView,
+        AutoFitY,
+        FitX,
+        FollowLatest,
         ToggleDebug,
-        ToggleCrosshair
+        CycleAggregationMode,
+        ToggleCrosshair,
+        StepCrosshairLeft,
+        StepCrosshairRight,
+        JumpCrosshairLeft,
+        JumpCrosshairRight
     ]
 );
 
+/// Number of data points skipped by `JumpCrosshairLeft` / `JumpCrosshairRight`
+/// (typically bound to shift-left / shift-right; the step actions to left / right).
+pub const CROSSHAIR_JUMP_STEPS: isize = 10;
+
 #[derive(Clone)]
 pub struct ChartActionHandler {
     pub chart: Entity<Chart>,
@@ -31,6 +43,7 @@ impl ChartActionHandler {
     pub fn handle_pan_left(&self, _: &PanLeft, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
             let gaps = c.shared_state.read(cx).gap_index.clone();
+            c.user_interacted = true;
             c.shared_x_axis.update(cx, move |r, _| {
                 ViewController::pan_axis(r, -20.0, 200.0, false, gaps.as_deref());
                 r.update_ticks_if_needed(10, gaps.as_deref());
@@ -42,6 +55,7 @@ impl ChartActionHandler {
     pub fn handle_pan_right(&self, _: &PanRight, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
             let gaps = c.shared_state.read(cx).gap_index.clone();
+            c.user_interacted = true;
             c.shared_x_axis.update(cx, move |r, _| {
                 ViewController::pan_axis(r, 20.0, 200.0, false, gaps.as_deref());
                 r.update_ticks_if_needed(10, gaps.as_deref());
@@ -53,6 +67,7 @@ impl ChartActionHandler {
     pub fn handle_zoom_in(&self, _: &ZoomIn, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
             let gaps = c.shared_state.read(cx).gap_index.clone();
+            c.user_interacted = true;
             c.shared_x_axis.update(cx, move |r, _| {
                 ViewController::zoom_axis_at(r, 0.5, 0.9, gaps.as_deref());
                 r.update_ticks_if_needed(10, gaps.as_deref());
@@ -64,6 +79,7 @@ impl ChartActionHandler {
     pub fn handle_zoom_out(&self, _: &ZoomOut, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
             let gaps = c.shared_state.read(cx).gap_index.clone();
+            c.user_interacted = true;
             c.shared_x_axis.update(cx, move |r, _| {
                 ViewController::zoom_axis_at(r, 0.5, 1.1, gaps.as_deref());
                 r.update_ticks_if_needed(10, gaps.as_deref());
@@ -74,52 +90,23 @@ impl ChartActionHandler {
     
     pub fn handle_reset_view(&self, _: &ResetView, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
-            let mut x_min = f64::INFINITY;
-            let mut x_max = f64::NEG_INFINITY;
-            for ps in &c.panes {
-                for s in &ps.series {
-                    if let Some((sx_min, sx_max, _, _)) = s.plot.read().get_min_max() {
-                        x_min = x_min.min(sx_min);
-                        x_max = x_max.max(sx_max);
-                    }
-                }
-            }
-            if x_min != f64::INFINITY {
-                let gaps = c.shared_state.read(cx).gap_index.clone();
-                c.shared_x_axis.update(cx, move |r, _| {
-                    ViewController::auto_fit_axis(r, x_min, x_max, 0.05);
-                    r.update_ticks_if_needed(10, gaps.as_deref());
-                });
-            }
-            for ps in c.panes.iter() {
-                let x_range = c.shared_x_axis.read(cx);
-                let x_bounds = (x_range.min, x_range.max);
-                for (a_idx, y_axis_state) in ps.y_axes.iter().enumerate() {
-                    let mut sy_min = f64::INFINITY;
-                    let mut sy_max = f64::NEG_INFINITY;
-                    for series in &ps.series {
-                        if series.y_axis_id.0 != a_idx {
-                            continue;
-                        }
-                        if let Some((s_min, s_max)) =
-                            series.plot.read().get_y_range(x_bounds.0, x_bounds.1)
-                        {
-                            sy_min = sy_min.min(s_min);
-                            sy_max = sy_max.max(s_max);
-                        }
-                    }
-                    if sy_min != f64::INFINITY {
-                        y_axis_state.entity.update(cx, |y, _| {
-                            ViewController::auto_fit_axis(y, sy_min, sy_max, 0.05);
-                            y.update_ticks_if_needed(10, None);
-                        });
-                    }
-                }
-            }
+            c.fit_to_data(cx);
             cx.notify();
         });
     }
 
+    pub fn handle_auto_fit_y(&self, _: &AutoFitY, _win: &mut Window, cx: &mut App) {
+        self.chart.update(cx, |c, cx| c.fit_y_to_visible_x(cx));
+    }
+
+    pub fn handle_fit_x(&self, _: &FitX, _win: &mut Window, cx: &mut App) {
+        self.chart.update(cx, |c, cx| c.fit_x_to_data(cx));
+    }
+
+    pub fn handle_follow_latest(&self, _: &FollowLatest, _win: &mut Window, cx: &mut App) {
+        self.chart.update(cx, |c, cx| c.resume_following(cx));
+    }
+
     pub fn handle_toggle_debug(&self, _: &ToggleDebug, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
             c.shared_state.update(cx, |s: &mut SharedPlotState, _| {
@@ -129,16 +116,42 @@ impl ChartActionHandler {
         });
     }
 
+    /// Only active in debug mode, see `Chart::cycle_aggregation_mode`.
+    pub fn handle_cycle_aggregation_mode(
+        &self,
+        _: &CycleAggregationMode,
+        _win: &mut Window,
+        cx: &mut App,
+    ) {
+        self.chart.update(cx, |c, cx| {
+            if c.shared_state.read(cx).debug_mode {
+                c.cycle_aggregation_mode(cx);
+            }
+        });
+    }
+
     pub fn handle_toggle_crosshair(&self, _: &ToggleCrosshair, _win: &mut Window, cx: &mut App) {
         self.chart.update(cx, |c, cx| {
-            c.shared_state.update(cx, |s: &mut SharedPlotState, _| {
-                s.crosshair_enabled = !s.crosshair_enabled;
-                if !s.crosshair_enabled {
-                    s.hover_x = None;
-                    s.mouse_pos = None;
-                }
-            });
-            cx.notify();
+            let visible = c.crosshair_visible(cx);
+            c.set_crosshair_visible(!visible, cx);
         });
     }
+
+    pub fn handle_step_crosshair_left(&self, _: &StepCrosshairLeft, _win: &mut Window, cx: &mut App) {
+        self.chart.update(cx, |c, cx| c.step_crosshair(-1, cx));
+    }
+
+    pub fn handle_step_crosshair_right(&self, _: &StepCrosshairRight, _win: &mut Window, cx: &mut App) {
+        self.chart.update(cx, |c, cx| c.step_crosshair(1, cx));
+    }
+
+    pub fn handle_jump_crosshair_left(&self, _: &JumpCrosshairLeft, _win: &mut Window, cx: &mut App) {
+        self.chart
+            .update(cx, |c, cx| c.step_crosshair(-CROSSHAIR_JUMP_STEPS, cx));
+    }
+
+    pub fn handle_jump_crosshair_right(&self, _: &JumpCrosshairRight, _win: &mut Window, cx: &mut App) {
+        self.chart
+            .update(cx, |c, cx| c.step_crosshair(CROSSHAIR_JUMP_STEPS, cx));
+    }
 }
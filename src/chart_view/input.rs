@@ -1,5 +1,9 @@
-use crate::chart::Chart;
-use crate::data_types::{AxisRange, SharedPlotState};
+use crate::chart::{Chart, ChartEvent, PaneState, ANNOTATIONS_SERIES_ID};
+use crate::data_types::{
+    Annotation, AnnotationId, AxisRange, InertiaConfig, SharedPlotState, ZoomConfig,
+};
+use crate::gaps::GapIndex;
+use crate::scales::ChartScale;
 use crate::view_controller::ViewController;
 use crate::utils::PixelsExt;
 use gpui::*;
@@ -16,6 +20,10 @@ pub struct ChartInputHandler {
     pub last_render_axis_bounds: Rc<RefCell<HashMap<String, Bounds<Pixels>>>>,
     pub bounds: Rc<RefCell<Bounds<Pixels>>>,
     pub pane_bounds: Rc<RefCell<HashMap<String, Bounds<Pixels>>>>,
+
+    pub zoom_config: ZoomConfig,
+    /// Synced from `ChartView::inertia_config` on every render.
+    pub inertia_config: InertiaConfig,
 }
 
 impl ChartInputHandler {
@@ -32,9 +40,166 @@ impl ChartInputHandler {
             last_render_axis_bounds,
             bounds,
             pane_bounds,
+            zoom_config: ZoomConfig::default(),
+            inertia_config: InertiaConfig::default(),
         }
     }
 
+    /// Finds the visible series closest to `position` within `tolerance_px`.
+    /// Distance is measured vertically against the Y-range of each series
+    /// in a small X window around the cursor, taken on the series' X axis
+    /// (`x_domains` indexed by `x_axis_id`, as for painting).
+    pub fn series_under_cursor(
+        ps: &PaneState,
+        x_domains: &[(f64, f64)],
+        y_domains: &[(f64, f64)],
+        bounds: Bounds<Pixels>,
+        position: Point<Pixels>,
+        tolerance_px: f32,
+        gaps: Option<&crate::gaps::GapIndex>,
+    ) -> Option<String> {
+        let w = bounds.size.width.as_f32();
+        let h = bounds.size.height.as_f32();
+        if w <= 0.0 || h <= 0.0 {
+            return None;
+        }
+        let local_x = (position.x - bounds.origin.x).as_f32();
+        let local_y = (position.y - bounds.origin.y).as_f32();
+
+        let mut best: Option<(f32, String)> = None;
+        for series in &ps.series {
+            if ps.hidden_series.contains(&series.id) {
+                continue;
+            }
+            let Some(&(x_min, x_max)) = x_domains.get(series.x_axis_id.0) else {
+                continue;
+            };
+            let x_at = |px: f32| {
+                ViewController::map_pixels_to_value(px, w, x_min, x_max, false, gaps)
+            };
+            let (x_lo, x_hi) = (x_at(local_x - tolerance_px), x_at(local_x + tolerance_px));
+            let Some(&(d_min, d_max)) = y_domains.get(series.y_axis_id.0) else {
+                continue;
+            };
+            let span = d_max - d_min;
+            if span.abs() < f64::EPSILON {
+                continue;
+            }
+            let Some((s_min, s_max)) = series.plot.read().get_y_range(x_lo, x_hi) else {
+                continue;
+            };
+            let to_px = |v: f64| ((1.0 - (v - d_min) / span) * h as f64) as f32;
+            let (top, bottom) = (to_px(s_max), to_px(s_min));
+            let dist = if local_y < top {
+                top - local_y
+            } else if local_y > bottom {
+                local_y - bottom
+            } else {
+                0.0
+            };
+            if dist <= tolerance_px && best.as_ref().is_none_or(|(d, _)| dist < *d) {
+                best = Some((dist, series.id.clone()));
+            }
+        }
+        best.map(|(_, id)| id)
+    }
+
+    /// Finds the annotation of the pane closest to `position` within
+    /// `tolerance_px`, the topmost one on ties. Annotations are mapped to
+    /// screen space through the pane's X domain and the Y domain of the
+    /// annotation series' axis. Line-like annotations are hit near their
+    /// outline, filled rectangles anywhere inside; text is approximated by a
+    /// box from its anchor.
+    pub fn annotation_under_cursor(
+        ps: &PaneState,
+        x_domain: (f64, f64),
+        y_domains: &[(f64, f64)],
+        bounds: Bounds<Pixels>,
+        position: Point<Pixels>,
+        tolerance_px: f32,
+        gaps: Option<std::sync::Arc<GapIndex>>,
+    ) -> Option<AnnotationId> {
+        let w = bounds.size.width.as_f32();
+        let h = bounds.size.height.as_f32();
+        if w <= 0.0 || h <= 0.0 || ps.hidden_series.contains(ANNOTATIONS_SERIES_ID) {
+            return None;
+        }
+        let axis = ps
+            .series
+            .iter()
+            .find(|s| s.id == ANNOTATIONS_SERIES_ID)
+            .map_or(0, |s| s.y_axis_id.0);
+        let &y_domain = y_domains.get(axis)?;
+        let x_scale = ChartScale::new_linear(x_domain, (0.0, w)).with_gaps(gaps.clone());
+        let y_scale = ChartScale::new_linear(y_domain, (h, 0.0));
+        let lx = (position.x - bounds.origin.x).as_f32();
+        let ly = (position.y - bounds.origin.y).as_f32();
+
+        // Distance to a box, and to its outline when inside
+        let box_dist = |left: f32, right: f32, top: f32, bottom: f32, solid: bool| {
+            let (left, right) = (left.min(right), left.max(right));
+            let (top, bottom) = (top.min(bottom), top.max(bottom));
+            let dx = (left - lx).max(lx - right).max(0.0);
+            let dy = (top - ly).max(ly - bottom).max(0.0);
+            if dx > 0.0 || dy > 0.0 {
+                dx.hypot(dy)
+            } else if solid {
+                0.0
+            } else {
+                (lx - left).min(right - lx).min(ly - top).min(bottom - ly)
+            }
+        };
+
+        let mut best: Option<(f32, AnnotationId)> = None;
+        for (id, annotation) in ps.annotations.iter().rev() {
+            let dist = match annotation {
+                Annotation::VLine { x, .. } => {
+                    if gaps.as_ref().is_some_and(|g| g.is_inside(*x as i64)) {
+                        continue;
+                    }
+                    (x_scale.map(*x) - lx).abs()
+                }
+                Annotation::HLine { y, .. } => (y_scale.map(*y) - ly).abs(),
+                Annotation::Rect {
+                    x_min,
+                    x_max,
+                    y_min,
+                    y_max,
+                    fill,
+                    ..
+                } => box_dist(
+                    x_scale.map(*x_min),
+                    x_scale.map(*x_max),
+                    y_scale.map(*y_max),
+                    y_scale.map(*y_min),
+                    *fill,
+                ),
+                Annotation::Text {
+                    x,
+                    y,
+                    text,
+                    font_size,
+                    ..
+                } => {
+                    let (sx, sy) = (x_scale.map(*x), y_scale.map(*y));
+                    let columns = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+                    let rows = text.lines().count().max(1);
+                    box_dist(
+                        sx,
+                        sx + columns as f32 * font_size * 0.6,
+                        sy,
+                        sy + rows as f32 * font_size,
+                        true,
+                    )
+                }
+            };
+            if dist <= tolerance_px && best.is_none_or(|(d, _)| dist < d) {
+                best = Some((dist, *id));
+            }
+        }
+        best.map(|(_, id)| id)
+    }
+
     pub fn handle_mouse_down(
         &self,
         event: &MouseDownEvent,
@@ -44,38 +209,62 @@ impl ChartInputHandler {
         window.focus(&self.focus_handle);
         let p_bounds = self.pane_bounds.borrow().clone();
         self.chart.update(cx, |c, cx| {
-            for ps in c.panes.iter_mut() {
+            let mut clicked_series = None;
+            let mut clicked_annotation = None;
+            let mut fit_pane = None;
+            for (p_idx, ps) in c.panes.iter_mut().enumerate() {
                 if let Some(bounds) = p_bounds.get(&ps.id) {
                     if bounds.contains(&event.position) {
                         if event.click_count >= 2 {
                             // Auto-fit Y for this pane specifically
-                            let x_range = c.shared_x_axis.read(cx);
-                            let x_bounds = (x_range.min, x_range.max);
-                            for (a_idx, y_axis_state) in ps.y_axes.iter().enumerate() {
-                                let mut sy_min = f64::INFINITY;
-                                let mut sy_max = f64::NEG_INFINITY;
-                                for series in &ps.series {
-                                    if series.y_axis_id.0 != a_idx
-                                        || ps.hidden_series.contains(&series.id)
-                                    {
-                                        continue;
-                                    }
-                                    if let Some((s_min, s_max)) =
-                                        series.plot.read().get_y_range(x_bounds.0, x_bounds.1)
-                                    {
-                                        sy_min = sy_min.min(s_min);
-                                        sy_max = sy_max.max(s_max);
-                                    }
-                                }
-                                if sy_min != f64::INFINITY {
-                                    y_axis_state.entity.update(cx, |y, _| {
-                                        ViewController::auto_fit_axis(y, sy_min, sy_max, 0.05);
-                                        y.update_ticks_if_needed(10, None);
-                                    });
+                            fit_pane = Some(p_idx);
+                            break;
+                        }
+
+                        // Precedence: annotation > box zoom > pan
+                        if let Some(tolerance) = c.annotation_hit_tolerance {
+                            let y_domains: Vec<(f64, f64)> = ps
+                                .y_axes
+                                .iter()
+                                .map(|a| a.entity.read(cx).clamped_bounds())
+                                .collect();
+                            let hit = Self::annotation_under_cursor(
+                                ps,
+                                ps.x_axis_or(&c.shared_x_axis).read(cx).clamped_bounds(),
+                                &y_domains,
+                                *bounds,
+                                event.position,
+                                tolerance,
+                                c.shared_state.read(cx).gap_index.clone(),
+                            );
+                            if let Some(id) = hit {
+                                clicked_annotation = Some((id, ps.id.clone()));
+                                break;
+                            }
+                        }
+
+                        if event.button == MouseButton::Left {
+                            // A click close to a series selects it instead of starting a pan
+                            let state = c.shared_state.read(cx);
+                            if state.emphasis.enabled && state.emphasis.selectable {
+                                let y_domains: Vec<(f64, f64)> = ps
+                                    .y_axes
+                                    .iter()
+                                    .map(|a| a.entity.read(cx).clamped_bounds())
+                                    .collect();
+                                clicked_series = Self::series_under_cursor(
+                                    ps,
+                                    &[ps.x_axis_or(&c.shared_x_axis).read(cx).clamped_bounds()],
+                                    &y_domains,
+                                    *bounds,
+                                    event.position,
+                                    state.emphasis.hit_tolerance_px,
+                                    state.gap_index.as_deref(),
+                                );
+                                if clicked_series.is_some() {
+                                    break;
                                 }
                             }
-                            cx.notify();
-                            return;
                         }
 
                         match event.button {
@@ -103,6 +292,23 @@ impl ChartInputHandler {
                     }
                 }
             }
+            if let Some(p_idx) = fit_pane {
+                c.fit_pane_y(p_idx, cx);
+                c.user_interacted = false;
+                cx.notify();
+                return;
+            }
+            if let Some((id, pane_id)) = clicked_annotation {
+                if event.button == MouseButton::Left {
+                    c.begin_annotation_drag(id, pane_id, event.position);
+                }
+                cx.emit(ChartEvent::AnnotationSelected(id));
+                cx.notify();
+                return;
+            }
+            if clicked_series.is_some() {
+                c.set_selected_series(clicked_series, cx);
+            }
         });
     }
 
@@ -111,13 +317,13 @@ impl ChartInputHandler {
         event: &MouseMoveEvent,
         _win: &mut Window,
         cx: &mut App,
-        view_entity_id: EntityId,
     ) {
         let axis_bounds_rc = self.last_render_axis_bounds.clone();
         let pane_bounds_ref = self.pane_bounds.borrow(); 
         let container_bounds = self.bounds.borrow().clone();
         let bh = container_bounds.size.height.as_f32();
         let estimated_height = if bh > 0.0 { bh } else { 600.0 };
+        let max_velocity = self.inertia_config.max_velocity;
 
         struct PendingSharedState {
             mouse_pos: Option<Option<Point<Pixels>>>,
@@ -171,10 +377,17 @@ impl ChartInputHandler {
                 }
             }
             if c.dragging_splitter.is_some() && event.pressed_button != Some(MouseButton::Left) {
-                c.dragging_splitter = None;
-                c.last_mouse_y = None;
+                c.end_splitter_drag(cx);
                 chart_needs_notify = true;
             }
+            if c.dragging_annotation.is_some() {
+                if event.pressed_button != Some(MouseButton::Left) {
+                    c.end_annotation_drag(cx);
+                    chart_needs_notify = true;
+                } else {
+                    any_drag_active = true;
+                }
+            }
 
             if !any_drag_active && c.shared_state.read(cx).is_dragging {
                 pending.is_dragging = Some(false);
@@ -187,6 +400,9 @@ impl ChartInputHandler {
                     pending.hover_x = Some(None);
                     chart_needs_notify = true;
                 }
+                if c.hovered_series.take().is_some() {
+                    cx.notify();
+                }
                 return;
             }
 
@@ -195,14 +411,32 @@ impl ChartInputHandler {
                             let delta = event.position.y - last_y;
                             if delta.abs() > px(0.5) {
                                 let mut weights: Vec<f32> = c.panes.iter().map(|p| p.weight).collect();
-                                ViewController::resize_panes(
+                                let mut fixed: Vec<Option<f32>> = c
+                                    .panes
+                                    .iter()
+                                    .map(|p| p.fixed_height.map(|h| h.as_f32()))
+                                    .collect();
+                                let panes_height: f32 = c
+                                    .panes
+                                    .iter()
+                                    .filter_map(|p| pane_bounds_ref.get(&p.id))
+                                    .map(|b| b.size.height.as_f32())
+                                    .sum();
+                                let total_height = if panes_height > 0.0 {
+                                    panes_height
+                                } else {
+                                    estimated_height
+                                };
+                                ViewController::resize_mixed_panes(
                                     &mut weights,
+                                    &mut fixed,
                                     index,
                                     delta.as_f32(),
-                                    estimated_height,
+                                    total_height,
                                 );
                                 for (i, p) in c.panes.iter_mut().enumerate() {
                                     p.weight = weights[i];
+                                    p.fixed_height = fixed[i].map(px);
                                 }
                                 c.last_mouse_y = Some(event.position.y);
                                 chart_needs_notify = true;
@@ -269,6 +503,7 @@ impl ChartInputHandler {
                                     }
                                     r.update_ticks_if_needed(10, gaps.as_deref());
                                 });
+                                c.user_interacted = true;
                                 chart_needs_notify = true;
                             }
                             c.last_mouse_pos = Some(event.position);
@@ -276,6 +511,62 @@ impl ChartInputHandler {
                         return;
                     }
         
+                    if let Some(drag) = c.dragging_annotation.clone() {
+                        let (Some(ps), Some(bounds), Some(annotation)) = (
+                            c.panes.iter().find(|p| p.id == drag.pane_id),
+                            pane_bounds_ref.get(&drag.pane_id),
+                            c.annotation(drag.id).cloned(),
+                        ) else {
+                            c.dragging_annotation = None;
+                            return;
+                        };
+                        let gaps = c.shared_state.read(cx).gap_index.clone();
+                        let x_domain = ps.x_axis_or(&c.shared_x_axis).read(cx).clamped_bounds();
+                        // Y moves along the axis of the annotation series
+                        let axis = ps
+                            .series
+                            .iter()
+                            .find(|s| s.id == ANNOTATIONS_SERIES_ID)
+                            .map_or(0, |s| s.y_axis_id.0);
+                        let y_domain =
+                            ps.y_axes.get(axis).map(|a| a.entity.read(cx).clamped_bounds());
+                        let (w, h) = (bounds.size.width.as_f32(), bounds.size.height.as_f32());
+                        let x_at = |pos: Point<Pixels>| {
+                            ViewController::map_pixels_to_value(
+                                (pos.x - bounds.origin.x).as_f32(),
+                                w,
+                                x_domain.0,
+                                x_domain.1,
+                                false,
+                                gaps.as_deref(),
+                            )
+                        };
+                        let dx = x_at(event.position) - x_at(drag.last_pos);
+                        let dy = y_domain.map_or(0.0, |(lo, hi)| {
+                            if h > 0.0 {
+                                -(event.position.y - drag.last_pos.y).as_f32() as f64 / h as f64
+                                    * (hi - lo)
+                            } else {
+                                0.0
+                            }
+                        });
+                        if dx != 0.0 || dy != 0.0 {
+                            let mut annotation = annotation;
+                            annotation.translate(dx, dy);
+                            c.update_annotation(drag.id, annotation, cx);
+                            if let Some(d) = &mut c.dragging_annotation {
+                                d.last_pos = event.position;
+                                d.moved = true;
+                            }
+                            chart_needs_notify = true;
+                        }
+                        if chart_needs_notify {
+                            cx.notify();
+                        }
+                        return;
+                    }
+
+                    let mut dragged = false;
                     for ps in c.panes.iter_mut() {
                         if let Some(start) = ps.drag_start {
                             if let Some(bounds) = pane_bounds_ref.get(&ps.id) {
@@ -286,7 +577,7 @@ impl ChartInputHandler {
                                 match ps.drag_button {
                                     Some(MouseButton::Left) => {
                                         let gaps_x = gaps.clone();
-                                        c.shared_x_axis.update(cx, move |x, _| {
+                                        ps.x_axis_or(&c.shared_x_axis).update(cx, move |x, _| {
                                             ViewController::pan_axis(
                                                 x,
                                                 delta.x.as_f32(),
@@ -314,13 +605,21 @@ impl ChartInputHandler {
                                             ViewController::compute_zoom_factor(-delta.y.as_f32(), 100.0);
         
                                         let pivot_source = ps.initial_drag_start.unwrap_or(start);
-                                        let pivot_x =
-                                            (pivot_source.x - bounds.origin.x).as_f32() as f64 / pw as f64;
-                                        let pivot_y =
-                                            (pivot_source.y - bounds.origin.y).as_f32() as f64 / ph as f64;
+                                        let pivot_x = ViewController::zoom_pivot_pct(
+                                            pivot_source.x.as_f32(),
+                                            bounds.origin.x.as_f32(),
+                                            pw,
+                                            false,
+                                        );
+                                        let pivot_y = ViewController::zoom_pivot_pct(
+                                            pivot_source.y.as_f32(),
+                                            bounds.origin.y.as_f32(),
+                                            ph,
+                                            true,
+                                        );
         
                                         let gaps_x = gaps.clone();
-                                        c.shared_x_axis.update(cx, move |x, _| {
+                                        ps.x_axis_or(&c.shared_x_axis).update(cx, move |x, _| {
                                             ViewController::zoom_axis_at(
                                                 x,
                                                 pivot_x,
@@ -332,7 +631,7 @@ impl ChartInputHandler {
                                             y_axis.entity.update(cx, |y, _| {
                                                 ViewController::zoom_axis_at(
                                                     y,
-                                                    1.0 - pivot_y,
+                                                    pivot_y,
                                                     factor_y,
                                                     None,
                                                 );
@@ -349,18 +648,24 @@ impl ChartInputHandler {
                                             delta.x.as_f32() as f64 / dt,
                                             delta.y.as_f32() as f64 / dt,
                                         );
-                                        ps.velocity = Point::new(
+                                        let (vx, vy) = ViewController::clamp_velocity(
                                             ps.velocity.x * 0.3 + new_velocity.x * 0.7,
                                             ps.velocity.y * 0.3 + new_velocity.y * 0.7,
+                                            max_velocity,
                                         );
+                                        ps.velocity = Point::new(vx, vy);
                                     }
                                 }
                                 ps.drag_start = Some(event.position);
                                 ps.last_drag_time = Some(now);
+                                dragged = true;
                                 chart_needs_notify = true;
                             }
                         }
                     }
+                    if dragged {
+                        c.user_interacted = true;
+                    }
         
                     if c.shared_state.read(cx).box_zoom_start.is_some() {
                         pending.box_zoom_current = Some(event.position);
@@ -369,11 +674,12 @@ impl ChartInputHandler {
                     }
         
                     let mut inside_any_pane = false;
+                    let mut hovered_series = None;
                     for ps in &c.panes {
                         if let Some(bounds) = pane_bounds_ref.get(&ps.id) {
                             if bounds.contains(&event.position) {
                                 inside_any_pane = true;
-                                let x_range = c.shared_x_axis.read(cx);
+                                let x_range = ps.x_axis_or(&c.shared_x_axis).read(cx).clone();
                                 let gaps = c.shared_state.read(cx).gap_index.clone();
                                 let hover_x = ViewController::map_pixels_to_value(
                                     (event.position.x - bounds.origin.x).as_f32(),
@@ -388,7 +694,25 @@ impl ChartInputHandler {
                                 // Always track position and value so toggle works immediately
                                 pending.mouse_pos = Some(Some(event.position));
                                 pending.hover_x = Some(Some(hover_x));
-                                pending.active_chart_id = Some(Some(view_entity_id));
+                                pending.active_chart_id = Some(Some(cx.entity_id()));
+
+                                if current_state.emphasis.enabled {
+                                    let y_domains: Vec<(f64, f64)> = ps
+                                        .y_axes
+                                        .iter()
+                                        .map(|a| a.entity.read(cx).clamped_bounds())
+                                        .collect();
+                                    let hovered = Self::series_under_cursor(
+                                        ps,
+                                        &[x_range.clamped_bounds()],
+                                        &y_domains,
+                                        *bounds,
+                                        event.position,
+                                        current_state.emphasis.hit_tolerance_px,
+                                        gaps.as_deref(),
+                                    );
+                                    hovered_series = Some(hovered);
+                                }
                                 
                                 if current_state.crosshair_enabled {
                                     chart_needs_notify = true;
@@ -404,12 +728,20 @@ impl ChartInputHandler {
                         if !state.is_dragging {
                              pending.mouse_pos = Some(None);
                              pending.hover_x = Some(None);
+                             hovered_series = Some(None);
                              if state.mouse_pos.is_some() {
                                  chart_needs_notify = true;
                              }
                         }
                     }
                     
+                    if let Some(hovered) = hovered_series {
+                        if hovered != c.hovered_series {
+                            c.hovered_series = hovered;
+                            chart_needs_notify = true;
+                        }
+                    }
+
                     if chart_needs_notify {
                         cx.notify();
                     }
@@ -418,7 +750,11 @@ impl ChartInputHandler {
                 if pending.mouse_pos.is_some() || pending.hover_x.is_some() || pending.active_chart_id.is_some() || pending.box_zoom_current.is_some() || pending.is_dragging.is_some() {
                     let shared_state = self.chart.read(cx).shared_state.clone();
                     shared_state.update(cx, |s, _| {
-                        if let Some(mp) = pending.mouse_pos { s.mouse_pos = mp; }
+                        if let Some(mp) = pending.mouse_pos {
+                            s.mouse_pos = mp;
+                            // The mouse takes over from the keyboard crosshair
+                            s.crosshair_pinned = false;
+                        }
                         if let Some(hx) = pending.hover_x { s.hover_x = hx; }
                         if let Some(id) = pending.active_chart_id { s.active_chart_id = id; }
                         if let Some(bz) = pending.box_zoom_current { s.box_zoom_current = Some(bz); }
@@ -438,6 +774,7 @@ impl ChartInputHandler {
     ) {
         let p_bounds = self.pane_bounds.borrow().clone();
         let mut needs_inertia = false;
+        let inertia = &self.inertia_config;
 
         self.chart.update(cx, |c, cx| {
             if event.button == MouseButton::Right {
@@ -449,7 +786,8 @@ impl ChartInputHandler {
                         for ps in c.panes.iter() {
                             if let Some(bounds) = p_bounds.get(&ps.id) {
                                 if bounds.contains(&start) {
-                                    let x_range = c.shared_x_axis.read(cx);
+                                    let x_axis = ps.x_axis_or(&c.shared_x_axis);
+                                    let x_range = x_axis.read(cx);
                                     let x_scale = crate::scales::ChartScale::new_linear(
                                         x_range.clamped_bounds(),
                                         (0.0, bounds.size.width.as_f32()),
@@ -457,16 +795,18 @@ impl ChartInputHandler {
                                     let px1 = x_scale.invert((start.x - bounds.origin.x).as_f32());
                                     let px2 = x_scale.invert((end.x - bounds.origin.x).as_f32());
                                     if (px1 - px2).abs() > f64::EPSILON {
-                                        c.shared_x_axis.update(cx, |x, _| {
+                                        x_axis.update(cx, |x, _| {
                                             ViewController::auto_fit_axis(
                                                 x,
                                                 px1.min(px2),
                                                 px1.max(px2),
                                                 0.0,
                                             );
+                                            x.snap_to_data();
                                         });
                                     }
-                                    if let Some(y_axis) = ps.y_axes.first() {
+                                    // Each Y axis maps the box through its own scale
+                                    for y_axis in &ps.y_axes {
                                         let y_range = y_axis.entity.read(cx);
                                         let y_scale = crate::scales::ChartScale::new_linear(
                                             y_range.clamped_bounds(),
@@ -484,6 +824,7 @@ impl ChartInputHandler {
                                                     py1.max(py2),
                                                     0.0,
                                                 );
+                                                y.snap_to_data();
                                             });
                                         }
                                     }
@@ -491,6 +832,7 @@ impl ChartInputHandler {
                                 }
                             }
                         }
+                        c.user_interacted = true;
                     }
                 }
                 c.shared_state.update(cx, |s: &mut SharedPlotState, _| {
@@ -501,12 +843,21 @@ impl ChartInputHandler {
             }
 
             let now = std::time::Instant::now();
+            let mut clicked_empty_space = false;
             for ps in c.panes.iter_mut() {
                 if ps.drag_start.is_some() {
+                    // A left click that did not move clears the selection
+                    if ps.drag_button == Some(MouseButton::Left) {
+                        if let Some(initial) = ps.initial_drag_start {
+                            let moved = (event.position.x - initial.x).abs()
+                                + (event.position.y - initial.y).abs();
+                            clicked_empty_space |= moved < px(3.0);
+                        }
+                    }
                     ps.drag_start = None;
                     ps.initial_drag_start = None;
                     if let Some(last_time) = ps.last_drag_time {
-                        if now.duration_since(last_time).as_millis() > 50 {
+                        if !inertia.enabled || now.duration_since(last_time) > inertia.stop_threshold {
                             ps.velocity = Point::default();
                         }
                     }
@@ -515,10 +866,13 @@ impl ChartInputHandler {
                     }
                 }
             }
-            c.dragging_splitter = None;
+            if clicked_empty_space {
+                c.set_selected_series(None, cx);
+            }
+            c.end_splitter_drag(cx);
+            c.end_annotation_drag(cx);
             c.dragging_axis = None;
             c.last_mouse_pos = None;
-            c.last_mouse_y = None;
             c.shared_state.update(cx, |s: &mut SharedPlotState, _| {
                 s.is_dragging = false;
             });
@@ -547,16 +901,24 @@ impl ChartInputHandler {
                 if let Some(bounds) = p_bounds.get(&ps.id) {
                     let pw = bounds.size.width.as_f32() as f64;
                     let ph = bounds.size.height.as_f32() as f64;
-                    c.shared_x_axis.update(cx, |x, _| {
-                        x.pan(-ps.velocity.x * dt * (x.span() / pw));
-                        x.clamp();
+                    // Stop sliding along an axis as soon as it hits its limits
+                    let x_free = ps.x_axis_or(&c.shared_x_axis).update(cx, |x, _| {
+                        let delta = -ps.velocity.x * dt * (x.span() / pw);
+                        ViewController::pan_clamped(x, delta)
                     });
+                    if !x_free {
+                        ps.velocity.x = 0.0;
+                    }
+                    let mut y_free = true;
                     for y_axis in &ps.y_axes {
-                        y_axis.entity.update(cx, |y, _| {
-                            y.pan(ps.velocity.y * dt * (y.span() / ph));
-                            y.clamp();
+                        y_free &= y_axis.entity.update(cx, |y, _| {
+                            let delta = ps.velocity.y * dt * (y.span() / ph);
+                            ViewController::pan_clamped(y, delta)
                         });
                     }
+                    if !y_free {
+                        ps.velocity.y = 0.0;
+                    }
                 }
             }
             if active {
@@ -577,9 +939,11 @@ impl ChartInputHandler {
     ) {
         let p_bounds = self.pane_bounds.borrow().clone();
         self.chart.update(cx, |c, cx| {
+            let mut scrolled = false;
             for ps in &c.panes {
                 if let Some(bounds) = p_bounds.get(&ps.id) {
                     if bounds.contains(&event.position) {
+                        scrolled = true;
                         let is_zoom = event.modifiers.control || event.modifiers.platform;
                         let delta_y = match event.delta {
                             ScrollDelta::Pixels(p) => p.y.as_f32(),
@@ -587,11 +951,11 @@ impl ChartInputHandler {
                         };
                         let gaps = c.shared_state.read(cx).gap_index.clone();
                         if is_zoom {
-                            let factor = (1.0f64 - delta_y as f64 * 0.01).clamp(0.1, 10.0);
+                            let factor = self.zoom_config.wheel_factor(delta_y);
                             let mx_pct = (event.position.x - bounds.origin.x).as_f32() as f64
                                 / bounds.size.width.as_f32() as f64;
                             let gaps_x = gaps.clone();
-                            c.shared_x_axis.update(cx, move |x, _| {
+                            ps.x_axis_or(&c.shared_x_axis).update(cx, move |x, _| {
                                 ViewController::zoom_axis_at(x, mx_pct, factor, gaps_x.as_deref())
                             });
                             let my_pct = (event.position.y - bounds.origin.y).as_f32() as f64
@@ -607,7 +971,7 @@ impl ChartInputHandler {
                                 ScrollDelta::Lines(p) => p.x as f32 * 20.0,
                             };
                             let gaps_x = gaps.clone();
-                            c.shared_x_axis.update(cx, move |x, _| {
+                            ps.x_axis_or(&c.shared_x_axis).update(cx, move |x, _| {
                                 ViewController::pan_axis(
                                     x,
                                     delta_x,
@@ -632,6 +996,9 @@ impl ChartInputHandler {
                     }
                 }
             }
+            if scrolled {
+                c.user_interacted = true;
+            }
             cx.notify();
         });
     }
@@ -646,6 +1013,8 @@ impl Clone for ChartInputHandler {
             last_render_axis_bounds: self.last_render_axis_bounds.clone(),
             bounds: self.bounds.clone(),
             pane_bounds: self.pane_bounds.clone(),
+            zoom_config: self.zoom_config,
+            inertia_config: self.inertia_config.clone(),
         }
     }
 }
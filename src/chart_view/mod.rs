@@ -3,15 +3,19 @@ pub mod input;
 pub mod actions;
 
 use crate::chart::Chart;
-use crate::data_types::{InertiaConfig, LegendConfig};
+use crate::data_types::{CrosshairConfig, InertiaConfig, LegendConfig, ZoomConfig};
 use gpui::prelude::*;
 use gpui::*;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
-pub use renderer::AxisKey;
-pub use actions::{PanLeft, PanRight, PanUp, PanDown, ZoomIn, ZoomOut, ResetView, ToggleDebug, ToggleCrosshair};
+pub use renderer::{AxisKey, ProbeResult};
+pub use actions::{
+    AutoFitY, CycleAggregationMode, FitX, FollowLatest, JumpCrosshairLeft, JumpCrosshairRight, PanDown, PanLeft, PanRight, PanUp, ResetView,
+    StepCrosshairLeft, StepCrosshairRight, ToggleCrosshair, ToggleDebug, ZoomIn, ZoomOut,
+    CROSSHAIR_JUMP_STEPS,
+};
 
 use self::renderer::ChartRenderer;
 use self::input::ChartInputHandler;
@@ -27,6 +31,10 @@ pub struct ChartView {
 
     // Configuration exposed for modification (needs to sync with renderer)
     pub inertia_config: InertiaConfig,
+
+    /// Explicit rect to render into instead of filling the container (see
+    /// `set_target_bounds`).
+    target_bounds: Option<Bounds<Pixels>>,
     
     focus_handle: FocusHandle,
 }
@@ -41,8 +49,11 @@ impl ChartView {
     pub fn new(chart: Entity<Chart>, cx: &mut Context<Self>) -> Self {
         cx.observe(&chart, |_, _, cx| cx.notify()).detach();
         let shared_state = chart.read(cx).shared_state.clone();
-        cx.observe(&shared_state, |_, _, cx| {
-            eprintln!("DEBUG: ChartView observed shared_state update");
+        cx.observe(&shared_state, |_, state, cx| {
+            // Diagnostics only when the debug overlay is on, and only for a tracing subscriber
+            if state.read(cx).debug_mode {
+                tracing::trace!("ChartView observed shared_state update");
+            }
             cx.notify()
         }).detach();
 
@@ -76,6 +87,7 @@ impl ChartView {
             input,
             actions,
             inertia_config: InertiaConfig::default(),
+            target_bounds: None,
             focus_handle,
         }
     }
@@ -84,12 +96,63 @@ impl ChartView {
     pub fn set_legend_config(&mut self, config: LegendConfig) {
         self.renderer.legend_config = config;
     }
+
+    pub fn set_crosshair_config(&mut self, config: CrosshairConfig) {
+        self.renderer.crosshair_config = config;
+    }
+
+    pub fn set_zoom_config(&mut self, config: ZoomConfig) {
+        self.renderer.zoom_config = config;
+        self.input.zoom_config = config;
+    }
+
+    /// Renders the chart into `bounds` instead of filling its container; `None`
+    /// restores `size_full()`.
+    ///
+    /// The origin is relative to the parent element, and the chart is
+    /// absolutely positioned: it is taken out of the parent's flex flow, so it
+    /// neither takes space from its siblings nor stretches with the parent,
+    /// and gutters, axes and panes are laid out against this exact size. The
+    /// parent should be `relative()` for the origin to apply to it (otherwise
+    /// it is resolved against the nearest positioned ancestor) and is not
+    /// resized to contain the chart. As the root view of a window only the
+    /// size applies, since the root element is always placed at the window
+    /// origin. Useful for sub-rects of a larger layout
+    /// and for rendering at a fixed size in golden-image tests.
+    pub fn set_target_bounds(&mut self, bounds: Option<Bounds<Pixels>>) {
+        self.target_bounds = bounds;
+    }
+
+    pub fn target_bounds(&self) -> Option<Bounds<Pixels>> {
+        self.target_bounds
+    }
+
+    /// Data-space coordinates and series values under `point` (window
+    /// coordinates), for tooltips or HUDs drawn by the host. Read-only, unlike
+    /// the hover handling; None before the first render or outside the panes.
+    pub fn probe(&self, point: Point<Pixels>, cx: &App) -> Option<ProbeResult> {
+        ChartRenderer::probe(
+            self.chart.read(cx),
+            &self.renderer.pane_bounds.borrow(),
+            point,
+            cx,
+        )
+    }
 }
 
 impl Render for ChartView {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         // Delegate rendering to renderer
-        let element = self.renderer.render(window, cx);
+        let mut element = self.renderer.render(window, cx);
+        if let Some(b) = self.target_bounds {
+            element = element
+                .absolute()
+                .left(b.origin.x)
+                .top(b.origin.y)
+                .w(b.size.width)
+                .h(b.size.height);
+        }
+        self.input.inertia_config = self.inertia_config.clone();
         
         // Attach event listeners here using the input handler and action handler
         // Since input and actions are stored in self, we need to clone them or pass references.
@@ -99,7 +162,12 @@ impl Render for ChartView {
         let input = self.input.clone();
         let actions = self.actions.clone();
         let entity_id = cx.entity_id();
-        
+
+        // Sparklines are display-only
+        if self.chart.read(cx).sparkline {
+            return element.id(("chart-view", entity_id)).into_any_element();
+        }
+
         element
             .id(("chart-view", entity_id))
             .track_focus(&self.focus_handle)
@@ -117,7 +185,7 @@ impl Render for ChartView {
             })
             .on_mouse_move({
                 let input = input.clone();
-                move |e, w, c| input.handle_global_mouse_move(e, w, c, entity_id)
+                move |e, w, c| input.handle_global_mouse_move(e, w, c)
             })
             .on_mouse_up(MouseButton::Left, {
                 let input = input.clone();
@@ -155,13 +223,46 @@ impl Render for ChartView {
                 let actions = actions.clone();
                 move |a, w, c| actions.handle_reset_view(a, w, c)
             })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_auto_fit_y(a, w, c)
+            })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_fit_x(a, w, c)
+            })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_follow_latest(a, w, c)
+            })
             .on_action({
                 let actions = actions.clone();
                 move |a, w, c| actions.handle_toggle_debug(a, w, c)
             })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_cycle_aggregation_mode(a, w, c)
+            })
             .on_action({
                 let actions = actions.clone();
                 move |a, w, c| actions.handle_toggle_crosshair(a, w, c)
             })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_step_crosshair_left(a, w, c)
+            })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_step_crosshair_right(a, w, c)
+            })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_jump_crosshair_left(a, w, c)
+            })
+            .on_action({
+                let actions = actions.clone();
+                move |a, w, c| actions.handle_jump_crosshair_right(a, w, c)
+            })
+            .into_any_element()
     }
 }
@@ -1,7 +1,10 @@
 use crate::utils::PixelsExt;
 use crate::axis_renderer::AxisRenderer;
 use crate::chart::Chart;
-use crate::data_types::{AxisEdge, AxisRange, LegendConfig, LegendPosition, Orientation};
+use crate::data_types::{
+    Annotation, AxisEdge, AxisFormat, AxisRange, CrosshairConfig, CrosshairMode, LegendConfig,
+    LegendPosition, Orientation, SharedPlotState, TickCount, ZoomConfig,
+};
 use crate::gutter_manager::GutterManager;
 use crate::theme::ChartTheme;
 use crate::Series;
@@ -27,9 +30,25 @@ impl AxisKey {
     }
 }
 
+/// Data under a screen point, see `ChartView::probe`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbeResult {
+    /// Index of the pane under the point
+    pub pane_idx: usize,
+    /// X in data units, on the pane's X axis
+    pub data_x: f64,
+    /// Value of each visible series of the pane at `data_x`, interpolated
+    /// between points (close for OHLCV). None outside the series' data.
+    pub per_series: Vec<(String, Option<f64>)>,
+    /// Y in data units on each Y axis of the pane, by axis index
+    pub data_y_by_axis: Vec<f64>,
+}
+
 pub struct ChartRenderer {
     pub chart: Entity<Chart>,
     pub legend_config: LegendConfig,
+    pub crosshair_config: CrosshairConfig,
+    pub zoom_config: ZoomConfig,
     
     // Bounds shared with InputHandler
     pub last_render_axis_bounds: Rc<RefCell<HashMap<String, Bounds<Pixels>>>>,
@@ -41,6 +60,7 @@ pub struct ChartRenderer {
     gutter_right: Pixels,
     gutter_top: Pixels,
     gutter_bottom: Pixels,
+    padding: Edges<Pixels>,
 }
 
 impl ChartRenderer {
@@ -53,6 +73,8 @@ impl ChartRenderer {
         Self {
             chart,
             legend_config: LegendConfig::default(),
+            crosshair_config: CrosshairConfig::default(),
+            zoom_config: ZoomConfig::default(),
             last_render_axis_bounds,
             bounds,
             pane_bounds,
@@ -60,15 +82,27 @@ impl ChartRenderer {
             gutter_right: px(0.0),
             gutter_top: px(0.0),
             gutter_bottom: px(0.0),
+            padding: Edges::default(),
         }
     }
 
-    fn calculate_gutters(&mut self, x_axes: &[crate::chart::AxisState], panes: &[crate::chart::PaneState]) {
-        let g = GutterManager::calculate(panes, x_axes);
+    fn calculate_gutters(
+        &mut self,
+        x_axes: &[crate::chart::AxisState],
+        panes: &[crate::chart::PaneState],
+        padding: Edges<Pixels>,
+        sparkline: bool,
+    ) {
+        let g = if sparkline {
+            GutterManager::padding_only(padding)
+        } else {
+            GutterManager::calculate(panes, x_axes, padding)
+        };
         self.gutter_left = g.left;
         self.gutter_right = g.right;
         self.gutter_top = g.top;
         self.gutter_bottom = g.bottom;
+        self.padding = g.padding;
     }
 
     pub fn render_control_button(
@@ -139,6 +173,7 @@ impl ChartRenderer {
             return None;
         }
         let is_vertical = self.legend_config.orientation == Orientation::Vertical;
+        let show_buttons = self.legend_config.show_buttons;
         let mut name_col_children = vec![];
         let mut btn_col_children = vec![];
         let mut horiz_items = vec![];
@@ -157,11 +192,29 @@ impl ChartRenderer {
             let s_enabled = is_isolated || on_axis_0 > 1;
 
             let name_el = div()
+                .id(SharedString::from(format!("legend-{}-{}", pane_idx, id)))
                 .h_5()
                 .flex()
                 .items_center()
                 .gap_1()
                 .cursor_pointer()
+                .on_hover({
+                    let id = id.clone();
+                    let chart = chart_handle.clone();
+                    move |hovered: &bool, _, cx| {
+                        chart.update(cx, |c, cx| {
+                            if !c.shared_state.read(cx).emphasis.enabled {
+                                return;
+                            }
+                            if *hovered {
+                                c.hovered_series = Some(id.clone());
+                            } else if c.hovered_series.as_ref() == Some(&id) {
+                                c.hovered_series = None;
+                            }
+                            cx.notify();
+                        });
+                    }
+                })
                 .on_mouse_down(MouseButton::Left, {
                     let id = id.clone();
                     let chart = chart_handle.clone();
@@ -201,47 +254,49 @@ impl ChartRenderer {
                         .child(id.clone()),
                 );
 
-            let btn_el = div()
-                .h_5()
-                .flex()
-                .items_center()
-                .gap_1()
-                .child({
-                    let id = id.clone();
-                    let chart = chart_handle.clone();
-                    Self::render_legend_button("▲", pane_idx > 0, move |_, _, cx| {
-                        cx.stop_propagation();
-                        chart.update(cx, |c, cx| c.move_series(pane_idx, pane_idx - 1, &id, cx));
+            let btn_el = show_buttons.then(|| {
+                div()
+                    .h_5()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .child({
+                        let id = id.clone();
+                        let chart = chart_handle.clone();
+                        Self::render_legend_button("▲", pane_idx > 0, move |_, _, cx| {
+                            cx.stop_propagation();
+                            chart.update(cx, |c, cx| c.move_series(pane_idx, pane_idx - 1, &id, cx));
+                        })
                     })
-                })
-                .child({
-                    let id = id.clone();
-                    let chart = chart_handle.clone();
-                    Self::render_legend_button("▼", pane_idx < pane_count - 1, move |_, _, cx| {
-                        cx.stop_propagation();
-                        chart.update(cx, |c, cx| c.move_series(pane_idx, pane_idx + 1, &id, cx));
+                    .child({
+                        let id = id.clone();
+                        let chart = chart_handle.clone();
+                        Self::render_legend_button("▼", pane_idx < pane_count - 1, move |_, _, cx| {
+                            cx.stop_propagation();
+                            chart.update(cx, |c, cx| c.move_series(pane_idx, pane_idx + 1, &id, cx));
+                        })
                     })
-                })
-                .child({
-                    let id = id.clone();
-                    let chart = chart_handle.clone();
-                    Self::render_legend_button("S", s_enabled, move |_, _, cx| {
-                        cx.stop_propagation();
-                        chart.update(cx, |c, cx| c.toggle_series_isolation(pane_idx, &id, cx));
+                    .child({
+                        let id = id.clone();
+                        let chart = chart_handle.clone();
+                        Self::render_legend_button("S", s_enabled, move |_, _, cx| {
+                            cx.stop_propagation();
+                            chart.update(cx, |c, cx| c.toggle_series_isolation(pane_idx, &id, cx));
+                        })
                     })
-                })
-                .child({
-                    let id = id.clone();
-                    let chart = chart_handle.clone();
-                    Self::render_legend_button("✕", true, move |_, _, cx| {
-                        cx.stop_propagation();
-                        chart.update(cx, |c, cx| c.remove_series_by_id(id.clone(), cx));
+                    .child({
+                        let id = id.clone();
+                        let chart = chart_handle.clone();
+                        Self::render_legend_button("✕", true, move |_, _, cx| {
+                            cx.stop_propagation();
+                            chart.update(cx, |c, cx| c.remove_series_by_id(id.clone(), cx));
+                        })
                     })
-                });
+            });
 
             if is_vertical {
                 name_col_children.push(name_el.into_any_element());
-                btn_col_children.push(btn_el.into_any_element());
+                btn_col_children.extend(btn_el.map(|el| el.into_any_element()));
             } else {
                 horiz_items.push(
                     div()
@@ -249,7 +304,7 @@ impl ChartRenderer {
                         .items_center()
                         .gap_2()
                         .child(name_el)
-                        .child(btn_el)
+                        .children(btn_el)
                         .into_any_element(),
                 );
             }
@@ -281,52 +336,279 @@ impl ChartRenderer {
         }
     }
 
+    /// Maps `point` to data space using the pane bounds of the last render,
+    /// without touching any state. None outside every pane.
+    pub fn probe(
+        chart: &Chart,
+        pane_bounds: &HashMap<String, Bounds<Pixels>>,
+        point: Point<Pixels>,
+        cx: &App,
+    ) -> Option<ProbeResult> {
+        let (pane_idx, ps, bounds) = chart.panes.iter().enumerate().find_map(|(i, ps)| {
+            let b = pane_bounds.get(&ps.id)?;
+            b.contains(&point).then_some((i, ps, b))
+        })?;
+
+        let gaps = chart.shared_state.read(cx).gap_index.clone();
+        let x_scale = crate::scales::ChartScale::new_linear(
+            ps.x_axis_or(&chart.shared_x_axis).read(cx).clamped_bounds(),
+            (0.0, bounds.size.width.as_f32()),
+        )
+        .with_gaps(gaps);
+        let data_x = x_scale.invert((point.x - bounds.origin.x).as_f32());
+
+        let per_series = ps
+            .series
+            .iter()
+            .filter(|s| !ps.hidden_series.contains(&s.id))
+            .map(|s| (s.id.clone(), s.plot.read().value_at(data_x)))
+            .collect();
+        let data_y_by_axis = ps
+            .y_axes
+            .iter()
+            .map(|a| {
+                crate::scales::ChartScale::new_linear(
+                    a.entity.read(cx).clamped_bounds(),
+                    (bounds.size.height.as_f32(), 0.0),
+                )
+                .invert((point.y - bounds.origin.y).as_f32())
+            })
+            .collect();
+
+        Some(ProbeResult {
+            pane_idx,
+            data_x,
+            per_series,
+            data_y_by_axis,
+        })
+    }
+
+    /// Crosshair Y values for a mouse at `mouse_y`, as (pane index, axis index, value):
+    /// every Y axis of the pane under the cursor, none for the other panes.
+    pub fn crosshair_y_values(
+        panes: &[crate::chart::PaneState],
+        pane_bounds: &HashMap<String, Bounds<Pixels>>,
+        mouse_y: Pixels,
+        cx: &App,
+    ) -> Vec<(usize, usize, f64)> {
+        let Some((p_idx, ps, bounds)) = panes.iter().enumerate().find_map(|(i, ps)| {
+            let b = pane_bounds.get(&ps.id)?;
+            (mouse_y >= b.origin.y && mouse_y <= b.origin.y + b.size.height).then_some((i, ps, b))
+        }) else {
+            return Vec::new();
+        };
+
+        ps.y_axes
+            .iter()
+            .enumerate()
+            .map(|(a_idx, y_a)| {
+                let scale = crate::scales::ChartScale::new_linear(
+                    y_a.entity.read(cx).clamped_bounds(),
+                    (bounds.size.height.as_f32(), 0.0),
+                );
+                (p_idx, a_idx, scale.invert((mouse_y - bounds.origin.y).as_f32()))
+            })
+            .collect()
+    }
+
+    /// Series value the horizontal crosshair snaps to in
+    /// `CrosshairMode::NearestSeriesY`: among the visible series of the pane
+    /// under `mouse_y`, the one whose value at `hover_x` is on screen and
+    /// closest to the cursor. Returns (pane index, axis index, value, screen Y).
+    pub fn nearest_series_y(
+        panes: &[crate::chart::PaneState],
+        pane_bounds: &HashMap<String, Bounds<Pixels>>,
+        hover_x: f64,
+        mouse_y: Pixels,
+        cx: &App,
+    ) -> Option<(usize, usize, f64, Pixels)> {
+        let (p_idx, ps, bounds) = panes.iter().enumerate().find_map(|(i, ps)| {
+            let b = pane_bounds.get(&ps.id)?;
+            (mouse_y >= b.origin.y && mouse_y <= b.origin.y + b.size.height).then_some((i, ps, b))
+        })?;
+
+        ps.series
+            .iter()
+            .filter(|s| !ps.hidden_series.contains(&s.id))
+            .filter_map(|s| {
+                let a_idx = s.y_axis_id.0;
+                let y_a = ps.y_axes.get(a_idx)?;
+                let value = s.plot.read().value_at(hover_x)?;
+                let scale = crate::scales::ChartScale::new_linear(
+                    y_a.entity.read(cx).clamped_bounds(),
+                    (bounds.size.height.as_f32(), 0.0),
+                );
+                let y = bounds.origin.y + px(scale.map(value));
+                let on_screen = y >= bounds.origin.y && y <= bounds.origin.y + bounds.size.height;
+                on_screen.then_some((p_idx, a_idx, value, y))
+            })
+            .min_by(|a, b| {
+                let distance = |y: Pixels| (y - mouse_y).abs().as_f32();
+                distance(a.3).total_cmp(&distance(b.3))
+            })
+    }
+
+    /// Value readout shown next to the crosshair (see `readout_visible`): the X value
+    /// followed by the value of each visible series at that X, or the
+    /// open/high/low/close (and volume) of the candle under it for OHLCV series.
+    fn render_crosshair_readout(
+        &self,
+        ps: &crate::chart::PaneState,
+        hx: f64,
+        x_axis: &crate::chart::AxisState,
+        shared_state: &SharedPlotState,
+        theme: &ChartTheme,
+        cx: &App,
+    ) -> Option<AnyElement> {
+        let pane_bounds = *self.pane_bounds.borrow().get(&ps.id)?;
+        let mut x_scale = crate::scales::ChartScale::new_linear(
+            x_axis.entity.read(cx).clamped_bounds(),
+            (0.0, pane_bounds.size.width.as_f32()),
+        );
+        if let Some(gaps) = &shared_state.gap_index {
+            x_scale = x_scale.with_gaps(Some(gaps.clone()));
+        }
+
+        let mut lines = vec![x_scale.format_tick(hx, &x_axis.format)];
+        for series in ps.series.iter().filter(|s| !ps.hidden_series.contains(&s.id)) {
+            let plot = series.plot.read();
+            let candle = plot.ohlcv_at(hx);
+            let Some(value) = candle.as_ref().map(|c| c.close).or_else(|| plot.value_at(hx)) else {
+                continue;
+            };
+            let y_format = ps
+                .y_axes
+                .get(series.y_axis_id.0)
+                .map(|a| a.format)
+                .unwrap_or(AxisFormat::Numeric);
+            let y_range = ps
+                .y_axes
+                .get(series.y_axis_id.0)
+                .map(|a| a.entity.read(cx).clamped_bounds())
+                .unwrap_or((0.0, 1.0));
+            let y_scale = crate::scales::ChartScale::new_linear(y_range, (1.0, 0.0));
+            let fmt = |v: f64| series.format_readout(v, |v| y_scale.format_tick(v, &y_format));
+            lines.push(match candle {
+                Some(c) => {
+                    let mut line = format!(
+                        "{}: O {} H {} L {} C {}",
+                        series.id,
+                        fmt(c.open),
+                        fmt(c.high),
+                        fmt(c.low),
+                        fmt(c.close)
+                    );
+                    if c.volume != 0.0 {
+                        line.push_str(&format!(" V {}", compact_volume(c.volume)));
+                    }
+                    line
+                }
+                None => format!("{}: {}", series.id, fmt(value)),
+            });
+        }
+
+        let sx = px(x_scale.map(hx));
+        Some(
+            div()
+                .absolute()
+                .top(px(4.0))
+                .left(sx + px(8.0))
+                .bg(theme.tag_background)
+                .text_color(theme.tag_text)
+                .rounded_sm()
+                .p_1()
+                .text_size(px(11.0))
+                .flex()
+                .flex_col()
+                .children(lines)
+                .into_any_element(),
+        )
+    }
+
     pub fn render<V: 'static>(&mut self, window: &mut Window, cx: &mut Context<V>) -> Div {
         let start_time = std::time::Instant::now();
 
         let chart_handle = self.chart.clone();
 
-        let (panes, x_axes, theme, shared_state_handle) = {
+        let (
+            panes,
+            x_axes,
+            theme,
+            shared_state_handle,
+            loading,
+            follow_paused,
+            render_history,
+            show_pane_controls,
+        ) = {
             let chart = chart_handle.read(cx);
             (
                 chart.panes.clone(),
                 chart.x_axes.clone(),
                 chart.theme.clone(),
                 chart.shared_state.clone(),
+                chart.loading,
+                chart.follow_latest.is_some() && !chart.following,
+                chart.render_history.clone(),
+                chart.show_pane_controls,
             )
         };
 
-        let shared_state = shared_state_handle.read(cx).clone();
+        let mut shared_state = shared_state_handle.read(cx).clone();
+        // Hover and selection emphasis are per chart, not shared with the group
+        let chart = chart_handle.read(cx);
+        shared_state.hovered_series = chart.hovered_series.clone();
+        shared_state.selected_series = chart.selected_series.clone();
 
         // Debug mode frame request is handled by the View via notify/update generally, 
         // but here we can schedule next frame if needed.
-        if shared_state.debug_mode {
+        if shared_state.repaints_continuously() {
             cx.on_next_frame(window, move |_, _, cx| {
                 cx.notify();
             });
         }
 
-        let shared_x_axis = chart_handle.read(cx).shared_x_axis.clone();
+        let (shared_x_axis, plot_padding, sparkline, show_overflow) = {
+            let chart = chart_handle.read(cx);
+            (
+                chart.shared_x_axis.clone(),
+                chart.plot_padding,
+                chart.sparkline,
+                chart.show_overflow_indicators,
+            )
+        };
 
-        self.calculate_gutters(&x_axes, &panes);
-        let total_weight: f32 = panes.iter().map(|p| p.weight).sum();
+        self.calculate_gutters(&x_axes, &panes, plot_padding, sparkline);
+        // Sparklines have no axes to render
+        let axis_panes = if sparkline { &[][..] } else { &panes[..] };
+        let rendered_x_axes = if sparkline { &[][..] } else { &x_axes[..] };
+        // Fixed panes keep their height, the others share the rest by weight.
+        // Panes and Y axis columns are laid out alike so they stay aligned.
+        let weighted_sum: f32 =
+            panes.iter().filter(|p| p.fixed_height.is_none()).map(|p| p.weight).sum();
+        let pane_slots: Vec<PaneSlot> = panes
+            .iter()
+            .map(|p| match p.fixed_height {
+                Some(h) => PaneSlot::Fixed(h.max(px(0.0))),
+                None if weighted_sum > 0.0 => PaneSlot::Weighted(p.weight),
+                None => PaneSlot::Weighted(1.0),
+            })
+            .collect();
         let container_bounds_rc = self.bounds.clone();
         let pane_bounds_rc = self.pane_bounds.clone();
         let last_render_axis_bounds = self.last_render_axis_bounds.clone();
 
         let hover_x = shared_state.hover_x;
         let mouse_pos = shared_state.mouse_pos;
+        // Sparklines never draw the crosshair, whatever the shared state says
+        let crosshair_shown =
+            !sparkline && shared_state.crosshair_visible_on(chart_handle.entity_id());
 
         let mut left_y_axis_elements = Vec::new();
         let mut right_y_axis_elements = Vec::new();
-        let mut current_top_pct = 0.0;
 
-        for (pane_idx, p) in panes.iter().enumerate() {
-            let h_pct = if total_weight > 0.0 {
-                p.weight / total_weight
-            } else {
-                1.0 / panes.len() as f32
-            };
+        for (pane_idx, p) in axis_panes.iter().enumerate() {
+            let mut left_axes = Vec::new();
+            let mut right_axes = Vec::new();
             let mut left_cursor = px(0.0);
             let mut right_cursor = px(0.0);
             let pane_id = p.id.clone();
@@ -364,12 +646,11 @@ impl ChartRenderer {
                     &axis_entity.read(cx),
                     axis.edge,
                     axis.size,
-                    h_pct,
-                    current_top_pct,
                     x_pos,
                     axis.label.clone(),
                     axis.format,
                     axis.min_label_spacing,
+                    axis.tick_count,
                     &theme,
                     None,
                     {
@@ -404,51 +685,41 @@ impl ChartRenderer {
                             chart.update(cx, |c, cx| {
                                 c.dragging_axis = None;
                                 if let Some(ps) = c.panes.iter().find(|p| p.id == p_id) {
-                                    let x_range = c.shared_x_axis.read(cx);
+                                    let x_range = ps.x_axis_or(&c.shared_x_axis).read(cx).clone();
                                     let x_bounds = (x_range.min, x_range.max);
                                     if let Some(y_axis_state) = ps.y_axes.get(axis_idx) {
-                                        let mut sy_min = f64::INFINITY;
-                                        let mut sy_max = f64::NEG_INFINITY;
-                                        for series in &ps.series {
-                                            if ps.hidden_series.contains(&series.id)
-                                                || series.y_axis_id.0 != axis_idx
-                                            {
-                                                continue;
-                                            }
-                                            if let Some((s_min, s_max)) = series
-                                                .plot
-                                                .read()
-                                                .get_y_range(x_bounds.0, x_bounds.1)
-                                            {
-                                                sy_min = sy_min.min(s_min);
-                                                sy_max = sy_max.max(s_max);
-                                            }
-                                        }
-                                        if sy_min != f64::INFINITY {
+                                        if let Some((sy_min, sy_max)) = ps.y_range_on_axis(
+                                            axis_idx, x_bounds.0, x_bounds.1, false,
+                                        ) {
+                                            let padding = c.autofit_padding;
                                             y_axis_state.entity.update(cx, |y, _| {
-                                                crate::view_controller::ViewController::auto_fit_axis(
-                                                    y, sy_min, sy_max, 0.05,
+                                                crate::view_controller::ViewController::auto_fit_axis_padded(
+                                                    y, sy_min, sy_max, padding,
                                                 );
                                                 y.update_ticks_if_needed(10, None);
                                             });
                                         }
                                     }
                                 }
+                                c.user_interacted = false;
                                 cx.notify();
                             });
                             return;
                         }
                         if let Some(bounds) = lab.borrow().get(&key) {
-                            let pct = ((event.position.y - bounds.origin.y).as_f32()
-                                / bounds.size.height.as_f32())
-                            .clamp(0.0, 1.0) as f64;
+                            let pivot_pct = crate::view_controller::ViewController::zoom_pivot_pct(
+                                event.position.y.as_f32(),
+                                bounds.origin.y.as_f32(),
+                                bounds.size.height.as_f32(),
+                                true,
+                            );
                             chart.update(cx, |c, cx| {
                                 c.dragging_axis = Some(crate::chart::AxisDragInfo {
                                     pane_id: Some(p_id.clone()),
                                     axis_idx,
                                     is_y: true,
                                     button: MouseButton::Left,
-                                    pivot_pct: 1.0 - pct,
+                                    pivot_pct,
                                 });
                                 c.last_mouse_pos = Some(event.position);
                                 cx.notify();
@@ -464,16 +735,19 @@ impl ChartRenderer {
                     cx.listener(move |_, event: &MouseDownEvent, _, cx| {
                         cx.stop_propagation();
                         if let Some(bounds) = lab.borrow().get(&key) {
-                            let pct = ((event.position.y - bounds.origin.y).as_f32()
-                                / bounds.size.height.as_f32())
-                            .clamp(0.0, 1.0) as f64;
+                            let pivot_pct = crate::view_controller::ViewController::zoom_pivot_pct(
+                                event.position.y.as_f32(),
+                                bounds.origin.y.as_f32(),
+                                bounds.size.height.as_f32(),
+                                true,
+                            );
                             chart.update(cx, |c, cx| {
                                 c.dragging_axis = Some(crate::chart::AxisDragInfo {
                                     pane_id: Some(p_id.clone()),
                                     axis_idx,
                                     is_y: true,
                                     button: MouseButton::Middle,
-                                    pivot_pct: 1.0 - pct,
+                                    pivot_pct,
                                 });
                                 c.last_mouse_pos = Some(event.position);
                                 cx.notify();
@@ -483,13 +757,14 @@ impl ChartRenderer {
                 })
                 .on_scroll_wheel({
                     let axis_entity = axis_entity.clone();
+                    let zoom_config = self.zoom_config;
                     move |event, _, cx| {
                         cx.stop_propagation();
                         let dy = match event.delta {
                             ScrollDelta::Pixels(p) => p.y.as_f32(),
                             ScrollDelta::Lines(p) => p.y as f32 * 20.0,
                         };
-                        let factor = (1.0f64 - dy as f64 * 0.01).clamp(0.1, 10.0);
+                        let factor = zoom_config.wheel_factor(dy);
                         axis_entity.update(cx, |r, _| {
                             crate::view_controller::ViewController::zoom_axis_at(r, 0.5, factor, None);
                         });
@@ -497,18 +772,28 @@ impl ChartRenderer {
                 })
                 .into_any_element();
                 if is_left {
-                    left_y_axis_elements.push(el);
+                    left_axes.push(el);
                 } else {
-                    right_y_axis_elements.push(el);
+                    right_axes.push(el);
+                }
+            }
+            let is_last = pane_idx == axis_panes.len() - 1;
+            for (column, axes) in [
+                (&mut left_y_axis_elements, left_axes),
+                (&mut right_y_axis_elements, right_axes),
+            ] {
+                let slot = pane_slots[pane_idx].apply(div()).relative().children(axes);
+                column.push(slot.into_any_element());
+                if !is_last {
+                    column.push(div().h(SPLITTER_HEIGHT).flex_none().into_any_element());
                 }
             }
-            current_top_pct += h_pct;
         }
 
         let mut x_axis_elements = Vec::new();
         let mut top_cursor = px(0.0);
         let mut bot_cursor = px(0.0);
-        for (axis_idx, x_axis) in x_axes.iter().enumerate() {
+        for (axis_idx, x_axis) in rendered_x_axes.iter().enumerate() {
             let axis_entity = x_axis.entity.clone();
             let key = AxisKey::X(axis_idx).key();
             let chart = chart_handle.clone();
@@ -522,6 +807,7 @@ impl ChartRenderer {
                 x_axis.label.clone(),
                 x_axis.format,
                 x_axis.min_label_spacing,
+                x_axis.tick_count,
                 &theme,
                 shared_state.gap_index.clone(),
                 {
@@ -548,40 +834,24 @@ impl ChartRenderer {
                     if event.click_count >= 2 {
                         chart.update(cx, |c, cx| {
                             c.dragging_axis = None;
-                            let mut x_min = f64::INFINITY;
-                            let mut x_max = f64::NEG_INFINITY;
-                            for ps in &c.panes {
-                                for s in &ps.series {
-                                    if let Some((sx_min, sx_max, _, _)) =
-                                        s.plot.read().get_min_max()
-                                    {
-                                        x_min = x_min.min(sx_min);
-                                        x_max = x_max.max(sx_max);
-                                    }
-                                }
-                            }
-                            if x_min != f64::INFINITY {
-                                let gaps = c.shared_state.read(cx).gap_index.clone();
-                                c.shared_x_axis.update(cx, move |r, _| {
-                                    crate::view_controller::ViewController::auto_fit_axis(r, x_min, x_max, 0.05);
-                                    r.update_ticks_if_needed(10, gaps.as_deref());
-                                });
-                            }
-                            cx.notify();
+                            c.fit_x_to_data(cx);
                         });
                         return;
                     }
                     if let Some(bounds) = lab.borrow().get(&key) {
-                        let pct = ((event.position.x - bounds.origin.x).as_f32()
-                            / bounds.size.width.as_f32())
-                        .clamp(0.0, 1.0) as f64;
+                        let pivot_pct = crate::view_controller::ViewController::zoom_pivot_pct(
+                            event.position.x.as_f32(),
+                            bounds.origin.x.as_f32(),
+                            bounds.size.width.as_f32(),
+                            false,
+                        );
                         chart.update(cx, |c, cx| {
                             c.dragging_axis = Some(crate::chart::AxisDragInfo {
                                 pane_id: None,
                                 axis_idx,
                                 is_y: false,
                                 button: MouseButton::Left,
-                                pivot_pct: pct,
+                                pivot_pct,
                             });
                             c.last_mouse_pos = Some(event.position);
                             cx.notify();
@@ -596,16 +866,19 @@ impl ChartRenderer {
                 cx.listener(move |_, event: &MouseDownEvent, _, cx| {
                     cx.stop_propagation();
                     if let Some(bounds) = lab.borrow().get(&key) {
-                        let pct = ((event.position.x - bounds.origin.x).as_f32()
-                            / bounds.size.width.as_f32())
-                        .clamp(0.0, 1.0) as f64;
+                        let pivot_pct = crate::view_controller::ViewController::zoom_pivot_pct(
+                            event.position.x.as_f32(),
+                            bounds.origin.x.as_f32(),
+                            bounds.size.width.as_f32(),
+                            false,
+                        );
                         chart.update(cx, |c, cx| {
                             c.dragging_axis = Some(crate::chart::AxisDragInfo {
                                 pane_id: None,
                                 axis_idx,
                                 is_y: false,
                                 button: MouseButton::Middle,
-                                pivot_pct: pct,
+                                pivot_pct,
                             });
                             c.last_mouse_pos = Some(event.position);
                             cx.notify();
@@ -616,13 +889,14 @@ impl ChartRenderer {
             .on_scroll_wheel({
                 let axis_entity = axis_entity.clone();
                 let shared_state = shared_state_handle.clone();
+                let zoom_config = self.zoom_config;
                 move |event, _, cx| {
                     cx.stop_propagation();
                     let dy = match event.delta {
                         ScrollDelta::Pixels(p) => p.y.as_f32(),
                         ScrollDelta::Lines(p) => p.y as f32 * 20.0,
                     };
-                    let factor = (1.0f64 - dy as f64 * 0.01).clamp(0.1, 10.0);
+                    let factor = zoom_config.wheel_factor(dy);
                     let gaps = shared_state.read(cx).gap_index.clone();
                     axis_entity.update(cx, |r, _| {
                         crate::view_controller::ViewController::zoom_axis_at(r, 0.5, factor, gaps.as_deref());
@@ -633,12 +907,12 @@ impl ChartRenderer {
                 AxisEdge::Top => {
                     let pos = top_cursor;
                     top_cursor += x_axis.size;
-                    el.top(pos)
+                    el.top(self.padding.top + pos)
                 }
                 AxisEdge::Bottom => {
                     let pos = bot_cursor;
                     bot_cursor += x_axis.size;
-                    el.bottom(pos)
+                    el.bottom(self.padding.bottom + pos)
                 }
                 _ => el,
             };
@@ -646,8 +920,75 @@ impl ChartRenderer {
         }
 
         let mut tags = Vec::new();
-        if shared_state.crosshair_enabled {
-            if let (Some(_pos), Some(hx)) = (mouse_pos, hover_x) {
+        // Price level tags, under the crosshair ones
+        // Sized after the axis labels, with some room around the text
+        let level_tag_height = theme.axis_label_size * 1.8;
+        for ps in panes.iter().filter(|p| !p.price_levels.is_empty()) {
+            // The levels follow the axis of the series painting them, and
+            // go away with it
+            let Some(series) = ps
+                .series
+                .iter()
+                .find(|s| s.id == crate::chart::ANNOTATIONS_SERIES_ID)
+                .filter(|s| !ps.hidden_series.contains(&s.id))
+            else {
+                continue;
+            };
+            let axis_idx = series.y_axis_id.0;
+            let Some(y_a) = ps.y_axes.get(axis_idx) else {
+                continue;
+            };
+            let key = AxisKey::Y(ps.id.clone(), axis_idx).key();
+            let Some(b) = last_render_axis_bounds.borrow().get(&key).copied() else {
+                continue;
+            };
+            let container_origin = self.bounds.borrow().origin;
+            let scale = crate::scales::ChartScale::new_linear(
+                y_a.entity.read(cx).clamped_bounds(),
+                (b.size.height.as_f32(), 0.0),
+            );
+            for (id, annotation) in &ps.annotations {
+                let Annotation::HLine { y, color, .. } = annotation else {
+                    continue;
+                };
+                let offset = scale.map(*y);
+                let in_view = (0.0..=b.size.height.as_f32()).contains(&offset);
+                if !ps.price_levels.contains(id) || !in_view {
+                    continue;
+                }
+                let text_color = if color.l > 0.6 { gpui::black() } else { gpui::white() };
+                tags.push(
+                    div()
+                        .absolute()
+                        .top(b.origin.y - container_origin.y + px(offset) - level_tag_height / 2.0)
+                        .left(b.origin.x - container_origin.x)
+                        .w(y_a.size)
+                        .h(level_tag_height)
+                        .bg(*color)
+                        .text_color(text_color)
+                        .rounded_sm()
+                        .text_size(theme.axis_label_size)
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .child(scale.format_tick(*y, &y_a.format))
+                        .into_any_element(),
+                );
+            }
+        }
+        // Row of the horizontal crosshair line and of the Y tags
+        let snapped_y = match (self.crosshair_config.mode, hover_x, mouse_pos) {
+            (CrosshairMode::NearestSeriesY, Some(hx), Some(p)) => {
+                let pane_bounds = self.pane_bounds.borrow();
+                Self::nearest_series_y(&panes, &pane_bounds, hx, p.y, cx)
+            }
+            _ => None,
+        };
+        let crosshair_y = mouse_pos.map(|p| snapped_y.map_or(p.y, |s| s.3));
+        if crosshair_shown {
+            let crosshair_x =
+                hover_x.filter(|_| mouse_pos.is_some() || shared_state.crosshair_pinned);
+            if let Some(hx) = crosshair_x {
                 let container_origin = self.bounds.borrow().origin;
                 for (i, x_a) in x_axes.iter().enumerate() {
                     let key = AxisKey::X(i).key();
@@ -679,38 +1020,45 @@ impl ChartRenderer {
                         );
                     }
                 }
-                for ps in panes.iter() {
-                    for (a_idx, y_a) in ps.y_axes.iter().enumerate() {
-                        let key = AxisKey::Y(ps.id.clone(), a_idx).key();
-                        if let (Some(b), Some(p)) =
-                            (last_render_axis_bounds.borrow().get(&key), mouse_pos)
+                if let Some(crosshair_y) = crosshair_y {
+                    let pane_bounds = self.pane_bounds.borrow().clone();
+                    for (p_idx, a_idx, mut val) in
+                        Self::crosshair_y_values(&panes, &pane_bounds, crosshair_y, cx)
+                    {
+                        // The exact series value rather than the one read back from pixels
+                        if let Some((_, _, value, _)) =
+                            snapped_y.filter(|s| (s.0, s.1) == (p_idx, a_idx))
                         {
-                            if p.y >= b.origin.y && p.y <= b.origin.y + b.size.height {
-                                let r = y_a.entity.read(cx);
-                                let scale = crate::scales::ChartScale::new_linear(
-                                    r.clamped_bounds(),
-                                    (b.size.height.as_f32(), 0.0),
-                                );
-                                let val = scale.invert((p.y - b.origin.y).as_f32());
-                                tags.push(
-                                    div()
-                                        .absolute()
-                                        .top(p.y - container_origin.y - px(10.0))
-                                        .left(b.origin.x - container_origin.x)
-                                        .w(y_a.size)
-                                        .h(px(20.0))
-                                        .bg(theme.tag_background)
-                                        .text_color(theme.tag_text)
-                                        .rounded_sm()
-                                        .text_size(px(11.0))
-                                        .flex()
-                                        .items_center()
-                                        .justify_center()
-                                        .child(scale.format_tick(val, &y_a.format))
-                                        .into_any_element(),
-                                );
-                            }
+                            val = value;
                         }
+                        let ps = &panes[p_idx];
+                        let y_a = &ps.y_axes[a_idx];
+                        let key = AxisKey::Y(ps.id.clone(), a_idx).key();
+                        let Some(b) = last_render_axis_bounds.borrow().get(&key).copied() else {
+                            continue;
+                        };
+                        let r = y_a.entity.read(cx);
+                        let scale = crate::scales::ChartScale::new_linear(
+                            r.clamped_bounds(),
+                            (b.size.height.as_f32(), 0.0),
+                        );
+                        tags.push(
+                            div()
+                                .absolute()
+                                .top(crosshair_y - container_origin.y - px(10.0))
+                                .left(b.origin.x - container_origin.x)
+                                .w(y_a.size)
+                                .h(px(20.0))
+                                .bg(theme.tag_background)
+                                .text_color(theme.tag_text)
+                                .rounded_sm()
+                                .text_size(px(11.0))
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .child(scale.format_tick(val, &y_a.format))
+                                .into_any_element(),
+                        );
                     }
                 }
             }
@@ -718,34 +1066,83 @@ impl ChartRenderer {
 
         let mut pane_elements = Vec::new();
         let x_axis_entity = shared_x_axis.clone();
+        let hovered_pane = mouse_pos.and_then(|mp| {
+            let bounds = pane_bounds_rc.borrow();
+            panes
+                .iter()
+                .position(|p| bounds.get(&p.id).is_some_and(|b| b.contains(&mp)))
+        });
         for (i, ps) in panes.iter().enumerate() {
-            let h_pct = if total_weight > 0.0 {
-                ps.weight / total_weight
-            } else {
-                1.0 / panes.len() as f32
-            };
             let is_last = i == panes.len() - 1;
             let is_first = i == 0;
             let pane_rc = pane_bounds_rc.clone();
             let series = ps.series.clone();
             let hidden = ps.hidden_series.clone();
+            let baseline = ps.baseline;
             let y_axes_entities: Vec<Entity<AxisRange>> =
                 ps.y_axes.iter().map(|a| a.entity.clone()).collect();
-            let x_axis_entity = x_axis_entity.clone();
+            // Grid lines follow the tick density of the primary axes
+            let tick_density = |a: Option<&crate::chart::AxisState>| {
+                a.map(|a| (a.tick_count, a.format, a.min_label_spacing.as_f32()))
+                    .unwrap_or((TickCount::Auto, AxisFormat::Numeric, 20.0))
+            };
+            let x_tick_density = tick_density(x_axes.first());
+            let y_tick_density = tick_density(ps.y_axes.first());
+            let x_axis_entity = ps.x_axis_or(&x_axis_entity);
+            // Hover X is in the units of the hovered pane's X axis
+            let show_hover_x = match &ps.x_axis {
+                Some(_) => hovered_pane == Some(i),
+                None => hovered_pane.is_none_or(|h| panes[h].x_axis.is_none()),
+            };
             let theme_for_canvas = theme.clone();
+            let placeholder = if loading {
+                Some("Loading…")
+            } else if !ps.has_data() {
+                Some("No data")
+            } else {
+                None
+            };
+            let show_grid = placeholder.is_none() && !sparkline;
             let hx_val = shared_state.hover_x;
+            let crosshair_readout = if crosshair_shown
+                && shared_state.readout_visible(
+                    hovered_pane.is_some() && show_hover_x,
+                    hovered_pane == Some(i),
+                ) {
+                // A hovered pane with its own X axis reads hover X in its units
+                let x_axis = x_axes.first().map(|a| match &ps.x_axis {
+                    Some(entity) if !shared_state.crosshair_pinned => crate::chart::AxisState {
+                        entity: entity.clone(),
+                        ..a.clone()
+                    },
+                    _ => a.clone(),
+                });
+                hx_val.zip(x_axis).and_then(|(hx, x_axis)| {
+                    self.render_crosshair_readout(ps, hx, &x_axis, &shared_state, &theme, cx)
+                })
+            } else {
+                None
+            };
             let pane_id_for_canvas = ps.id.clone();
             let pane_id_for_close = ps.id.clone();
             let pane_id_for_debug = ps.id.clone();
             let chart = chart_handle.clone();
             // Pass `cx`
-            let legend = self.render_legend(i, ps, &theme, panes.len(), chart.clone(), cx);
+            let legend = if sparkline {
+                None
+            } else {
+                self.render_legend(i, ps, &theme, panes.len(), chart.clone(), cx)
+            };
             let shared_state_for_canvas = shared_state_handle.clone();
 
             let mut pane_debug_overlay = None;
             if shared_state.debug_mode {
                 let times = shared_state.pane_paint_times.read();
                 if let Some(nanos) = times.get(&pane_id_for_debug) {
+                    let modes = ps.series.iter().filter_map(|s| {
+                        let mode = s.plot.read().data_source()?.aggregation_mode();
+                        Some(format!("{}: {:?}", s.id, mode))
+                    });
                     pane_debug_overlay = Some(
                         div()
                             .absolute()
@@ -756,19 +1153,42 @@ impl ChartRenderer {
                             .rounded_sm()
                             .text_size(px(10.0))
                             .text_color(gpui::green())
-                            .child(format!("{:.2?}", std::time::Duration::from_nanos(*nanos))),
+                            .flex()
+                            .flex_col()
+                            .child(format!("{:.2?}", std::time::Duration::from_nanos(*nanos)))
+                            .children(
+                                shared_state
+                                    .pane_point_counts
+                                    .read()
+                                    .get(&pane_id_for_debug)
+                                    .map(|&(raw, drawn)| {
+                                        format!(
+                                            "{} raw → {} drawn",
+                                            compact_count(raw),
+                                            compact_count(drawn)
+                                        )
+                                    }),
+                            )
+                            .children(modes),
                     );
                 }
             }
 
-            let shared_state_for_paint = shared_state.clone();
+            let crosshair_config = self.crosshair_config.clone();
+            let mut shared_state_for_paint = shared_state.clone();
+            shared_state_for_paint.series_opacity = ps
+                .series
+                .iter()
+                .filter(|s| s.opacity < 1.0)
+                .map(|s| (s.id.clone(), s.opacity))
+                .collect();
             pane_elements.push(
-                div()
-                    .h(relative(h_pct))
+                pane_slots[i]
+                    .apply(div())
                     .w_full()
                     .relative()
                     .group("pane_container")
-                    .cursor(CursorStyle::Crosshair)
+                    .when(!sparkline, |d| d.cursor(CursorStyle::Crosshair))
                     .child(
                         canvas(
                             move |_, _, _| {},
@@ -792,26 +1212,54 @@ impl ChartRenderer {
                                     .map(|a| a.read(cx).clamped_bounds())
                                     .collect();
                                 let x_domains = vec![x_bounds];
-                                window.with_content_mask(Some(ContentMask { bounds }), |window| {
-                                    if !y_axes_entities.is_empty() {
+                                let clip = crate::transform::plot_clip_bounds(
+                                    bounds,
+                                    window.scale_factor(),
+                                );
+                                window.with_content_mask(Some(ContentMask { bounds: clip }), |window| {
+                                    if show_grid && !y_axes_entities.is_empty() {
                                         let y0 = y_axes_entities[0].read(cx).clone();
                                         let y_scale = crate::scales::ChartScale::new_linear(
                                             y_domains[0],
                                             (bounds.size.height.as_f32(), 0.0),
                                         );
 
+                                        let (x_tc, x_fmt, x_spacing) = x_tick_density;
+                                        let (y_tc, y_fmt, y_spacing) = y_tick_density;
+                                        let x_count = x_tc.resolve(
+                                            bounds.size.width.as_f32(),
+                                            &x_fmt,
+                                            x_spacing,
+                                        );
+                                        let y_count = y_tc.resolve(
+                                            bounds.size.height.as_f32(),
+                                            &y_fmt,
+                                            y_spacing,
+                                        );
+
                                         let mut x_axis_range = x_range.clone();
-                                        let x_ticks = x_axis_range
-                                            .ticks(10, shared_state_for_paint.gap_index.as_deref())
-                                            .to_vec();
+                                        let x_ticks = AxisRenderer::ticks(
+                                            &mut x_axis_range,
+                                            &x_fmt,
+                                            x_count,
+                                            shared_state_for_paint.gap_index.as_deref(),
+                                        );
 
                                         let y_render_info = crate::rendering::YAxisRenderInfo {
                                             domain: y_domains[0],
                                             scale: y_scale,
-                                            ticks: d3rs::scale::LinearScale::new()
-                                                .domain(y_domains[0].0, y_domains[0].1)
-                                                .range(bounds.size.height.as_f32() as f64, 0.0)
-                                                .ticks(10),
+                                            ticks: match y_fmt {
+                                                AxisFormat::PercentOfRange => {
+                                                    crate::data_types::percent_of_range_ticks(
+                                                        y_domains[0],
+                                                        y_count,
+                                                    )
+                                                }
+                                                _ => d3rs::scale::LinearScale::new()
+                                                    .domain(y_domains[0].0, y_domains[0].1)
+                                                    .range(bounds.size.height.as_f32() as f64, 0.0)
+                                                    .ticks(y_count),
+                                            },
                                             limits: (y0.min_limit, y0.max_limit),
                                         };
                                         crate::rendering::paint_grid(
@@ -827,13 +1275,27 @@ impl ChartRenderer {
                                             &y_render_info,
                                             &theme_for_canvas,
                                         );
+                                        if let Some(offset) = baseline.and_then(|v| {
+                                            crate::view_controller::ViewController::value_to_offset_px(
+                                                v,
+                                                y_domains[0],
+                                                bounds.size.height.as_f32(),
+                                            )
+                                        }) {
+                                            crate::rendering::paint_baseline(
+                                                window,
+                                                bounds,
+                                                offset,
+                                                theme_for_canvas.baseline_line,
+                                            );
+                                        }
                                     }
                                     let visible_series: Vec<Series> = series
                                         .iter()
                                         .filter(|s| !hidden.contains(&s.id))
                                         .cloned()
                                         .collect();
-                                    crate::rendering::paint_plot(
+                                    let stats = crate::rendering::paint_plot(
                                         window,
                                         bounds,
                                         &visible_series,
@@ -842,41 +1304,71 @@ impl ChartRenderer {
                                         cx,
                                         &shared_state_for_paint,
                                     );
+                                    if show_overflow {
+                                        crate::rendering::paint_overflow_indicators(
+                                            window,
+                                            bounds,
+                                            crate::data_types::DataOverflow::of_series(
+                                                &visible_series,
+                                                &x_domains,
+                                                &y_domains,
+                                            ),
+                                            theme_for_canvas.overflow_indicator,
+                                        );
+                                    }
+                                    shared_state_for_paint.pane_point_counts.write().insert(
+                                        pane_id_for_canvas.clone(),
+                                        (stats.raw_points, stats.drawn_points),
+                                    );
+                                    shared_state_for_paint
+                                        .pane_widths
+                                        .write()
+                                        .insert(pane_id_for_canvas.clone(), bounds.size.width.as_f32());
                                     
-                                    if shared_state_for_paint.crosshair_enabled {
-                                        if let Some(hx) = hx_val {
-                                            let sx = x_scale.map(hx);
-                                            let mut builder = PathBuilder::stroke(px(1.0));
-                                            builder.move_to(Point::new(
-                                                bounds.origin.x + px(sx),
-                                                bounds.origin.y,
-                                            ));
-                                            builder.line_to(Point::new(
-                                                bounds.origin.x + px(sx),
-                                                bounds.origin.y + bounds.size.height,
-                                            ));
-                                            if let Ok(path) = builder.build() {
-                                                window
-                                                    .paint_path(path, theme_for_canvas.crosshair_line);
+                                    if crosshair_shown {
+                                        if let Some(hx) = hx_val.filter(|_| show_hover_x) {
+                                            let sx = px(x_scale.map(hx));
+                                            crate::rendering::paint_crosshair_line(
+                                                window,
+                                                Point::new(bounds.origin.x + sx, bounds.origin.y),
+                                                Point::new(
+                                                    bounds.origin.x + sx,
+                                                    bounds.origin.y + bounds.size.height,
+                                                ),
+                                                &crosshair_config.vertical,
+                                                &theme_for_canvas,
+                                            );
+                                            if let Some(radius) = crosshair_config.point_marker_radius {
+                                                crate::rendering::paint_crosshair_markers(
+                                                    window,
+                                                    bounds,
+                                                    &visible_series,
+                                                    &x_scale,
+                                                    &y_domains,
+                                                    hx,
+                                                    radius,
+                                                    crosshair_config
+                                                        .vertical
+                                                        .color
+                                                        .unwrap_or(theme_for_canvas.crosshair_line),
+                                                );
                                             }
                                         }
 
-                                        if let Some(mp) = mouse_pos {
-                                            if mp.y >= bounds.origin.y
-                                                && mp.y <= bounds.origin.y + bounds.size.height
+                                        if let Some(y) = crosshair_y {
+                                            if y >= bounds.origin.y
+                                                && y <= bounds.origin.y + bounds.size.height
                                             {
-                                                let mut builder = PathBuilder::stroke(px(1.0));
-                                                builder.move_to(Point::new(bounds.origin.x, mp.y));
-                                                builder.line_to(Point::new(
-                                                    bounds.origin.x + bounds.size.width,
-                                                    mp.y,
-                                                ));
-                                                if let Ok(path) = builder.build() {
-                                                    window.paint_path(
-                                                        path,
-                                                        theme_for_canvas.crosshair_line,
-                                                    );
-                                                }
+                                                crate::rendering::paint_crosshair_line(
+                                                    window,
+                                                    Point::new(bounds.origin.x, y),
+                                                    Point::new(
+                                                        bounds.origin.x + bounds.size.width,
+                                                        y,
+                                                    ),
+                                                    &crosshair_config.horizontal,
+                                                    &theme_for_canvas,
+                                                );
                                             }
                                         }
                                     }
@@ -892,60 +1384,85 @@ impl ChartRenderer {
                         .size_full()
                         .absolute(),
                     )
-                    .child(
+                    .when(!sparkline && (show_pane_controls || (is_first && follow_paused)), |d| {
+                        d.child(
+                            div()
+                                .absolute()
+                                .top_2()
+                                .right_2()
+                                .flex()
+                                .gap_1()
+                                .bg(theme.background.opacity(0.4))
+                                .rounded_lg()
+                                .p_1()
+                                .border_1()
+                                .border_color(theme.axis_label.opacity(0.05))
+                                .group_hover("pane_container", |d| {
+                                    d.bg(theme.background.opacity(0.8))
+                                        .border_color(theme.axis_label.opacity(0.2))
+                                })
+                                .when(is_first && follow_paused, |d| {
+                                    d.child(Self::render_control_button("⏵", true, &theme, {
+                                        let chart = chart.clone();
+                                        move |_, _, cx| {
+                                            cx.stop_propagation();
+                                            chart.update(cx, |c, cx| c.resume_following(cx));
+                                        }
+                                    }))
+                                })
+                                .when(show_pane_controls, |d| {
+                                    d.child(Self::render_control_button("↑", !is_first, &theme, {
+                                        let chart = chart.clone();
+                                        move |_, _, cx| {
+                                            cx.stop_propagation();
+                                            chart.update(cx, |c, cx| c.move_pane_up(i, cx));
+                                        }
+                                    }))
+                                    .child(Self::render_control_button("↓", !is_last, &theme, {
+                                        let chart = chart.clone();
+                                        move |_, _, cx| {
+                                            cx.stop_propagation();
+                                            chart.update(cx, |c, cx| c.move_pane_down(i, cx));
+                                        }
+                                    }))
+                                    .child(Self::render_control_button("+", true, &theme, {
+                                        let chart = chart.clone();
+                                        move |_, _, cx| {
+                                            cx.stop_propagation();
+                                            chart.update(cx, |c, cx| c.add_pane_at(i + 1, 1.0, cx));
+                                        }
+                                    }))
+                                    .child(Self::render_control_button("✕", true, &theme, {
+                                        let p_id = pane_id_for_close.clone();
+                                        let chart = chart.clone();
+                                        move |_, _, cx| {
+                                            cx.stop_propagation();
+                                            chart.update(cx, |c, cx| c.remove_pane_by_id(p_id.clone(), cx));
+                                        }
+                                    }))
+                                }),
+                        )
+                    })
+                    .children(placeholder.map(|text| {
                         div()
                             .absolute()
-                            .top_2()
-                            .right_2()
+                            .size_full()
                             .flex()
-                            .gap_1()
-                            .bg(theme.background.opacity(0.4))
-                            .rounded_lg()
-                            .p_1()
-                            .border_1()
-                            .border_color(theme.axis_label.opacity(0.05))
-                            .group_hover("pane_container", |d| {
-                                d.bg(theme.background.opacity(0.8))
-                                    .border_color(theme.axis_label.opacity(0.2))
-                            })
-                            .child(Self::render_control_button("↑", !is_first, &theme, {
-                                let chart = chart.clone();
-                                move |_, _, cx| {
-                                    cx.stop_propagation();
-                                    chart.update(cx, |c, cx| c.move_pane_up(i, cx));
-                                }
-                            }))
-                            .child(Self::render_control_button("↓", !is_last, &theme, {
-                                let chart = chart.clone();
-                                move |_, _, cx| {
-                                    cx.stop_propagation();
-                                    chart.update(cx, |c, cx| c.move_pane_down(i, cx));
-                                }
-                            }))
-                            .child(Self::render_control_button("+", true, &theme, {
-                                let chart = chart.clone();
-                                move |_, _, cx| {
-                                    cx.stop_propagation();
-                                    chart.update(cx, |c, cx| c.add_pane_at(i + 1, 1.0, cx));
-                                }
-                            }))
-                            .child(Self::render_control_button("✕", true, &theme, {
-                                let p_id = pane_id_for_close.clone();
-                                let chart = chart.clone();
-                                move |_, _, cx| {
-                                    cx.stop_propagation();
-                                    chart.update(cx, |c, cx| c.remove_pane_by_id(p_id.clone(), cx));
-                                }
-                            })),
-                    )
+                            .items_center()
+                            .justify_center()
+                            .text_color(theme.placeholder_text)
+                            .child(text)
+                    }))
                     .children(legend)
+                    .children(crosshair_readout)
                     .children(pane_debug_overlay)
                     .into_any_element(),
             );
-            if !is_last {
+            if !is_last && !sparkline {
                 pane_elements.push(
                     div()
-                        .h(px(6.0))
+                        .h(SPLITTER_HEIGHT)
+                        .flex_none()
                         .w_full()
                         .flex()
                         .items_center()
@@ -955,10 +1472,7 @@ impl ChartRenderer {
                         .on_mouse_down(MouseButton::Left, {
                             let chart = chart_handle.clone();
                             cx.listener(move |_, event: &MouseDownEvent, _win, cx| {
-                                chart.update(cx, |c, _| {
-                                    c.dragging_splitter = Some(i);
-                                    c.last_mouse_y = Some(event.position.y);
-                                });
+                                chart.update(cx, |c, _| c.begin_splitter_drag(i, event.position.y));
                                 cx.notify();
                             })
                         })
@@ -1000,9 +1514,32 @@ impl ChartRenderer {
             );
         }
 
+        let elapsed = start_time.elapsed();
+        let previous_paint: u64 = {
+            let times = shared_state.pane_paint_times.read();
+            panes.iter().filter_map(|ps| times.get(&ps.id)).sum()
+        };
+        render_history
+            .write()
+            .record(elapsed, std::time::Duration::from_nanos(previous_paint));
+
         let mut debug_overlay = None;
         if shared_state.debug_mode {
-            let elapsed = start_time.elapsed();
+            let (raw, drawn) = {
+                let counts = shared_state.pane_point_counts.read();
+                panes
+                    .iter()
+                    .filter_map(|ps| counts.get(&ps.id))
+                    .fold((0, 0), |acc, c| (acc.0 + c.0, acc.1 + c.1))
+            };
+            let x_ranges: Vec<String> = x_axes
+                .iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    let r = a.entity.read(cx);
+                    format!("X{}: [{:.4}, {:.4}]", i, r.min, r.max)
+                })
+                .collect();
             debug_overlay = Some(
                 div()
                     .absolute()
@@ -1024,6 +1561,12 @@ impl ChartRenderer {
                         std::time::Duration::from_nanos(shared_state.total_paint_nanos())
                     ))
                     .child(format!("Panes: {}", panes.len()))
+                    .child(format!(
+                        "Visible: {} raw → {} drawn",
+                        compact_count(raw),
+                        compact_count(drawn)
+                    ))
+                    .children(x_ranges)
                     .child(format!("Hover X: {:?}", shared_state.hover_x)),
             );
         }
@@ -1059,8 +1602,10 @@ impl ChartRenderer {
                     .absolute()
                     .top(self.gutter_top)
                     .bottom(self.gutter_bottom)
-                    .left_0()
-                    .w(self.gutter_left)
+                    .left(self.padding.left)
+                    .w(self.gutter_left - self.padding.left * 2.0)
+                    .flex()
+                    .flex_col()
                     .children(left_y_axis_elements),
             )
             .child(
@@ -1068,8 +1613,10 @@ impl ChartRenderer {
                     .absolute()
                     .top(self.gutter_top)
                     .bottom(self.gutter_bottom)
-                    .right_0()
-                    .w(self.gutter_right)
+                    .right(self.padding.right)
+                    .w(self.gutter_right - self.padding.right * 2.0)
+                    .flex()
+                    .flex_col()
                     .children(right_y_axis_elements),
             )
             .children(x_axis_elements)
@@ -1078,3 +1625,50 @@ impl ChartRenderer {
             .children(debug_overlay)
     }
 }
+
+/// Height of the splitter between two panes.
+const SPLITTER_HEIGHT: Pixels = px(6.0);
+
+/// How a pane, and the Y axes beside it, take their share of the chart height.
+#[derive(Clone, Copy, Debug)]
+enum PaneSlot {
+    Fixed(Pixels),
+    Weighted(f32),
+}
+
+impl PaneSlot {
+    fn apply(self, d: Div) -> Div {
+        match self {
+            Self::Fixed(h) => d.flex_none().h(h),
+            Self::Weighted(w) => {
+                let mut d = d.flex_basis(px(0.0)).min_h_0();
+                d.style().flex_grow = Some(w.max(0.0));
+                d.style().flex_shrink = Some(1.0);
+                d
+            }
+        }
+    }
+}
+
+/// Formats a point count for the debug overlay: `850`, `3.1k`, `1.2M`.
+fn compact_count(n: usize) -> String {
+    match n {
+        0..1_000 => n.to_string(),
+        1_000..1_000_000 => format!("{:.1}k", n as f64 / 1e3),
+        _ => format!("{:.1}M", n as f64 / 1e6),
+    }
+}
+
+/// Formats a candle volume for the crosshair readout: `0.25`, `850`, `3.1k`,
+/// `1.2M`, `4.5B`.
+fn compact_volume(v: f64) -> String {
+    match v.abs() {
+        a if a >= 1e9 => format!("{:.1}B", v / 1e9),
+        a if a >= 1e6 => format!("{:.1}M", v / 1e6),
+        a if a >= 1e3 => format!("{:.1}k", v / 1e3),
+        _ => {
+            let s = format!("{v:.2}");
+            s.trim_end_matches('0').trim_end_matches('.').to_string()
+        }
+    }
+}
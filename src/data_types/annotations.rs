@@ -1,18 +1,62 @@
 use gpui::Hsla;
 
+/// Identifies an annotation added with `Chart::add_annotation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AnnotationId(pub u64);
+
+/// Whether an annotation is painted behind or on top of the series.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnnotationLayer {
+    /// Painted before every series of the pane (e.g. shaded regions)
+    Background,
+    /// Painted after the series of the pane (e.g. callouts)
+    #[default]
+    Foreground,
+}
+
+/// Stroke pattern of a line annotation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+impl LineStyle {
+    /// Dash pattern in pixels (on, off) for a line `width` pixels wide, scaled
+    /// with the width so thick lines keep their rhythm; empty when solid.
+    pub fn dash_pattern(self, width: f32) -> Vec<f32> {
+        let w = width.max(1.0);
+        match self {
+            LineStyle::Solid => vec![],
+            LineStyle::Dashed => vec![6.0 * w, 4.0 * w],
+            LineStyle::Dotted => vec![w, 2.0 * w],
+        }
+    }
+}
+
+/// An annotation drawn on a pane. `affects_autofit` decides whether auto-fit
+/// (reset view, double-click) keeps the annotation in view.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Annotation {
     VLine {
         x: f64,
         color: Hsla,
         width: f32,
+        style: LineStyle,
         label: Option<String>,
+        layer: AnnotationLayer,
+        affects_autofit: bool,
     },
     HLine {
         y: f64,
         color: Hsla,
         width: f32,
+        style: LineStyle,
         label: Option<String>,
+        layer: AnnotationLayer,
+        affects_autofit: bool,
     },
     Rect {
         x_min: f64,
@@ -21,6 +65,8 @@ pub enum Annotation {
         y_max: f64,
         color: Hsla,
         fill: bool,
+        layer: AnnotationLayer,
+        affects_autofit: bool,
     },
     Text {
         x: f64,
@@ -28,9 +74,82 @@ pub enum Annotation {
         text: String,
         color: Hsla,
         font_size: f32,
+        layer: AnnotationLayer,
+        affects_autofit: bool,
     },
 }
 
+impl Annotation {
+    pub fn layer(&self) -> AnnotationLayer {
+        match self {
+            Annotation::VLine { layer, .. }
+            | Annotation::HLine { layer, .. }
+            | Annotation::Rect { layer, .. }
+            | Annotation::Text { layer, .. } => *layer,
+        }
+    }
+
+    pub fn affects_autofit(&self) -> bool {
+        match self {
+            Annotation::VLine {
+                affects_autofit, ..
+            }
+            | Annotation::HLine {
+                affects_autofit, ..
+            }
+            | Annotation::Rect {
+                affects_autofit, ..
+            }
+            | Annotation::Text {
+                affects_autofit, ..
+            } => *affects_autofit,
+        }
+    }
+
+    /// X range covered by the annotation; `None` for HLines, which span every X.
+    pub fn x_extent(&self) -> Option<(f64, f64)> {
+        match self {
+            Annotation::VLine { x, .. } | Annotation::Text { x, .. } => Some((*x, *x)),
+            Annotation::HLine { .. } => None,
+            Annotation::Rect { x_min, x_max, .. } => Some((*x_min, *x_max)),
+        }
+    }
+
+    /// Y range covered by the annotation; `None` for VLines, which span every Y.
+    pub fn y_extent(&self) -> Option<(f64, f64)> {
+        match self {
+            Annotation::HLine { y, .. } | Annotation::Text { y, .. } => Some((*y, *y)),
+            Annotation::VLine { .. } => None,
+            Annotation::Rect { y_min, y_max, .. } => Some((*y_min, *y_max)),
+        }
+    }
+
+    /// Moves the annotation by `dx`/`dy` data units; the extent a line spans
+    /// (Y for VLines, X for HLines) ignores the matching delta.
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        match self {
+            Annotation::VLine { x, .. } => *x += dx,
+            Annotation::HLine { y, .. } => *y += dy,
+            Annotation::Rect {
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                ..
+            } => {
+                *x_min += dx;
+                *x_max += dx;
+                *y_min += dy;
+                *y_max += dy;
+            }
+            Annotation::Text { x, y, .. } => {
+                *x += dx;
+                *y += dy;
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct HeatmapCell {
     pub x: f64,
@@ -39,4 +158,7 @@ pub struct HeatmapCell {
     pub height: f64,
     pub color: Hsla,
     pub text: Option<String>,
+    /// Value mapped through the plot's colormap, if it has one; `color` is
+    /// used otherwise.
+    pub value: Option<f64>,
 }
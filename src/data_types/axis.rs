@@ -14,6 +14,9 @@ pub enum TimeUnit {
 pub enum AxisFormat {
     Numeric,
     Time(TimeUnit, Option<chrono_tz::Tz>), // unit, timezone
+    /// Labels show the position in the visible range, 0% at the bottom (or
+    /// left) to 100%, with ticks on round percentages. Data stays absolute.
+    PercentOfRange,
 }
 
 impl Default for AxisFormat {
@@ -22,6 +25,101 @@ impl Default for AxisFormat {
     }
 }
 
+/// Target number of ticks on an axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TickCount {
+    /// Derived from the axis pixel length and the minimum label spacing
+    #[default]
+    Auto,
+    Fixed(usize),
+}
+
+impl TickCount {
+    /// Resolves the target tick count for an axis `axis_px` pixels long.
+    pub fn resolve(&self, axis_px: f32, format: &AxisFormat, min_label_spacing: f32) -> usize {
+        match self {
+            TickCount::Fixed(n) => (*n).max(1),
+            TickCount::Auto => {
+                let label_size_est = match format {
+                    AxisFormat::Time(..) => 80.0 + min_label_spacing,
+                    AxisFormat::Numeric | AxisFormat::PercentOfRange => 50.0 + min_label_spacing,
+                };
+                ((axis_px / label_size_est).floor() as usize).clamp(2, 20)
+            }
+        }
+    }
+}
+
+/// Ticks at round percentages of `domain` (as absolute values), for
+/// `AxisFormat::PercentOfRange`.
+pub fn percent_of_range_ticks(domain: (f64, f64), count: usize) -> Vec<f64> {
+    let span = domain.1 - domain.0;
+    if !span.is_finite() || span == 0.0 {
+        return Vec::new();
+    }
+    LinearScale::new()
+        .domain(0.0, 100.0)
+        .range(0.0, 1.0)
+        .ticks(count)
+        .into_iter()
+        .map(|pct| domain.0 + pct / 100.0 * span)
+        .collect()
+}
+
+/// Decides which tick labels to draw so that no two drawn labels are closer
+/// than `min_spacing_px`, given each tick's screen position and label extent
+/// (width for horizontal axes, height for vertical ones).
+///
+/// Labels are kept every 1, 2, 5, 10, 20, ... ticks, anchored on multiples of
+/// that stride: the "nicest" values win and the selection only depends on the
+/// tick values, so labels do not flicker while panning.
+pub fn visible_tick_labels(
+    ticks: &[f64],
+    tick_px: &[f32],
+    label_extents_px: &[f32],
+    min_spacing_px: f32,
+) -> Vec<bool> {
+    let n = ticks.len().min(tick_px.len());
+    if n < 2 {
+        return vec![true; ticks.len()];
+    }
+
+    let mut step = f64::INFINITY;
+    let mut spacing_px = f32::INFINITY;
+    for i in 1..n {
+        let d = (ticks[i] - ticks[i - 1]).abs();
+        if d > 0.0 {
+            step = step.min(d);
+        }
+        spacing_px = spacing_px.min((tick_px[i] - tick_px[i - 1]).abs());
+    }
+    let needed = label_extents_px.iter().copied().fold(0.0f32, f32::max) + min_spacing_px;
+    if !step.is_finite() || spacing_px >= needed {
+        return vec![true; ticks.len()];
+    }
+
+    // Strides 1, 2, 5, 10, 20, 50, ...
+    let mut decade = 1usize;
+    let stride = 'search: loop {
+        for m in [1, 2, 5] {
+            let stride = decade * m;
+            if spacing_px * stride as f32 >= needed || stride >= n {
+                break 'search stride;
+            }
+        }
+        decade *= 10;
+    };
+
+    let mut visible: Vec<bool> = ticks
+        .iter()
+        .map(|t| ((t / step).round() as i64).rem_euclid(stride as i64) == 0)
+        .collect();
+    if !visible.contains(&true) {
+        visible[0] = true;
+    }
+    visible
+}
+
 /// Axis management types
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct AxisId(pub usize);
@@ -42,15 +140,54 @@ pub struct ChartAxis<T> {
     pub size: gpui::Pixels,
 }
 
-/// State for a single axis (X or Y).
+/// Ticks computed by `AxisRange::ticks` for one tick count.
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct CachedTicks {
+    pub count: usize,
+    pub domain: (f64, f64),
+    /// Logical bounds of the domain under the gap index the ticks were
+    /// computed with, None without gaps.
+    pub gaps: Option<(i64, i64)>,
+    pub ticks: Vec<f64>,
+}
+
+/// Tick counts whose ticks an axis keeps at once, e.g. an overview axis
+/// and the main axis sharing one range.
+const TICK_CACHE_SIZE: usize = 4;
+
+/// State for a single axis (X or Y).
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct AxisRange {
     pub min: f64,
     pub max: f64,
     pub min_limit: Option<f64>,
     pub max_limit: Option<f64>,
-    pub cached_ticks: Vec<f64>,
-    pub last_tick_domain: (f64, f64),
+    /// Ticks of the last tick counts asked for, see `ticks`
+    pub cached_ticks: Vec<CachedTicks>,
+    /// Extent of the data shown on this axis. When set, zooming out stops
+    /// once the whole extent is visible (see `snap_to_data`).
+    pub data_extent: Option<(f64, f64)>,
+    /// When false, `min_limit`/`max_limit` are kept but not enforced, so the
+    /// view can be panned or zoomed past them.
+    pub clamp_enabled: bool,
+    /// Padding added around the data when this axis is auto-fitted, as a
+    /// fraction of the data span. None uses `Chart::autofit_padding`.
+    pub autofit_padding: Option<f64>,
+}
+
+impl Default for AxisRange {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 0.0,
+            min_limit: None,
+            max_limit: None,
+            cached_ticks: vec![],
+            data_extent: None,
+            clamp_enabled: true,
+            autofit_padding: None,
+        }
+    }
 }
 
 impl AxisRange {
@@ -58,8 +195,6 @@ impl AxisRange {
         Self {
             min,
             max,
-            cached_ticks: vec![],
-            last_tick_domain: (0.0, 0.0),
             ..Default::default()
         }
     }
@@ -68,12 +203,30 @@ impl AxisRange {
         self.max - self.min
     }
 
+    /// Ticks of the visible range, cached per tick count so that axes
+    /// sharing this range with different densities keep their own.
     pub fn ticks(&mut self, count: usize, gaps: Option<&GapIndex>) -> &[f64] {
         let (min, max) = self.clamped_bounds();
-        let domain_changed = (min - self.last_tick_domain.0).abs() > (max - min) * 0.001
-            || (max - self.last_tick_domain.1).abs() > (max - min) * 0.001;
+        // Ticks computed with other gaps (or none) would fall inside gaps
+        let tick_gaps = gaps.map(|g| (g.to_logical(min as i64), g.to_logical(max as i64)));
+        let idx = match self.cached_ticks.iter().position(|c| c.count == count) {
+            Some(idx) => idx,
+            None => {
+                if self.cached_ticks.len() >= TICK_CACHE_SIZE {
+                    self.cached_ticks.remove(0);
+                }
+                self.cached_ticks.push(CachedTicks {
+                    count,
+                    ..Default::default()
+                });
+                self.cached_ticks.len() - 1
+            }
+        };
+        let entry = &mut self.cached_ticks[idx];
+        let domain_changed = (min - entry.domain.0).abs() > (max - min) * 0.001
+            || (max - entry.domain.1).abs() > (max - min) * 0.001;
 
-        if domain_changed || self.cached_ticks.is_empty() {
+        if domain_changed || tick_gaps != entry.gaps || entry.ticks.is_empty() {
             if let Some(gaps) = gaps {
                 let l_min = gaps.to_logical(min as i64) as f64;
                 let l_max = gaps.to_logical(max as i64) as f64;
@@ -83,20 +236,21 @@ impl AxisRange {
                     .ticks(count);
 
                 let mut cursor = gaps.cursor();
-                self.cached_ticks = logical_ticks
+                entry.ticks = logical_ticks
                     .into_iter()
                     .map(|t| cursor.to_real(t as i64) as f64)
                     .filter(|&t| !gaps.is_inside(t as i64))
                     .collect();
             } else {
-                self.cached_ticks = LinearScale::new()
+                entry.ticks = LinearScale::new()
                     .domain(min, max)
                     .range(0.0, 1.0)
                     .ticks(count);
             }
-            self.last_tick_domain = (min, max);
+            entry.domain = (min, max);
+            entry.gaps = tick_gaps;
         }
-        &self.cached_ticks
+        &entry.ticks
     }
 
     pub fn update_ticks_if_needed(&mut self, count: usize, gaps: Option<&GapIndex>) {
@@ -107,6 +261,9 @@ impl AxisRange {
     pub fn clamped_bounds(&self) -> (f64, f64) {
         let mut c_min = self.min;
         let mut c_max = self.max;
+        if !self.clamp_enabled {
+            return (c_min, c_max);
+        }
         if let Some(l) = self.min_limit {
             if c_min < l {
                 c_min = l;
@@ -134,6 +291,17 @@ impl AxisRange {
         self.cached_ticks.clear();
     }
 
+    /// Caps the domain to `data_extent` once it covers it entirely.
+    pub fn snap_to_data(&mut self) {
+        if let Some((lo, hi)) = self.data_extent {
+            if hi > lo && self.span() >= hi - lo {
+                self.min = lo;
+                self.max = hi;
+                self.cached_ticks.clear();
+            }
+        }
+    }
+
     /// Pan with optional clamping (handled manually if needed).
     pub fn pan(&mut self, delta_data: f64) {
         self.min += delta_data;
@@ -141,8 +309,24 @@ impl AxisRange {
         self.cached_ticks.clear();
     }
 
+    /// Sets the bounds the view can be panned or zoomed to (None: unbounded
+    /// on that side) and moves the current view inside them.
+    pub fn set_limits(&mut self, min_limit: Option<f64>, max_limit: Option<f64>) {
+        let (min_limit, max_limit) = match (min_limit, max_limit) {
+            (Some(lo), Some(hi)) if lo > hi => (Some(hi), Some(lo)),
+            limits => limits,
+        };
+        self.min_limit = min_limit;
+        self.max_limit = max_limit;
+        self.clamp();
+    }
+
     /// Applies limits intelligently to preserve the pivot.
+    /// Does nothing when `clamp_enabled` is false.
     pub fn clamp(&mut self) {
+        if !self.clamp_enabled {
+            return;
+        }
         let (Some(min_l), Some(max_l)) = (self.min_limit, self.max_limit) else {
             // Case where only one or no limit is present: simple clamping
             if let Some(l) = self.min_limit {
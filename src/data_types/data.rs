@@ -11,6 +11,17 @@ pub struct Ohlcv {
     pub volume: f64,
 }
 
+/// Per-point color change for line series. The color of the segment leading
+/// to a point is decided by that point's op:
+/// - `Persistent(c)`: this segment and all following ones use `c`, until the
+///   next `Persistent` or `Reset`.
+/// - `OneShot(c)`: only this segment uses `c`; the segment after it goes back
+///   to the persistent color.
+/// - `Reset`: this segment and the following ones use the series color again.
+/// - `None`: keeps the current color.
+///
+/// Min/max and M4 decimation move a bucket's change onto one of the points
+/// they keep (see `decimation::common::carry_color_op`).
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum ColorOp {
     Persistent(Hsla),
@@ -20,6 +31,30 @@ pub enum ColorOp {
     None,
 }
 
+impl ColorOp {
+    /// Whether the op changes the color inherited by the following points.
+    pub fn is_persistent_change(&self) -> bool {
+        matches!(self, ColorOp::Persistent(_) | ColorOp::Reset)
+    }
+
+    /// Applies the op to the persistent color `current` and returns the color
+    /// of the segment leading to its point (`None` = series color).
+    pub fn apply(&self, current: &mut Option<Hsla>) -> Option<Hsla> {
+        match *self {
+            ColorOp::Persistent(c) => {
+                *current = Some(c);
+                Some(c)
+            }
+            ColorOp::OneShot(c) => Some(c),
+            ColorOp::Reset => {
+                *current = None;
+                None
+            }
+            ColorOp::None => *current,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct PlotPoint {
     pub x: f64,
@@ -33,10 +68,115 @@ pub enum PlotData {
     Ohlcv(Ohlcv),
 }
 
+impl PlotData {
+    /// X coordinate of the sample (time for OHLCV).
+    pub fn x(&self) -> f64 {
+        match self {
+            PlotData::Point(p) => p.x,
+            PlotData::Ohlcv(o) => o.time,
+        }
+    }
+
+    /// Representative Y value of the sample (close for OHLCV).
+    pub fn y(&self) -> f64 {
+        match self {
+            PlotData::Point(p) => p.y,
+            PlotData::Ohlcv(o) => o.close,
+        }
+    }
+
+    /// Color op of a line point; always `ColorOp::None` for OHLCV.
+    pub fn color_op(&self) -> ColorOp {
+        match self {
+            PlotData::Point(p) => p.color_op,
+            PlotData::Ohlcv(_) => ColorOp::None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AggregationMode {
     MinMax, // 2 points par bin
     #[default]
     M4, // 4 points par bin (First, Min, Max, Last)
     LTTB,   // Largest-Triangle-Three-Buckets
+    /// LTTB on pan-invariant buckets, see `decimate_lttb_stable_arrays_into`
+    StableLTTB,
+    Custom, // User-provided reducer (see `CustomAggregator`)
+}
+
+/// Whether a series is decimated before drawing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimationPolicy {
+    /// Aggregated once the points in view exceed the budget times the
+    /// source's decimation threshold
+    #[default]
+    Auto,
+    /// Always drawn raw (clipped to the window), e.g. a short reference curve
+    Never,
+    /// Aggregated as soon as the points in view exceed the budget, even with
+    /// a decimation threshold above 1
+    Always,
 }
+
+/// Aggregation mode chosen from the zoom level, by the decimation ratio
+/// (points in view / point budget). `steps` pairs a ratio with the mode used
+/// from that ratio up, e.g. `[(20.0, AggregationMode::Custom)]` keeps the
+/// source's own mode up to 20 points per budget slot and switches to its
+/// custom reducer (e.g. bars) beyond. Below the decimation threshold the raw
+/// points are drawn whatever the steps.
+///
+/// A threshold is only crossed once the ratio leaves the band
+/// `threshold * (1 ± hysteresis)`, so panning along a zoom level close to a
+/// threshold does not flip between modes as the number of points in view
+/// changes slightly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoomAggregation {
+    /// (ratio, mode) pairs, sorted by ratio
+    pub steps: Vec<(f64, AggregationMode)>,
+    /// Relative width of the band around each threshold (default 0.1)
+    pub hysteresis: f64,
+}
+
+impl ZoomAggregation {
+    pub fn new(mut steps: Vec<(f64, AggregationMode)>) -> Self {
+        steps.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            steps,
+            hysteresis: 0.1,
+        }
+    }
+
+    pub fn with_hysteresis(mut self, hysteresis: f64) -> Self {
+        self.hysteresis = hysteresis.max(0.0);
+        self
+    }
+
+    /// Step to use at `ratio` when `current` is in use (None: below the
+    /// first threshold).
+    pub fn step_for(&self, ratio: f64, current: Option<usize>) -> Option<usize> {
+        let mut step = current.filter(|&s| s < self.steps.len());
+        loop {
+            let next = step.map_or(0, |s| s + 1);
+            match self.steps.get(next) {
+                Some(&(threshold, _)) if ratio >= threshold * (1.0 + self.hysteresis) => {
+                    step = Some(next)
+                }
+                _ => break,
+            }
+        }
+        while let Some(s) = step {
+            if ratio >= self.steps[s].0 * (1.0 - self.hysteresis) {
+                break;
+            }
+            step = s.checked_sub(1);
+        }
+        step
+    }
+}
+
+/// Per-bucket reduction used by `AggregationMode::Custom`: receives the points
+/// of one gap-aware bucket (in X order) and returns up to 4 representatives,
+/// also in X order.
+pub type CustomAggregator =
+    Box<dyn Fn(&[PlotData]) -> smallvec::SmallVec<[PlotData; 4]> + Send + Sync>;
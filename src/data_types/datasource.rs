@@ -1,6 +1,9 @@
 use std::collections::VecDeque;
 use crate::gaps::GapIndex;
-use super::data::{PlotData, AggregationMode};
+use gpui::Hsla;
+use super::data::{
+    AggregationMode, ColorOp, CustomAggregator, DecimationPolicy, PlotData, ZoomAggregation,
+};
 use super::axis::AxisDomain;
 
 /// Trait for data sources that provide points for the chart.
@@ -10,6 +13,58 @@ pub trait PlotDataSource: Send + Sync {
         AggregationMode::M4
     }
 
+    /// Changes the aggregation mode at runtime. Ignored by sources with a
+    /// fixed mode.
+    fn set_aggregation_mode(&mut self, _mode: AggregationMode) {}
+
+    /// Mode used to aggregate `count` points in view into `max_points`.
+    /// Sources with a zoom-dependent strategy (see `ZoomAggregation`) pick it
+    /// from `count / max_points` and the caller's `zoom_step`, which they
+    /// update; the default is `aggregation_mode`.
+    fn aggregation_mode_for(
+        &self,
+        _count: usize,
+        _max_points: usize,
+        _zoom_step: &mut Option<usize>,
+    ) -> AggregationMode {
+        self.aggregation_mode()
+    }
+
+    /// Multiplier on `max_points`: aggregation only engages once more than
+    /// `max_points * threshold` points are in view, and then still aggregates
+    /// into `max_points`. Values above 1.0 keep raw points for longer; values
+    /// below 1.0 are treated as 1.0. Defaults to 1.0.
+    fn decimation_threshold(&self) -> f64 {
+        1.0
+    }
+
+    /// Changes the decimation threshold. Ignored by sources without one.
+    fn set_decimation_threshold(&mut self, _threshold: f64) {}
+
+    /// Whether the data is decimated at all, see `DecimationPolicy`.
+    /// Defaults to `Auto`.
+    fn decimation_policy(&self) -> DecimationPolicy {
+        DecimationPolicy::Auto
+    }
+
+    /// Changes the decimation policy. Ignored by sources without one.
+    fn set_decimation_policy(&mut self, _policy: DecimationPolicy) {}
+
+    /// Point budget for `count` points in view: `None` when they should be
+    /// drawn as-is, otherwise the number of points to aggregate into.
+    fn decimation_budget(&self, count: usize, max_points: usize) -> Option<usize> {
+        let threshold = match self.decimation_policy() {
+            DecimationPolicy::Auto => self.decimation_threshold().max(1.0),
+            DecimationPolicy::Never => return None,
+            DecimationPolicy::Always => 1.0,
+        };
+        if count as f64 <= max_points as f64 * threshold {
+            None
+        } else {
+            Some(max_points.max(1))
+        }
+    }
+
     /// Returns the bounds of the data as (x_min, x_max, y_min, y_max)
     fn get_bounds(&self) -> Option<(f64, f64, f64, f64)>;
 
@@ -46,21 +101,230 @@ pub trait PlotDataSource: Send + Sync {
         output.extend(self.iter_aggregated(x_min, x_max, max_points, gaps));
     }
 
+    /// `get_aggregated_data` for a view drawing the source frame after frame:
+    /// `zoom_step` is the `ZoomAggregation` step it used last, kept by the
+    /// view so the hysteresis follows its own zoom level only.
+    fn get_aggregated_data_at(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        output: &mut Vec<PlotData>,
+        gaps: Option<&GapIndex>,
+        _zoom_step: &mut Option<usize>,
+    ) {
+        self.get_aggregated_data(x_min, x_max, max_points, output, gaps);
+    }
+
     /// Add a single data point
     fn add_data(&mut self, data: PlotData);
 
     /// Replace all data
     fn set_data(&mut self, data: Vec<PlotData>);
 
+    /// Removes all data, along with the caches derived from it (bounds, LOD
+    /// levels). Sources computed from other series ignore it, like `set_data`.
+    fn clear(&mut self) {
+        self.set_data(Vec::new());
+    }
+
     /// Suggested X spacing between points (e.g. for Bar width calculation)
     fn suggested_x_spacing(&self) -> f64;
 
     /// Total number of points
     fn len(&self) -> usize;
 
+    /// Counter bumped on every change to the data, for views caching what they
+    /// derive from it. Sources whose data never changes keep the default.
+    fn version(&self) -> u64 {
+        0
+    }
+
+    /// Number of points with an X inside `[x_min, x_max]`.
+    fn count_in_range(&self, x_min: f64, x_max: f64) -> usize {
+        self.iter_range(x_min, x_max)
+            .filter(|p| {
+                let x = crate::decimation::common::get_data_x(p);
+                x >= x_min && x <= x_max
+            })
+            .count()
+    }
+
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Persistent color a line enters `x` with: the last `ColorOp::Persistent`
+    /// before `x`, unless a `Reset` came after it. Sources that do not keep
+    /// per-point color ops return `None`.
+    fn persistent_color_before(&self, _x: f64) -> Option<Hsla> {
+        None
+    }
+
+    /// X of the point `steps` positions after (positive) or before (negative) `x`.
+    /// Stops at the first/last point if there are fewer points in that direction.
+    /// The default walks the points; sources sorted by X override it with
+    /// `step_sorted_x`.
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        let (x_min, x_max, _, _) = self.get_bounds()?;
+        let n = steps.unsigned_abs();
+        if n == 0 {
+            return None;
+        }
+        if steps > 0 {
+            self.iter_range(x, x_max)
+                .map(|p| p.x())
+                .filter(|&px| px > x)
+                .take(n)
+                .last()
+        } else {
+            let mut window = VecDeque::with_capacity(n);
+            for px in self.iter_range(x_min, x).map(|p| p.x()).filter(|&px| px < x) {
+                if window.len() == n {
+                    window.pop_front();
+                }
+                window.push_back(px);
+            }
+            window.front().copied()
+        }
+    }
+
+    /// Point with the X nearest to `x`, the earlier one on a tie. Found through
+    /// `step_x` and exact `iter_range` lookups.
+    fn nearest_point(&self, x: f64) -> Option<PlotData> {
+        let at = |px: f64| self.iter_range(px, px).find(|p| p.x() == px);
+        if let Some(p) = at(x) {
+            return Some(p);
+        }
+        let px = match (self.step_x(x, -1), self.step_x(x, 1)) {
+            (Some(before), Some(after)) if after - x < x - before => after,
+            (Some(before), _) => before,
+            (None, after) => after?,
+        };
+        at(px)
+    }
+}
+
+/// First index in `0..len` for which `pred` is false, `pred` holding for a
+/// prefix of the range (`slice::partition_point` on indices).
+fn partition_index(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// `PlotDataSource::step_x` over `len` points sorted by X, `x_at(i)` being the
+/// X of point `i`, by binary search.
+pub(crate) fn step_sorted_x(
+    len: usize,
+    x_at: impl Fn(usize) -> f64,
+    x: f64,
+    steps: isize,
+) -> Option<f64> {
+    let n = steps.unsigned_abs();
+    if n == 0 {
+        return None;
+    }
+    let i = if steps > 0 {
+        let first_after = partition_index(len, |i| x_at(i) <= x);
+        if first_after == len {
+            return None;
+        }
+        (first_after + n - 1).min(len - 1)
+    } else {
+        let before = partition_index(len, |i| x_at(i) < x);
+        before.checked_sub(1)?.saturating_sub(n - 1)
+    };
+    Some(x_at(i))
+}
+
+/// Walks points backwards from just before a position and returns the color
+/// set by the first `Persistent`/`Reset` op met.
+fn last_persistent_color<'a>(rev: impl Iterator<Item = &'a PlotData>) -> Option<Hsla> {
+    for p in rev {
+        match p.color_op() {
+            ColorOp::Persistent(c) => return Some(c),
+            ColorOp::Reset => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Empty domain, to be widened with `extend_domain`.
+fn empty_domain() -> AxisDomain {
+    AxisDomain {
+        x_min: f64::INFINITY,
+        x_max: f64::NEG_INFINITY,
+        y_min: f64::INFINITY,
+        y_max: f64::NEG_INFINITY,
+        ..Default::default()
+    }
+}
+
+/// Widens `domain` to cover `p`. NaN and infinite coordinates are skipped,
+/// so a single bad sample cannot break the bounds used by auto-fit.
+fn extend_domain(domain: &mut AxisDomain, p: &PlotData) {
+    let (xs, ys) = match p {
+        PlotData::Point(pt) => ([pt.x, pt.x], [pt.y, pt.y]),
+        PlotData::Ohlcv(o) => ([o.time, o.time + o.span], [o.low, o.high]),
+    };
+    for x in xs.into_iter().filter(|v| v.is_finite()) {
+        domain.x_min = domain.x_min.min(x);
+        domain.x_max = domain.x_max.max(x);
+    }
+    for y in ys.into_iter().filter(|v| v.is_finite()) {
+        domain.y_min = domain.y_min.min(y);
+        domain.y_max = domain.y_max.max(y);
+    }
+}
+
+/// The bounds, or None when no finite value was found on an axis.
+fn finite_bounds(b: (f64, f64, f64, f64)) -> Option<(f64, f64, f64, f64)> {
+    (b.0 <= b.1 && b.2 <= b.3).then_some(b)
+}
+
+/// The Y range, or None when no finite value was found.
+fn finite_y_range(y_min: f64, y_max: f64) -> Option<(f64, f64)> {
+    (y_min <= y_max).then_some((y_min, y_max))
+}
+
+fn has_color_ops<'a>(mut data: impl Iterator<Item = &'a PlotData>) -> bool {
+    data.any(|p| p.color_op() != ColorOp::None)
+}
+
+/// Puts `data` in ascending X order, which the binary-search lookups
+/// (`iter_range`, `partition_point`) rely on. Descending data (newest
+/// first) is reversed. Other orders are sorted when `sort` is set; otherwise
+/// they are left as-is, which panics in debug builds since lookups would
+/// silently miss points.
+pub fn ensure_ascending_x(data: &mut [PlotData], sort: bool) {
+    use crate::decimation::common::get_data_x;
+    use std::cmp::Ordering;
+    // NaN X values compare as unordered and do not break either order
+    let in_order = |unwanted: Ordering| {
+        data.windows(2)
+            .all(|w| get_data_x(&w[1]).partial_cmp(&get_data_x(&w[0])) != Some(unwanted))
+    };
+    if in_order(Ordering::Less) {
+        return;
+    }
+    let descending = in_order(Ordering::Greater);
+    if descending {
+        data.reverse();
+    } else if sort {
+        data.sort_by(|a, b| get_data_x(a).total_cmp(&get_data_x(b)));
+    } else {
+        tracing::warn!("data source received points not sorted by X; enable sort_on_set");
+        debug_assert!(false, "data source received points not sorted by X; enable sort_on_set");
+    }
 }
 
 /// Data source optimized for real-time streaming with a fixed capacity.
@@ -71,6 +335,13 @@ pub struct StreamingDataSource {
     current_chunk_count: usize,         // Points in the first chunk
     points_in_last_chunk: usize,        // Points in the last chunk
     suggested_spacing: f64,
+    decimation_threshold: f64,
+    decimation_policy: DecimationPolicy,
+    /// Sort unordered data passed to `set_data`, see `ensure_ascending_x`
+    sort_on_set: bool,
+    /// Some point carries a color op, so `persistent_color_before` has to scan
+    has_color_ops: bool,
+    version: u64,
 }
 
 const CHUNK_SIZE: usize = 512;
@@ -84,9 +355,24 @@ impl StreamingDataSource {
             current_chunk_count: 0,
             points_in_last_chunk: 0,
             suggested_spacing: 1.0,
+            decimation_threshold: 1.0,
+            decimation_policy: DecimationPolicy::Auto,
+            sort_on_set: false,
+            has_color_ops: false,
+            version: 0,
         }
     }
 
+    pub fn with_sort_on_set(mut self, sort: bool) -> Self {
+        self.sort_on_set = sort;
+        self
+    }
+
+    pub fn with_decimation_threshold(mut self, threshold: f64) -> Self {
+        self.set_decimation_threshold(threshold);
+        self
+    }
+
     fn update_suggested_spacing(&mut self, new_x: f64) {
         if let Some(last) = self.data.back() {
             let last_x = match last {
@@ -104,21 +390,28 @@ impl StreamingDataSource {
         }
     }
 
+    /// Number of points covered by the `i`-th cached chunk: the first one shrinks
+    /// on eviction, the last one may be partially filled, the others are full.
+    fn chunk_len(&self, i: usize) -> usize {
+        if i == 0 {
+            self.current_chunk_count
+        } else if i + 1 == self.bounds_cache.len() {
+            self.points_in_last_chunk
+        } else {
+            CHUNK_SIZE
+        }
+    }
+
     fn rebuild_cache(&mut self) {
         let mut min_spacing = f64::INFINITY;
         let mut last_x: Option<f64> = None;
         self.bounds_cache.clear();
+        self.has_color_ops = has_color_ops(self.data.iter());
         self.current_chunk_count = 0;
         self.points_in_last_chunk = 0;
 
         let mut count = 0;
-        let mut domain = AxisDomain {
-            x_min: f64::INFINITY,
-            x_max: f64::NEG_INFINITY,
-            y_min: f64::INFINITY,
-            y_max: f64::NEG_INFINITY,
-            ..Default::default()
-        };
+        let mut domain = empty_domain();
 
         for p in self.data.iter() {
             let x = match p {
@@ -132,21 +425,7 @@ impl StreamingDataSource {
                 }
             }
             last_x = Some(x);
-
-            match p {
-                PlotData::Point(pt) => {
-                    domain.x_min = domain.x_min.min(pt.x);
-                    domain.x_max = domain.x_max.max(pt.x);
-                    domain.y_min = domain.y_min.min(pt.y);
-                    domain.y_max = domain.y_max.max(pt.y);
-                }
-                PlotData::Ohlcv(o) => {
-                    domain.x_min = domain.x_min.min(o.time);
-                    domain.x_max = domain.x_max.max(o.time + o.span);
-                    domain.y_min = domain.y_min.min(o.low);
-                    domain.y_max = domain.y_max.max(o.high);
-                }
-            }
+            extend_domain(&mut domain, p);
 
             count += 1;
             if count == CHUNK_SIZE {
@@ -154,13 +433,7 @@ impl StreamingDataSource {
                     self.current_chunk_count = CHUNK_SIZE;
                 }
                 self.bounds_cache.push_back(domain);
-                domain = AxisDomain {
-                    x_min: f64::INFINITY,
-                    x_max: f64::NEG_INFINITY,
-                    y_min: f64::INFINITY,
-                    y_max: f64::NEG_INFINITY,
-                    ..Default::default()
-                };
+                domain = empty_domain();
                 count = 0;
             }
         }
@@ -184,9 +457,49 @@ impl StreamingDataSource {
 }
 
 impl PlotDataSource for StreamingDataSource {
+    fn decimation_threshold(&self) -> f64 {
+        self.decimation_threshold
+    }
+
+    fn set_decimation_threshold(&mut self, threshold: f64) {
+        self.decimation_threshold = threshold.max(1.0);
+    }
+
+    fn decimation_policy(&self) -> DecimationPolicy {
+        self.decimation_policy
+    }
+
+    fn set_decimation_policy(&mut self, policy: DecimationPolicy) {
+        self.decimation_policy = policy;
+    }
+
     fn len(&self) -> usize {
         self.data.len()
     }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        let x_at = |i: usize| crate::decimation::common::get_data_x(&self.data[i]);
+        step_sorted_x(self.data.len(), x_at, x, steps)
+    }
+
+    fn count_in_range(&self, x_min: f64, x_max: f64) -> usize {
+        let start = self.data.partition_point(|p| crate::decimation::common::get_data_x(p) < x_min);
+        let end = self.data.partition_point(|p| crate::decimation::common::get_data_x(p) <= x_max);
+        end.saturating_sub(start)
+    }
+
+    fn persistent_color_before(&self, x: f64) -> Option<Hsla> {
+        if !self.has_color_ops {
+            return None;
+        }
+        let end = self.data.partition_point(|p| crate::decimation::common::get_data_x(p) < x);
+        last_persistent_color(self.data.range(..end).rev())
+    }
+
     fn suggested_x_spacing(&self) -> f64 {
         self.suggested_spacing
     }
@@ -207,7 +520,7 @@ impl PlotDataSource for StreamingDataSource {
             b.2 = b.2.min(d.y_min);
             b.3 = b.3.max(d.y_max);
         }
-        Some(b)
+        finite_bounds(b)
     }
 
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
@@ -218,12 +531,12 @@ impl PlotDataSource for StreamingDataSource {
         let mut y_max = f64::NEG_INFINITY;
         let mut found = false;
 
-        let first_chunk_size = self.current_chunk_count;
         let mut current_data_start = 0;
 
         for (i, chunk) in self.bounds_cache.iter().enumerate() {
+            let end = (current_data_start + self.chunk_len(i)).min(self.data.len());
             if chunk.x_max < x_min || chunk.x_min > x_max {
-                current_data_start += if i == 0 { first_chunk_size } else { CHUNK_SIZE };
+                current_data_start = end;
                 continue;
             }
 
@@ -231,36 +544,27 @@ impl PlotDataSource for StreamingDataSource {
                 y_min = y_min.min(chunk.y_min);
                 y_max = y_max.max(chunk.y_max);
                 found = true;
-                current_data_start += if i == 0 { first_chunk_size } else { CHUNK_SIZE };
+                current_data_start = end;
             } else {
-                let count = if i == 0 { first_chunk_size } else { CHUNK_SIZE };
-                let end = current_data_start + count;
-
+                let mut domain = empty_domain();
                 for p in self.data.range(current_data_start..end) {
                     let x = match p {
                         PlotData::Point(pt) => pt.x,
                         PlotData::Ohlcv(o) => o.time,
                     };
                     if x >= x_min && x <= x_max {
-                        match p {
-                            PlotData::Point(pt) => {
-                                y_min = y_min.min(pt.y);
-                                y_max = y_max.max(pt.y);
-                            }
-                            PlotData::Ohlcv(o) => {
-                                y_min = y_min.min(o.low);
-                                y_max = y_max.max(o.high);
-                            }
-                        }
+                        extend_domain(&mut domain, p);
                         found = true;
                     }
                 }
+                y_min = y_min.min(domain.y_min);
+                y_max = y_max.max(domain.y_max);
                 current_data_start = end;
             }
         }
 
         if found {
-            Some((y_min, y_max))
+            finite_y_range(y_min, y_max)
         } else {
             None
         }
@@ -306,12 +610,57 @@ impl PlotDataSource for StreamingDataSource {
         Box::new(crate::decimation::decimate_min_max_slice(&data, max_points, gaps, None).into_iter())
     }
 
+    fn get_aggregated_data(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        output: &mut Vec<PlotData>,
+        gaps: Option<&GapIndex>,
+    ) {
+        output.clear();
+
+        let count = self.count_in_range(x_min, x_max);
+        let max_points = match self.decimation_budget(count, max_points) {
+            Some(budget) => budget,
+            None => {
+                output.extend(self.iter_range(x_min, x_max));
+                return;
+            }
+        };
+
+        // Same split as `VecDataSource`: each session gets its share of the points
+        if let Some(segments) =
+            gaps.and_then(|g| g.split_point_budget(x_min as i64, x_max as i64, max_points))
+        {
+            let mut segment_data = Vec::new();
+            for (s, e, segment_max_points) in segments {
+                if segment_max_points > 0 {
+                    segment_data.clear();
+                    segment_data.extend(self.iter_range(s as f64, e as f64));
+                    crate::decimation::decimate_min_max_slice_into(
+                        &segment_data,
+                        segment_max_points,
+                        output,
+                        None, // No gaps inside this segment
+                        None,
+                    );
+                }
+            }
+            return;
+        }
+
+        output.extend(self.iter_aggregated(x_min, x_max, max_points, gaps));
+    }
+
     fn add_data(&mut self, data: PlotData) {
+        self.version += 1;
         let x = match &data {
             PlotData::Point(p) => p.x,
             PlotData::Ohlcv(o) => o.time,
         };
         self.update_suggested_spacing(x);
+        self.has_color_ops |= data.color_op() != ColorOp::None;
 
         if self.data.len() >= self.capacity {
             self.data.pop_front();
@@ -322,39 +671,21 @@ impl PlotDataSource for StreamingDataSource {
                 if self.current_chunk_count == 0 {
                     // The first chunk is empty, remove it
                     self.bounds_cache.pop_front();
-                    // The next chunk (if any) becomes the current first chunk
-                    if !self.bounds_cache.is_empty() {
-                        self.current_chunk_count = CHUNK_SIZE; // It was a full chunk
-                    }
+                    // The next chunk (if any) becomes the current first chunk.
+                    // Middle chunks are full, the last one may be partial.
+                    self.current_chunk_count = match self.bounds_cache.len() {
+                        0 => 0,
+                        1 => self.points_in_last_chunk,
+                        _ => CHUNK_SIZE,
+                    };
                 } else {
                     // The first chunk has changed, we must recompute its bounds
                     // It corresponds to data[0..self.current_chunk_count]
                     if let Some(first_chunk_bounds) = self.bounds_cache.front_mut() {
-                        *first_chunk_bounds = AxisDomain {
-                            x_min: f64::INFINITY,
-                            x_max: f64::NEG_INFINITY,
-                            y_min: f64::INFINITY,
-                            y_max: f64::NEG_INFINITY,
-                            ..Default::default()
-                        };
+                        *first_chunk_bounds = empty_domain();
                         // Recompute bounds for the remaining points in this chunk
-                        for i in 0..self.current_chunk_count {
-                            let p = &self.data[i];
-                            match p {
-                                PlotData::Point(pt) => {
-                                    first_chunk_bounds.x_min = first_chunk_bounds.x_min.min(pt.x);
-                                    first_chunk_bounds.x_max = first_chunk_bounds.x_max.max(pt.x);
-                                    first_chunk_bounds.y_min = first_chunk_bounds.y_min.min(pt.y);
-                                    first_chunk_bounds.y_max = first_chunk_bounds.y_max.max(pt.y);
-                                }
-                                PlotData::Ohlcv(o) => {
-                                    first_chunk_bounds.x_min = first_chunk_bounds.x_min.min(o.time);
-                                    first_chunk_bounds.x_max =
-                                        first_chunk_bounds.x_max.max(o.time + o.span);
-                                    first_chunk_bounds.y_min = first_chunk_bounds.y_min.min(o.low);
-                                    first_chunk_bounds.y_max = first_chunk_bounds.y_max.max(o.high);
-                                }
-                            }
+                        for p in self.data.range(..self.current_chunk_count) {
+                            extend_domain(first_chunk_bounds, p);
                         }
                     }
                 }
@@ -364,44 +695,15 @@ impl PlotDataSource for StreamingDataSource {
         self.data.push_back(data.clone());
 
         if self.points_in_last_chunk == CHUNK_SIZE || self.bounds_cache.is_empty() {
-            let mut domain = AxisDomain {
-                x_min: x,
-                x_max: x,
-                y_min: f64::INFINITY,
-                y_max: f64::NEG_INFINITY,
-                ..Default::default()
-            };
-            match data {
-                PlotData::Point(pt) => {
-                    domain.y_min = pt.y;
-                    domain.y_max = pt.y;
-                }
-                PlotData::Ohlcv(o) => {
-                    domain.x_max = o.time + o.span;
-                    domain.y_min = o.low;
-                    domain.y_max = o.high;
-                }
-            }
+            let mut domain = empty_domain();
+            extend_domain(&mut domain, &data);
             self.bounds_cache.push_back(domain);
             self.points_in_last_chunk = 1;
             if self.bounds_cache.len() == 1 {
                 self.current_chunk_count = 1;
             }
         } else if let Some(last) = self.bounds_cache.back_mut() {
-            match data {
-                PlotData::Point(pt) => {
-                    last.x_min = last.x_min.min(pt.x);
-                    last.x_max = last.x_max.max(pt.x);
-                    last.y_min = last.y_min.min(pt.y);
-                    last.y_max = last.y_max.max(pt.y);
-                }
-                PlotData::Ohlcv(o) => {
-                    last.x_min = last.x_min.min(o.time);
-                    last.x_max = last.x_max.max(o.time + o.span);
-                    last.y_min = last.y_min.min(o.low);
-                    last.y_max = last.y_max.max(o.high);
-                }
-            }
+            extend_domain(last, &data);
             self.points_in_last_chunk += 1;
             if self.bounds_cache.len() == 1 {
                 self.current_chunk_count += 1;
@@ -409,7 +711,9 @@ impl PlotDataSource for StreamingDataSource {
         }
     }
 
-    fn set_data(&mut self, data: Vec<PlotData>) {
+    fn set_data(&mut self, mut data: Vec<PlotData>) {
+        self.version += 1;
+        ensure_ascending_x(&mut data, self.sort_on_set);
         self.data = VecDeque::from(data);
         if self.data.len() > self.capacity {
             let to_remove = self.data.len() - self.capacity;
@@ -429,24 +733,120 @@ pub struct VecDataSource {
     /// Reusing AxisDomain for chunk bounds
     bounds_cache: Vec<AxisDomain>,
     suggested_spacing: f64,
+    /// `Custom` skips the LOD pyramid and decimates with `custom_aggregator`
+    /// instead, `StableLTTB` with `decimate_lttb_stable_slice_into`.
+    mode: AggregationMode,
+    custom_aggregator: Option<CustomAggregator>,
+    /// Overrides `mode` depending on the zoom level, see `with_zoom_aggregation`
+    zoom_aggregation: Option<ZoomAggregation>,
+    /// Overall (x_min, x_max, y_min, y_max), kept up to date by `set_data`
+    /// and `add_data` so `get_bounds` is O(1).
+    total_bounds: Option<(f64, f64, f64, f64)>,
+    decimation_threshold: f64,
+    decimation_policy: DecimationPolicy,
+    /// Sort unordered data passed to `set_data`, see `ensure_ascending_x`
+    sort_on_set: bool,
+    /// Some point carries a color op, so `persistent_color_before` has to scan
+    has_color_ops: bool,
+    /// Build the LOD pyramid, see `with_lod_pyramid`
+    lod_enabled: bool,
+    /// Pyramid levels keep the min and max of groups of points instead of
+    /// averaging pairs, see `with_aggregation_mode`
+    lod_extremes: bool,
+    version: u64,
 }
 
 impl VecDataSource {
-    pub fn new(data: Vec<PlotData>) -> Self {
+    /// Expects points in ascending (or descending) X order, see
+    /// `new_sorted` for data in any order.
+    pub fn new(mut data: Vec<PlotData>) -> Self {
+        ensure_ascending_x(&mut data, false);
         let mut inst = Self {
             data,
             lod_levels: Vec::new(),
             bounds_cache: Vec::new(),
             suggested_spacing: 1.0,
+            mode: AggregationMode::M4,
+            custom_aggregator: None,
+            zoom_aggregation: None,
+            total_bounds: None,
+            decimation_threshold: 1.0,
+            decimation_policy: DecimationPolicy::Auto,
+            sort_on_set: false,
+            has_color_ops: false,
+            lod_enabled: true,
+            lod_extremes: false,
+            version: 0,
         };
         inst.rebuild_cache();
         inst.build_lod_pyramid();
         inst
     }
 
+    /// Selecting `MinMax` or `M4` explicitly also builds the LOD pyramid from
+    /// the min and max of every group of 4 points, so spikes survive at coarse
+    /// zoom levels. Otherwise, as by default, levels average pairs of points.
+    pub fn with_aggregation_mode(mut self, mode: AggregationMode) -> Self {
+        self.set_aggregation_mode(mode);
+        self
+    }
+
+    pub fn with_decimation_threshold(mut self, threshold: f64) -> Self {
+        self.set_decimation_threshold(threshold);
+        self
+    }
+
+    /// Sorts `data` by X, and the data of later `set_data` calls.
+    pub fn new_sorted(mut data: Vec<PlotData>) -> Self {
+        ensure_ascending_x(&mut data, true);
+        Self::new(data).with_sort_on_set(true)
+    }
+
+    pub fn with_sort_on_set(mut self, sort: bool) -> Self {
+        self.sort_on_set = sort;
+        self
+    }
+
+    /// Decimates with `reducer`, called once per gap-aware bucket, and switches
+    /// the source to `AggregationMode::Custom`.
+    pub fn with_custom_aggregation(
+        mut self,
+        reducer: impl Fn(&[PlotData]) -> smallvec::SmallVec<[PlotData; 4]> + Send + Sync + 'static,
+    ) -> Self {
+        self.custom_aggregator = Some(Box::new(reducer));
+        self.mode = AggregationMode::Custom;
+        self.build_lod_pyramid();
+        self
+    }
+
+    /// Enables (default) or disables the LOD pyramid. Disabled, every frame
+    /// decimates the raw points in view with `decimate_min_max_slice_into`,
+    /// so the exact min and max of each bucket are drawn whatever the mode
+    /// (pyramid levels average pairs of points unless extremes were selected,
+    /// see `with_aggregation_mode`). This costs
+    /// a pass over all visible points per frame instead of a level lookup,
+    /// and saves the memory of the levels (about the size of the data).
+    pub fn with_lod_pyramid(mut self, enabled: bool) -> Self {
+        self.lod_enabled = enabled;
+        self.build_lod_pyramid();
+        self
+    }
+
+    /// Switches the aggregation mode with the zoom level. The LOD pyramid is
+    /// built for the source's own mode; other modes decimate the points in
+    /// view directly. The hysteresis needs the step a view used last, see
+    /// `get_aggregated_data_at`; `get_aggregated_data` starts from none.
+    pub fn with_zoom_aggregation(mut self, zoom: ZoomAggregation) -> Self {
+        self.zoom_aggregation = Some(zoom);
+        self
+    }
+
     fn build_lod_pyramid(&mut self) {
         self.lod_levels.clear();
-        if self.data.len() < 2000 {
+        if !self.lod_enabled
+            || self.data.len() < 2000
+            || matches!(self.mode, AggregationMode::Custom | AggregationMode::StableLTTB)
+        {
             return;
         }
 
@@ -460,9 +860,17 @@ impl VecDataSource {
                 self.lod_levels.last().unwrap()
             };
 
-            for chunk in source_to_read.chunks(2) {
-                if let Some(agg) = crate::decimation::aggregate_chunk(chunk) {
-                    level.push(agg);
+            if self.lod_extremes {
+                // Each group of 4 points yields its min and max, so the level
+                // still halves the point count but transient spikes survive.
+                for chunk in source_to_read.chunks(4) {
+                    crate::decimation::aggregate_chunk_extremes(chunk, &mut level);
+                }
+            } else {
+                for chunk in source_to_read.chunks(2) {
+                    if let Some(agg) = crate::decimation::aggregate_chunk(chunk) {
+                        level.push(agg);
+                    }
                 }
             }
 
@@ -473,17 +881,12 @@ impl VecDataSource {
 
     fn rebuild_cache(&mut self) {
         self.bounds_cache.clear();
+        self.has_color_ops = has_color_ops(self.data.iter());
         let mut min_spacing = f64::INFINITY;
         let mut last_x: Option<f64> = None;
 
         for chunk in self.data.chunks(CHUNK_SIZE) {
-            let mut domain = AxisDomain {
-                x_min: f64::INFINITY,
-                x_max: f64::NEG_INFINITY,
-                y_min: f64::INFINITY,
-                y_max: f64::NEG_INFINITY,
-                ..Default::default()
-            };
+            let mut domain = empty_domain();
             for p in chunk {
                 let x = self.get_x(p);
                 if let Some(lx) = last_x {
@@ -493,21 +896,7 @@ impl VecDataSource {
                     }
                 }
                 last_x = Some(x);
-
-                match p {
-                    PlotData::Point(pt) => {
-                        domain.x_min = domain.x_min.min(pt.x);
-                        domain.x_max = domain.x_max.max(pt.x);
-                        domain.y_min = domain.y_min.min(pt.y);
-                        domain.y_max = domain.y_max.max(pt.y);
-                    }
-                    PlotData::Ohlcv(o) => {
-                        domain.x_min = domain.x_min.min(o.time);
-                        domain.x_max = domain.x_max.max(o.time + o.span);
-                        domain.y_min = domain.y_min.min(o.low);
-                        domain.y_max = domain.y_max.max(o.high);
-                    }
-                }
+                extend_domain(&mut domain, p);
             }
             self.bounds_cache.push(domain);
         }
@@ -516,6 +905,19 @@ impl VecDataSource {
         } else {
             min_spacing
         };
+
+        self.total_bounds = None;
+        for i in 0..self.bounds_cache.len() {
+            let d = &self.bounds_cache[i];
+            self.extend_total_bounds((d.x_min, d.x_max, d.y_min, d.y_max));
+        }
+    }
+
+    fn extend_total_bounds(&mut self, (x_min, x_max, y_min, y_max): (f64, f64, f64, f64)) {
+        self.total_bounds = Some(match self.total_bounds {
+            Some(b) => (b.0.min(x_min), b.1.max(x_max), b.2.min(y_min), b.3.max(y_max)),
+            None => (x_min, x_max, y_min, y_max),
+        });
     }
 
     fn get_x(&self, data: &PlotData) -> f64 {
@@ -527,31 +929,81 @@ impl VecDataSource {
 }
 
 impl PlotDataSource for VecDataSource {
+    fn aggregation_mode(&self) -> AggregationMode {
+        self.mode
+    }
+
+    fn set_aggregation_mode(&mut self, mode: AggregationMode) {
+        let extremes = matches!(mode, AggregationMode::MinMax | AggregationMode::M4);
+        if self.mode != mode || self.lod_extremes != extremes {
+            self.mode = mode;
+            self.lod_extremes = extremes;
+            self.build_lod_pyramid();
+        }
+    }
+
+    fn aggregation_mode_for(
+        &self,
+        count: usize,
+        max_points: usize,
+        zoom_step: &mut Option<usize>,
+    ) -> AggregationMode {
+        let Some(zoom) = &self.zoom_aggregation else {
+            return self.mode;
+        };
+        let ratio = count as f64 / max_points.max(1) as f64;
+        *zoom_step = zoom.step_for(ratio, *zoom_step);
+        zoom_step.map_or(self.mode, |s| zoom.steps[s].1)
+    }
+
+    fn decimation_threshold(&self) -> f64 {
+        self.decimation_threshold
+    }
+
+    fn set_decimation_threshold(&mut self, threshold: f64) {
+        self.decimation_threshold = threshold.max(1.0);
+    }
+
+    fn decimation_policy(&self) -> DecimationPolicy {
+        self.decimation_policy
+    }
+
+    fn set_decimation_policy(&mut self, policy: DecimationPolicy) {
+        self.decimation_policy = policy;
+    }
+
     fn len(&self) -> usize {
         self.data.len()
     }
 
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        step_sorted_x(self.data.len(), |i| self.get_x(&self.data[i]), x, steps)
+    }
+
+    fn count_in_range(&self, x_min: f64, x_max: f64) -> usize {
+        let start = self.data.partition_point(|p| self.get_x(p) < x_min);
+        let end = self.data.partition_point(|p| self.get_x(p) <= x_max);
+        end.saturating_sub(start)
+    }
+
+    fn persistent_color_before(&self, x: f64) -> Option<Hsla> {
+        if !self.has_color_ops {
+            return None;
+        }
+        let end = self.data.partition_point(|p| self.get_x(p) < x);
+        last_persistent_color(self.data[..end].iter().rev())
+    }
+
     fn suggested_x_spacing(&self) -> f64 {
         self.suggested_spacing
     }
 
     fn get_bounds(&self) -> Option<(f64, f64, f64, f64)> {
-        if self.bounds_cache.is_empty() {
-            return None;
-        }
-        let mut b = (
-            f64::INFINITY,
-            f64::NEG_INFINITY,
-            f64::INFINITY,
-            f64::NEG_INFINITY,
-        );
-        for d in &self.bounds_cache {
-            b.0 = b.0.min(d.x_min);
-            b.1 = b.1.max(d.x_max);
-            b.2 = b.2.min(d.y_min);
-            b.3 = b.3.max(d.y_max);
-        }
-        Some(b)
+        self.total_bounds.and_then(finite_bounds)
     }
 
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
@@ -574,25 +1026,19 @@ impl PlotDataSource for VecDataSource {
             }
             let start = i * CHUNK_SIZE;
             let end = (start + CHUNK_SIZE).min(self.data.len());
+            let mut domain = empty_domain();
             for p in &self.data[start..end] {
                 let x = self.get_x(p);
                 if x >= x_min && x <= x_max {
-                    match p {
-                        PlotData::Point(pt) => {
-                            y_min = y_min.min(pt.y);
-                            y_max = y_max.max(pt.y);
-                        }
-                        PlotData::Ohlcv(o) => {
-                            y_min = y_min.min(o.low);
-                            y_max = y_max.max(o.high);
-                        }
-                    }
+                    extend_domain(&mut domain, p);
                     found = true;
                 }
             }
+            y_min = y_min.min(domain.y_min);
+            y_max = y_max.max(domain.y_max);
         }
         if found {
-            Some((y_min, y_max))
+            finite_y_range(y_min, y_max)
         } else {
             None
         }
@@ -632,47 +1078,115 @@ impl PlotDataSource for VecDataSource {
         max_points: usize,
         output: &mut Vec<PlotData>,
         gaps: Option<&GapIndex>,
+    ) {
+        self.get_aggregated_data_at(x_min, x_max, max_points, output, gaps, &mut None);
+    }
+
+    fn get_aggregated_data_at(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        output: &mut Vec<PlotData>,
+        gaps: Option<&GapIndex>,
+        zoom_step: &mut Option<usize>,
     ) {
         output.clear();
 
-        if let Some(g) = gaps {
-            let intervals = g.split_range(x_min as i64, x_max as i64);
-            if intervals.len() > 1 {
-                let total_logical_span: f64 = intervals
-                    .iter()
-                    .map(|(s, e)| (g.to_logical(*e) - g.to_logical(*s)) as f64)
-                    .sum();
-
-                if total_logical_span > 0.0 {
-                    for (s, e) in intervals {
-                        let logical_span = (g.to_logical(e) - g.to_logical(s)) as f64;
-                    let segment_max_points =
-                        ((logical_span / total_logical_span) * max_points as f64).round() as usize;
-                    if segment_max_points > 0 {
-                            self.get_aggregated_data_lod(
-                                s as f64,
-                                e as f64,
-                                segment_max_points,
-                                output,
-                            );
-                        }
+        let start_idx = self.data.partition_point(|p| self.get_x(p) < x_min);
+        let end_idx = self.data.partition_point(|p| self.get_x(p) <= x_max);
+        let start = start_idx.saturating_sub(1);
+        let end = (end_idx + 1).min(self.data.len());
+        let mode = self.aggregation_mode_for(end_idx - start_idx, max_points, zoom_step);
+        let max_points = match self.decimation_budget(end_idx - start_idx, max_points) {
+            Some(budget) => budget,
+            None => {
+                output.extend_from_slice(&self.data[start..end]);
+                return;
+            }
+        };
+
+        let slice = &self.data[start..end];
+        match (mode, &self.custom_aggregator) {
+            (AggregationMode::Custom, Some(reducer)) => {
+                crate::decimation::decimate_custom_slice_into(
+                    slice,
+                    max_points,
+                    output,
+                    gaps,
+                    None,
+                    reducer.as_ref(),
+                );
+                return;
+            }
+            // The pyramid only holds levels for `self.mode`, and none for StableLTTB
+            _ if mode != self.mode || mode == AggregationMode::StableLTTB => {
+                match mode {
+                    AggregationMode::M4 => crate::decimation::decimate_m4_slice_into(
+                        slice, max_points, output, gaps, None,
+                    ),
+                    AggregationMode::LTTB => output.extend(crate::decimation::decimate_lttb_slice(
+                        slice, max_points, gaps, None,
+                    )),
+                    AggregationMode::StableLTTB => {
+                        // Buckets sized from the view rather than the data, see
+                        // `decimate_lttb_stable_arrays_into`
+                        let view_range = match gaps {
+                            Some(g) => {
+                                (g.to_logical(x_max as i64) - g.to_logical(x_min as i64)) as f64
+                            }
+                            None => x_max - x_min,
+                        };
+                        crate::decimation::decimate_lttb_stable_slice_into(
+                            slice,
+                            max_points,
+                            output,
+                            gaps,
+                            Some(view_range),
+                        )
                     }
-                    return;
+                    AggregationMode::MinMax | AggregationMode::Custom => {
+                        crate::decimation::decimate_min_max_slice_into(
+                            slice, max_points, output, gaps, None,
+                        )
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        if let Some(segments) =
+            gaps.and_then(|g| g.split_point_budget(x_min as i64, x_max as i64, max_points))
+        {
+            for (s, e, segment_max_points) in segments {
+                if segment_max_points > 0 {
+                    self.get_aggregated_data_lod(s as f64, e as f64, segment_max_points, output);
                 }
             }
+            return;
         }
 
         self.get_aggregated_data_lod(x_min, x_max, max_points, output);
     }
 
     fn add_data(&mut self, data: PlotData) {
+        self.version += 1;
+        let mut domain = empty_domain();
+        extend_domain(&mut domain, &data);
+        let point_bounds = (domain.x_min, domain.x_max, domain.y_min, domain.y_max);
+        self.has_color_ops |= data.color_op() != ColorOp::None;
         self.data.push(data);
         if self.data.len() % CHUNK_SIZE == 1 {
             self.rebuild_cache();
+        } else {
+            self.extend_total_bounds(point_bounds);
         }
     }
 
-    fn set_data(&mut self, data: Vec<PlotData>) {
+    fn set_data(&mut self, mut data: Vec<PlotData>) {
+        self.version += 1;
+        ensure_ascending_x(&mut data, self.sort_on_set);
         self.data = data;
         self.rebuild_cache();
         self.build_lod_pyramid();
@@ -734,30 +1248,125 @@ impl VecDataSource {
 
 use super::axis::AxisId;
 
+/// Formats a value for display, e.g. `Arc::new(|v| format!("{v:.4} V"))`.
+pub type ValueFormatter = std::sync::Arc<dyn Fn(f64) -> String + Send + Sync>;
+
 #[derive(Clone)]
 pub struct Series {
     pub id: String,
     pub plot:
         std::sync::Arc<parking_lot::RwLock<dyn crate::plot_types::PlotRenderer + Send + Sync>>,
     pub y_axis_id: AxisId,
+    /// Name of the Y axis this series follows, resolved to `y_axis_id` by
+    /// the chart (see `on_named_axis`).
+    pub(crate) y_axis_name: Option<String>,
     pub x_axis_id: AxisId,
+    /// Alpha multiplier applied to the whole series at render time
+    pub(crate) opacity: f32,
+    /// Formats the values of this series in the crosshair readout instead of
+    /// the format of its Y axis (see `with_readout_format`).
+    pub(crate) readout_format: Option<ValueFormatter>,
+    /// Paint order within the pane, see `with_z_order`
+    pub(crate) z_order: i32,
 }
 
 impl Series {
     pub fn new(
         id: impl Into<String>,
         plot: impl crate::plot_types::PlotRenderer + 'static,
+    ) -> Self {
+        Self::from_shared(id, std::sync::Arc::new(parking_lot::RwLock::new(plot)))
+    }
+
+    /// Series drawing a plot that is also held elsewhere, e.g. to push
+    /// data into it after the series was added to a pane.
+    pub fn from_shared(
+        id: impl Into<String>,
+        plot: std::sync::Arc<
+            parking_lot::RwLock<dyn crate::plot_types::PlotRenderer + Send + Sync>,
+        >,
     ) -> Self {
         Self {
             id: id.into(),
-            plot: std::sync::Arc::new(parking_lot::RwLock::new(plot)),
+            plot,
             x_axis_id: AxisId(0),
             y_axis_id: AxisId(0),
+            y_axis_name: None,
+            opacity: 1.0,
+            readout_format: None,
+            z_order: 0,
         }
     }
 
     pub fn on_axis(mut self, y_axis_id: usize) -> Self {
         self.y_axis_id = AxisId(y_axis_id);
+        self.y_axis_name = None;
+        self
+    }
+
+    /// Attaches the series to the Y axis named `name` (see
+    /// `Chart::set_y_axis_name`), following it when axes are inserted,
+    /// removed or reordered. Until an axis with that name exists, the
+    /// series stays on its current axis.
+    pub fn on_named_axis(mut self, name: &str) -> Self {
+        self.y_axis_name = Some(name.to_string());
+        self
+    }
+
+    pub fn y_axis_name(&self) -> Option<&str> {
+        self.y_axis_name.as_deref()
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// Series are painted back to front by increasing `z_order`, and in the
+    /// order of the pane for equal values (all 0 by default).
+    pub fn with_z_order(mut self, z_order: i32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
+    pub fn z_order(&self) -> i32 {
+        self.z_order
+    }
+
+    /// Formats this series' values in the crosshair readout, e.g. with more
+    /// precision or a unit, while the axis ticks keep the axis format.
+    pub fn with_readout_format(
+        mut self,
+        format: impl Fn(f64) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.readout_format = Some(std::sync::Arc::new(format));
         self
     }
+
+    /// Draws this series always raw or always decimated instead of by point
+    /// count (`DecimationPolicy`). Applies to the plot's data source; plots
+    /// without one are left unchanged.
+    pub fn with_decimation(self, policy: DecimationPolicy) -> Self {
+        if let Some(source) = self.plot.write().data_source_mut() {
+            source.set_decimation_policy(policy);
+        }
+        self
+    }
+
+    /// Empties the data of the series, keeping its plot and settings.
+    pub fn clear(&self) {
+        self.plot.write().clear_data();
+    }
+
+    /// Readout text of `value`: `readout_format` if set, else `axis_format`.
+    pub fn format_readout(&self, value: f64, axis_format: impl Fn(f64) -> String) -> String {
+        match &self.readout_format {
+            Some(format) => format(value),
+            None => axis_format(value),
+        }
+    }
 }
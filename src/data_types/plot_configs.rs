@@ -1,9 +1,18 @@
-use gpui::Hsla;
+use super::data::Ohlcv;
+use gpui::{Hsla, Rgba};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct LinePlotConfig {
     pub color: Hsla,
     pub line_width: f32,
+    /// Color each segment by its Y value instead of using `color`
+    pub colormap: Option<ColorMap>,
+    /// Y values mapped to the ends of `colormap`; `None` uses the visible Y range
+    pub colormap_range: Option<(f64, f64)>,
+    /// Shape of the corners between segments; `Round` smooths sharp angles
+    pub line_join: LineJoin,
+    /// Shape of the line ends
+    pub line_cap: LineCap,
 }
 
 impl Default for LinePlotConfig {
@@ -11,10 +20,243 @@ impl Default for LinePlotConfig {
         Self {
             color: gpui::blue(),
             line_width: 2.0,
+            colormap: None,
+            colormap_range: None,
+            line_join: LineJoin::default(),
+            line_cap: LineCap::default(),
         }
     }
 }
 
+impl LinePlotConfig {
+    /// Colormap position (0..=1) of `y` within `range`, clamped at the ends.
+    pub fn colormap_position(y: f64, (lo, hi): (f64, f64)) -> f32 {
+        let span = hi - lo;
+        if !y.is_finite() || span == 0.0 || !span.is_finite() {
+            return 0.5;
+        }
+        (((y - lo) / span) as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// How a stroked line is drawn where two segments meet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Sharp corner (beveled past the miter limit)
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// How the ends of a stroked line are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineCap {
+    /// Flat, ending exactly at the end point
+    #[default]
+    Butt,
+    Round,
+    /// Flat, extended by half the line width
+    Square,
+}
+
+impl LineJoin {
+    pub(crate) fn to_lyon(self) -> lyon::tessellation::LineJoin {
+        match self {
+            LineJoin::Miter => lyon::tessellation::LineJoin::Miter,
+            LineJoin::Round => lyon::tessellation::LineJoin::Round,
+            LineJoin::Bevel => lyon::tessellation::LineJoin::Bevel,
+        }
+    }
+}
+
+impl LineCap {
+    pub(crate) fn to_lyon(self) -> lyon::tessellation::LineCap {
+        match self {
+            LineCap::Butt => lyon::tessellation::LineCap::Butt,
+            LineCap::Round => lyon::tessellation::LineCap::Round,
+            LineCap::Square => lyon::tessellation::LineCap::Square,
+        }
+    }
+}
+
+/// Piecewise-linear color scale over `0..=1`, interpolated in RGB so that
+/// stops of distant hues do not sweep through the hues in between.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorMap {
+    stops: Vec<(f32, Hsla)>,
+}
+
+impl ColorMap {
+    /// Stops are `(position, color)` pairs; they are sorted by position.
+    pub fn new(mut stops: Vec<(f32, Hsla)>) -> Self {
+        stops.retain(|(t, _)| t.is_finite());
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops }
+    }
+
+    /// Evenly spaced stops from the first color to the last.
+    pub fn from_colors(colors: &[Hsla]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (i as f32 / last, *c))
+                .collect(),
+        )
+    }
+
+    /// Cold-to-hot: blue at 0, red at 1.
+    pub fn blue_red() -> Self {
+        Self::from_colors(&[gpui::blue(), gpui::red()])
+    }
+
+    pub fn stops(&self) -> &[(f32, Hsla)] {
+        &self.stops
+    }
+
+    /// Color at position `t`, clamped to the first and last stops.
+    pub fn color_at(&self, t: f32) -> Hsla {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return gpui::transparent_black();
+        };
+        if t.is_nan() || t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+        let i = self.stops.partition_point(|(p, _)| *p <= t);
+        let ((t0, c0), (t1, c1)) = (self.stops[i - 1], self.stops[i]);
+        let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 1.0 };
+        let (a, b) = (Rgba::from(c0), Rgba::from(c1));
+        let lerp = |x: f32, y: f32| x + (y - x) * f;
+        Hsla::from(Rgba {
+            r: lerp(a.r, b.r),
+            g: lerp(a.g, b.g),
+            b: lerp(a.b, b.b),
+            a: lerp(a.a, b.a),
+        })
+    }
+}
+
+/// Mapping from a value to a `ColorMap` position, applied before sampling
+/// so that values spanning orders of magnitude still spread over the map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorScale {
+    #[default]
+    Linear,
+    /// Logarithmic: zero and negative values (and range bounds) are clamped
+    /// to the smallest positive value of the range.
+    Log,
+    /// `sign(v) * ln(1 + |v|)`: logarithmic away from zero, linear around it,
+    /// for values of both signs.
+    SymLog,
+}
+
+impl ColorScale {
+    /// Lowest bound of a log range whose lower end is not positive.
+    const LOG_FLOOR_RATIO: f64 = 1e-3;
+
+    fn transform(self, v: f64) -> f64 {
+        match self {
+            ColorScale::Linear => v,
+            ColorScale::Log => v.log10(),
+            ColorScale::SymLog => v.signum() * v.abs().ln_1p(),
+        }
+    }
+
+    /// Range actually used by the scale: for `Log`, a non-positive lower
+    /// bound becomes three decades below the upper one.
+    pub fn effective_range(self, (lo, hi): (f64, f64)) -> (f64, f64) {
+        match self {
+            ColorScale::Log if lo <= 0.0 => (hi * Self::LOG_FLOOR_RATIO, hi),
+            _ => (lo, hi),
+        }
+    }
+
+    /// Position of `value` in `range`, clamped to `0..=1`; 0.5 if the range
+    /// is empty or not usable by the scale.
+    pub fn normalize(self, value: f64, range: (f64, f64)) -> f32 {
+        let (lo, hi) = self.effective_range(range);
+        let value = match self {
+            ColorScale::Log => value.max(lo),
+            _ => value,
+        };
+        let (t_lo, t_hi) = (self.transform(lo), self.transform(hi));
+        let span = t_hi - t_lo;
+        if !span.is_finite() || span <= 0.0 {
+            return 0.5;
+        }
+        ((self.transform(value) - t_lo) / span).clamp(0.0, 1.0) as f32
+    }
+
+    /// Values to label a colorbar with: nice linear ticks, powers of ten for
+    /// `Log` (with 2 and 5 multiples on narrow ranges), and zero plus signed
+    /// powers of ten for `SymLog`.
+    pub fn ticks(self, range: (f64, f64), count: usize) -> Vec<f64> {
+        let (lo, hi) = self.effective_range(range);
+        if !(lo.is_finite() && hi.is_finite()) || hi <= lo {
+            return Vec::new();
+        }
+        match self {
+            ColorScale::Linear => {
+                use d3rs::scale::Scale;
+                d3rs::scale::LinearScale::new().domain(lo, hi).ticks(count)
+            }
+            ColorScale::Log => {
+                let decades = Self::decade_ticks(lo, hi, &[1.0]);
+                if decades.len() >= 2 {
+                    decades
+                } else {
+                    Self::decade_ticks(lo, hi, &[1.0, 2.0, 5.0])
+                }
+            }
+            ColorScale::SymLog => {
+                let mut ticks: Vec<f64> = Self::decade_ticks(1.0, (-lo).max(0.0), &[1.0])
+                    .into_iter()
+                    .rev()
+                    .map(|t| -t)
+                    .collect();
+                if lo <= 0.0 && hi >= 0.0 {
+                    ticks.push(0.0);
+                }
+                ticks.extend(Self::decade_ticks(1.0, hi, &[1.0]));
+                ticks.retain(|t| *t >= lo && *t <= hi);
+                ticks
+            }
+        }
+    }
+
+    /// `m * 10^k` for each multiplier `m`, within `lo..=hi` (both positive).
+    fn decade_ticks(lo: f64, hi: f64, multipliers: &[f64]) -> Vec<f64> {
+        if lo <= 0.0 || hi < lo {
+            return Vec::new();
+        }
+        let mut out = Vec::new();
+        for k in lo.log10().floor() as i32..=hi.log10().ceil() as i32 {
+            for m in multipliers {
+                let t = m * 10f64.powi(k);
+                if t >= lo * (1.0 - 1e-12) && t <= hi * (1.0 + 1e-12) {
+                    out.push(t);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// How each OHLCV sample is drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CandleStyle {
+    /// Filled body between open and close with high/low wicks
+    #[default]
+    Candle,
+    /// High-low line with the open ticked on the left and the close on the right
+    OhlcBar,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CandlestickConfig {
     pub up_wick_color: Hsla,
@@ -26,6 +268,40 @@ pub struct CandlestickConfig {
     pub body_width_pct: f32,
     pub wick_width_pct: f32,
     pub border_thickness_px: f32,
+    pub style: CandleStyle,
+    /// Minimum body height so doji candles (open == close) stay visible
+    pub min_body_px: f32,
+    /// Below this width, candles and bars are drawn as a plain high-low line
+    pub min_bar_width_px: f32,
+    /// The newest candle is still forming (live data): draw it with
+    /// `provisional_opacity` so it reads as not final.
+    pub provisional_last: bool,
+    pub provisional_opacity: f32,
+}
+
+impl CandlestickConfig {
+    /// Top and bottom of the body in screen space for the given open and close
+    /// positions, grown around their middle to at least `min_body_px`.
+    pub fn body_span_px(&self, y_open: f32, y_close: f32) -> (f32, f32) {
+        let (top, bottom) = (y_open.min(y_close), y_open.max(y_close));
+        if bottom - top >= self.min_body_px {
+            return (top, bottom);
+        }
+        let mid = (top + bottom) / 2.0;
+        (mid - self.min_body_px / 2.0, mid + self.min_body_px / 2.0)
+    }
+
+    /// Opacity of `candle` given the start time of the newest candle of the
+    /// series: `provisional_opacity` for the candle covering it (also when
+    /// aggregated) if `provisional_last` is set, 1 otherwise.
+    pub fn candle_opacity(&self, candle: &Ohlcv, newest_time: f64) -> f32 {
+        let covers_newest = candle.time >= newest_time || candle.time + candle.span > newest_time;
+        if self.provisional_last && covers_newest {
+            self.provisional_opacity
+        } else {
+            1.0
+        }
+    }
 }
 
 impl Default for CandlestickConfig {
@@ -42,15 +318,59 @@ impl Default for CandlestickConfig {
             body_width_pct: 0.8,
             wick_width_pct: 0.1,
             border_thickness_px: 1.0,
+            style: CandleStyle::Candle,
+            min_body_px: 1.0,
+            min_bar_width_px: 2.0,
+            provisional_last: false,
+            provisional_opacity: 0.45,
+        }
+    }
+}
+
+/// Level an area is filled down (or up) to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AreaBaseline {
+    #[default]
+    Zero,
+    Value(f64),
+    /// Lowest value of the whole series, so the area sits on its minimum
+    SeriesMin,
+}
+
+impl AreaBaseline {
+    /// Baseline in data units, given the lowest value of the series.
+    pub fn resolve(&self, series_min: Option<f64>) -> f64 {
+        match *self {
+            AreaBaseline::Zero => 0.0,
+            AreaBaseline::Value(v) => v,
+            AreaBaseline::SeriesMin => series_min.unwrap_or(0.0),
         }
     }
 }
 
+/// Style of an `AreaPlot`.
+///
+/// GPUI composites every path with plain alpha blending (source over), with
+/// no screen or lighten mode: where translucent areas overlap, the one painted
+/// last tints the others, and the series are painted in the order of the pane
+/// (see `Series::with_z_order`). `with_fill_behind_series` keeps the lines
+/// readable through the overlaps.
 #[derive(Clone, Debug, PartialEq)]
 pub struct AreaPlotConfig {
     pub line_color: Hsla,
+    /// Fill of the area, or of its part above the baseline when
+    /// `negative_fill_color` is set
     pub fill_color: Hsla,
+    /// Fill of the part below the baseline, `fill_color` when None
+    pub(crate) negative_fill_color: Option<Hsla>,
     pub line_width: f32,
+    /// None until set: the area fills to `AreaPlot::baseline`, which the
+    /// auto-fit leaves out
+    pub(crate) baseline: Option<AreaBaseline>,
+    /// Paints the fill with the background layers, under every series of
+    /// the pane, instead of right under this area's line, so no fill covers
+    /// the line of another series
+    pub(crate) fill_behind_series: bool,
 }
 
 impl Default for AreaPlotConfig {
@@ -58,11 +378,46 @@ impl Default for AreaPlotConfig {
         Self {
             line_color: gpui::blue(),
             fill_color: gpui::blue().alpha(0.3),
+            negative_fill_color: None,
             line_width: 2.0,
+            baseline: None,
+            fill_behind_series: false,
         }
     }
 }
 
+impl AreaPlotConfig {
+    /// Level the area is filled to, zero by default. Once set, the
+    /// auto-fit includes it.
+    pub fn with_baseline(mut self, baseline: AreaBaseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    pub fn baseline(&self) -> AreaBaseline {
+        self.baseline.unwrap_or_default()
+    }
+
+    /// Fills the part of the area below the baseline with `color`.
+    pub fn with_negative_fill_color(mut self, color: Hsla) -> Self {
+        self.negative_fill_color = Some(color);
+        self
+    }
+
+    pub fn negative_fill_color(&self) -> Option<Hsla> {
+        self.negative_fill_color
+    }
+
+    pub fn with_fill_behind_series(mut self, behind: bool) -> Self {
+        self.fill_behind_series = behind;
+        self
+    }
+
+    pub fn fill_behind_series(&self) -> bool {
+        self.fill_behind_series
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct BarPlotConfig {
     pub color: Hsla,
@@ -26,6 +26,9 @@ pub struct LegendConfig {
     pub enabled: bool,
     pub position: LegendPosition,
     pub orientation: Orientation,
+    /// Shows the move/isolate/remove buttons next to each entry. When false
+    /// the legend only lists the series (clicking a name still toggles it).
+    pub show_buttons: bool,
 }
 
 impl Default for LegendConfig {
@@ -34,6 +37,7 @@ impl Default for LegendConfig {
             enabled: true,
             position: LegendPosition::TopLeft,
             orientation: Orientation::Vertical,
+            show_buttons: true,
         }
     }
 }
@@ -43,7 +47,10 @@ pub struct InertiaConfig {
     pub enabled: bool,
     pub friction: f64,
     pub sensitivity: f64,
+    /// A drag released after being still for longer than this does not slide.
     pub stop_threshold: std::time::Duration,
+    /// Upper bound of the release velocity, in pixels per second.
+    pub max_velocity: f64,
 }
 
 impl Default for InertiaConfig {
@@ -53,10 +60,225 @@ impl Default for InertiaConfig {
             friction: 0.80,
             sensitivity: 1.0,
             stop_threshold: std::time::Duration::from_millis(150),
+            max_velocity: 4000.0,
         }
     }
 }
 
+/// Scroll-wheel zoom tuning, shared by the plot area and the axes.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ZoomConfig {
+    /// Zoom change per scrolled pixel (0.01 = 1% per pixel)
+    pub sensitivity: f64,
+    /// Smallest factor a single scroll event can apply (strongest zoom in)
+    pub min_factor: f64,
+    /// Largest factor a single scroll event can apply (strongest zoom out)
+    pub max_factor: f64,
+}
+
+impl Default for ZoomConfig {
+    fn default() -> Self {
+        Self {
+            sensitivity: 0.01,
+            min_factor: 0.1,
+            max_factor: 10.0,
+        }
+    }
+}
+
+impl ZoomConfig {
+    /// Span factor for a vertical scroll of `delta_px` pixels (< 1.0 zooms in).
+    pub fn wheel_factor(&self, delta_px: f32) -> f64 {
+        (1.0 - delta_px as f64 * self.sensitivity).clamp(self.min_factor, self.max_factor)
+    }
+}
+
+/// Width of the X window kept on the newest data by `Chart::set_follow_latest`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FollowWindow {
+    /// The last N points of the series holding the newest data
+    Points(usize),
+    /// The last X units (e.g. milliseconds on a time axis)
+    Span(f64),
+}
+
+/// Style of a single crosshair line.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CrosshairLineStyle {
+    /// Line color; `None` uses `theme.crosshair_line`
+    pub color: Option<gpui::Hsla>,
+    pub width: f32,
+    /// Dash pattern in pixels (on, off, ...); empty for a solid line
+    pub dash: Vec<f32>,
+}
+
+impl Default for CrosshairLineStyle {
+    fn default() -> Self {
+        Self {
+            color: None,
+            width: 1.0,
+            dash: vec![],
+        }
+    }
+}
+
+/// How the horizontal crosshair line follows the cursor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrosshairMode {
+    /// At the cursor Y
+    #[default]
+    Free,
+    /// At the value, under the cursor X, of the visible series closest to
+    /// the cursor, so the line rides along it and the Y tag shows its value
+    NearestSeriesY,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CrosshairConfig {
+    pub vertical: CrosshairLineStyle,
+    pub horizontal: CrosshairLineStyle,
+    /// Radius of the dot drawn where the vertical line crosses each visible
+    /// series; `None` disables it
+    pub point_marker_radius: Option<f32>,
+    pub(crate) mode: CrosshairMode,
+}
+
+impl CrosshairConfig {
+    /// Selects how the horizontal line follows the cursor, freely by default.
+    pub fn with_mode(mut self, mode: CrosshairMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> CrosshairMode {
+        self.mode
+    }
+}
+
+/// Hover emphasis: the series under the cursor is thickened and the others are faded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EmphasisConfig {
+    pub enabled: bool,
+    /// Line width multiplier applied to the emphasized series
+    pub width_scale: f32,
+    /// Opacity factor applied to the other series
+    pub dim_opacity: f32,
+    /// Max distance (pixels) between the cursor and a series for it to be hovered
+    pub hit_tolerance_px: f32,
+    /// Whether a click on a series selects it (and keeps it emphasized)
+    pub selectable: bool,
+}
+
+impl Default for EmphasisConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            width_scale: 2.0,
+            dim_opacity: 0.3,
+            hit_tolerance_px: 6.0,
+            selectable: true,
+        }
+    }
+}
+
+/// Render-time style adjustments for a single series.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeriesEmphasis {
+    pub width_scale: f32,
+    pub opacity: f32,
+}
+
+impl Default for SeriesEmphasis {
+    fn default() -> Self {
+        Self {
+            width_scale: 1.0,
+            opacity: 1.0,
+        }
+    }
+}
+
+/// Number of frames averaged by `RenderStats`.
+pub const RENDER_STATS_WINDOW: usize = 120;
+
+/// Render and paint timings of a chart, see `Chart::render_stats`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// Last paint duration of each pane (pane ID -> duration)
+    pub pane_paint: std::collections::HashMap<String, std::time::Duration>,
+    /// Sum of `pane_paint`
+    pub total_paint: std::time::Duration,
+    /// Duration of the last element tree build (`ChartView::render`)
+    pub last_render: std::time::Duration,
+    /// Average render duration over the last `frames` frames
+    pub avg_render: std::time::Duration,
+    /// Average total paint duration over the last `frames` frames
+    pub avg_total_paint: std::time::Duration,
+    /// Number of frames in the averages (at most `RENDER_STATS_WINDOW`)
+    pub frames: usize,
+}
+
+/// Rolling render/paint history recorded by the renderer.
+#[derive(Debug, Default)]
+pub struct RenderHistory {
+    pub last_render: std::time::Duration,
+    pub renders: std::collections::VecDeque<std::time::Duration>,
+    pub paints: std::collections::VecDeque<std::time::Duration>,
+}
+
+impl RenderHistory {
+    /// Records one frame: its render duration and the total paint duration of
+    /// the previous frame (paint happens after render).
+    pub fn record(&mut self, render: std::time::Duration, paint: std::time::Duration) {
+        self.last_render = render;
+        for (history, value) in [(&mut self.renders, render), (&mut self.paints, paint)] {
+            if history.len() == RENDER_STATS_WINDOW {
+                history.pop_front();
+            }
+            history.push_back(value);
+        }
+    }
+
+    fn average(history: &std::collections::VecDeque<std::time::Duration>) -> std::time::Duration {
+        if history.is_empty() {
+            return std::time::Duration::ZERO;
+        }
+        history.iter().sum::<std::time::Duration>() / history.len() as u32
+    }
+
+    pub fn avg_render(&self) -> std::time::Duration {
+        Self::average(&self.renders)
+    }
+
+    pub fn avg_paint(&self) -> std::time::Duration {
+        Self::average(&self.paints)
+    }
+}
+
+/// Panes showing the value readout while the mouse hovers a chart. The
+/// keyboard-driven crosshair always shows it in every pane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HoverReadout {
+    /// No readout on mouse hover
+    #[default]
+    Off,
+    /// Readout in the hovered pane only
+    Pane,
+    /// Readout in every pane at the hovered X, e.g. price, volume and RSI
+    /// values side by side in stacked trading panes
+    AllPanes,
+}
+
+/// Charts sharing a state that draw the crosshair.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrosshairScope {
+    /// Every chart, at the hovered X
+    #[default]
+    AllCharts,
+    /// Only the chart in `SharedPlotState::active_chart_id`, so hovering one
+    /// chart draws no crosshair on the others
+    ActiveChart,
+}
+
 /// Shared state between multiple charts (Crosshair, etc.).
 #[derive(Debug, Default)]
 pub struct SharedPlotState {
@@ -64,28 +286,122 @@ pub struct SharedPlotState {
     pub hover_x: Option<f64>,
     /// Global screen position
     pub mouse_pos: Option<gpui::Point<gpui::Pixels>>,
-    /// ID of the chart currently hovered
+    /// Entity ID of the `Chart` last hovered, or whose crosshair was last
+    /// moved with the keyboard
     pub active_chart_id: Option<gpui::EntityId>,
     pub is_dragging: bool,
     pub debug_mode: bool,
+    /// Re-render on every frame to keep the debug timings live. None follows
+    /// `debug_mode`; Some(false) shows the overlay with a single stable frame
+    /// (e.g. for snapshot tests). See `repaints_continuously`.
+    pub continuous_repaint: Option<bool>,
     pub crosshair_enabled: bool,
+    /// Value readout next to the crosshair (see `Chart::set_tooltip_visible`)
+    pub tooltip_enabled: bool,
+    /// Crosshair driven by the keyboard: shown without a mouse position
+    pub crosshair_pinned: bool,
+    /// Readout on mouse hover (see `Chart::set_hover_readout`)
+    pub hover_readout: HoverReadout,
+    /// Charts drawing the crosshair (see `Chart::set_crosshair_scope`)
+    pub crosshair_scope: CrosshairScope,
     pub theme: crate::theme::ChartTheme,
 
     pub box_zoom_start: Option<gpui::Point<gpui::Pixels>>,
     pub box_zoom_current: Option<gpui::Point<gpui::Pixels>>,
 
-    /// Optional gap index for X axis compression
+    /// Optional gap index for X axis compression. Set it through
+    /// `set_gap_index`, which stores an index without gaps as None.
     pub gap_index: Option<Arc<GapIndex>>,
 
+    /// Series under the cursor in the chart being painted, filled from
+    /// `Chart::hovered_series` before painting
+    pub hovered_series: Option<String>,
+    /// Series selected by a click in the chart being painted, filled from
+    /// `Chart::selected_series` before painting
+    pub selected_series: Option<String>,
+    pub emphasis: EmphasisConfig,
+    /// Per-series opacity (ID -> factor), filled from `Series::opacity` before painting
+    pub series_opacity: std::collections::HashMap<String, f32>,
+
     /// Time taken by paint for each pane (ID -> nanoseconds)
     pub pane_paint_times:
         std::sync::Arc<parking_lot::RwLock<std::collections::HashMap<String, u64>>>,
+    /// Points painted for each pane (ID -> (raw in view, drawn after decimation)).
+    /// Raw counts are only gathered in debug mode.
+    pub pane_point_counts:
+        std::sync::Arc<parking_lot::RwLock<std::collections::HashMap<String, (usize, usize)>>>,
+    /// Width of each pane's plot area at the last paint (ID -> pixels), from
+    /// which the series derive their point budget (see `Chart::visible_decimated`).
+    pub pane_widths: std::sync::Arc<parking_lot::RwLock<std::collections::HashMap<String, f32>>>,
 }
 
 impl SharedPlotState {
+    /// Compresses the X axis over the gaps of `gaps`. An index without gaps
+    /// is stored as None, so the faster gap-free paths are taken.
+    pub fn set_gap_index(&mut self, gaps: Option<Arc<GapIndex>>) {
+        self.gap_index = gaps.filter(|g| !g.is_empty());
+    }
+
+    /// Whether the renderer schedules a new frame after each one.
+    pub fn repaints_continuously(&self) -> bool {
+        self.continuous_repaint.unwrap_or(self.debug_mode)
+    }
+
+    /// Whether the chart with entity ID `chart_id` draws the crosshair.
+    pub fn crosshair_visible_on(&self, chart_id: gpui::EntityId) -> bool {
+        self.crosshair_enabled
+            && match self.crosshair_scope {
+                CrosshairScope::AllCharts => true,
+                CrosshairScope::ActiveChart => self.active_chart_id == Some(chart_id),
+            }
+    }
+
+    /// Whether a pane shows the crosshair value readout. `chart_hovered` is
+    /// set when the mouse is over a pane sharing this pane's X axis, and
+    /// `pane_hovered` when it is over this pane.
+    pub fn readout_visible(&self, chart_hovered: bool, pane_hovered: bool) -> bool {
+        if !self.crosshair_enabled || !self.tooltip_enabled {
+            return false;
+        }
+        self.crosshair_pinned
+            || match self.hover_readout {
+                HoverReadout::Off => false,
+                HoverReadout::Pane => pane_hovered,
+                HoverReadout::AllPanes => chart_hovered,
+            }
+    }
+
     pub fn total_paint_nanos(&self) -> u64 {
         self.pane_paint_times.read().values().sum()
     }
+
+    /// Returns the emphasis to apply to a series given the current hover
+    /// and selection state, combined with the series' own opacity.
+    pub fn series_emphasis(&self, series_id: &str) -> SeriesEmphasis {
+        let base_opacity = self.series_opacity.get(series_id).copied().unwrap_or(1.0);
+        let base = SeriesEmphasis {
+            width_scale: 1.0,
+            opacity: base_opacity,
+        };
+        if !self.emphasis.enabled {
+            return base;
+        }
+        let focused = [&self.selected_series, &self.hovered_series];
+        if focused.iter().all(|f| f.is_none()) {
+            return base;
+        }
+        if focused.iter().any(|f| f.as_deref() == Some(series_id)) {
+            SeriesEmphasis {
+                width_scale: self.emphasis.width_scale,
+                opacity: base_opacity,
+            }
+        } else {
+            SeriesEmphasis {
+                width_scale: 1.0,
+                opacity: base_opacity * self.emphasis.dim_opacity,
+            }
+        }
+    }
 }
 
 impl Clone for SharedPlotState {
@@ -96,12 +412,75 @@ impl Clone for SharedPlotState {
             active_chart_id: self.active_chart_id,
             is_dragging: self.is_dragging,
             debug_mode: self.debug_mode,
+            continuous_repaint: self.continuous_repaint,
             crosshair_enabled: self.crosshair_enabled,
+            tooltip_enabled: self.tooltip_enabled,
+            crosshair_pinned: self.crosshair_pinned,
+            hover_readout: self.hover_readout,
+            crosshair_scope: self.crosshair_scope,
             theme: self.theme.clone(),
             box_zoom_start: self.box_zoom_start,
             box_zoom_current: self.box_zoom_current,
             gap_index: self.gap_index.clone(),
+            hovered_series: self.hovered_series.clone(),
+            selected_series: self.selected_series.clone(),
+            emphasis: self.emphasis.clone(),
+            series_opacity: self.series_opacity.clone(),
             pane_paint_times: self.pane_paint_times.clone(),
+            pane_point_counts: self.pane_point_counts.clone(),
+            pane_widths: self.pane_widths.clone(),
+        }
+    }
+}
+
+/// Sides of a pane beyond which some series has data, for the "more data
+/// this way" indicators (see `Chart::set_show_overflow_indicators`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DataOverflow {
+    pub left: bool,
+    pub right: bool,
+    pub top: bool,
+    pub bottom: bool,
+}
+
+impl DataOverflow {
+    /// Compares each series with the domains of its axes, indexed by the
+    /// series' axis IDs: its full X extent (`get_min_max`) for the left and
+    /// right sides, its Y range within the visible X domain (`get_y_range`)
+    /// for the top and bottom.
+    pub fn of_series(
+        series: &[super::Series],
+        x_domains: &[(f64, f64)],
+        y_domains: &[(f64, f64)],
+    ) -> Self {
+        // Ignore differences from rounding, e.g. right after an auto-fit
+        let beyond = |v: f64, edge: f64, span: f64, above: bool| {
+            let tol = span.abs() * 1e-9;
+            if above { v > edge + tol } else { v < edge - tol }
+        };
+        let mut out = Self::default();
+        for s in series {
+            let plot = s.plot.read();
+            let Some((x_min, x_max, _, _)) = plot.get_min_max() else {
+                continue;
+            };
+            let Some(&(x_lo, x_hi)) = x_domains.get(s.x_axis_id.0) else {
+                continue;
+            };
+            out.left |= beyond(x_min, x_lo, x_hi - x_lo, false);
+            out.right |= beyond(x_max, x_hi, x_hi - x_lo, true);
+            let (Some(&(lo, hi)), Some((y_min, y_max))) =
+                (y_domains.get(s.y_axis_id.0), plot.get_y_range(x_lo, x_hi))
+            else {
+                continue;
+            };
+            out.bottom |= beyond(y_min, lo, hi - lo, false);
+            out.top |= beyond(y_max, hi, hi - lo, true);
         }
+        out
+    }
+
+    pub fn any(&self) -> bool {
+        self.left || self.right || self.top || self.bottom
     }
 }
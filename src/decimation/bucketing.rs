@@ -191,6 +191,18 @@ where
     buckets
 }
 
+/// Buckets aligned to fixed intervals of X, whatever the number of points in
+/// each: point `i` belongs to bucket `floor(x_i / interval)`, like a
+/// time resample (e.g. 1-hour candles with `interval = 3_600_000.0` ms).
+/// Empty intervals produce no bucket. `x` must be sorted.
+pub fn calculate_interval_buckets(x: &[f64], interval: f64) -> Vec<Range<usize>> {
+    calculate_logical_time_buckets_generic(x.len(), |i| x[i], None, interval, 0.0)
+}
+
+pub fn calculate_interval_buckets_data(data: &[PlotData], interval: f64) -> Vec<Range<usize>> {
+    calculate_logical_time_buckets_generic(data.len(), |i| get_data_x(&data[i]), None, interval, 0.0)
+}
+
 /// Helper to calculate stable buckets for any decimation algorithm.
 /// Returns (stable_bin_size, buckets).
 pub fn calculate_stable_buckets_generic<F>(
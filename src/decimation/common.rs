@@ -150,6 +150,77 @@ pub fn find_extrema_indices_f64(chunk: &[f64]) -> (usize, usize) {
     (min_idx, max_idx)
 }
 
+/// Color change of a bucket as it must survive decimation: the last
+/// `Persistent`/`Reset` (the color handed over to the next bucket) wins over
+/// `OneShot`s, and the first `OneShot` over later ones. Returned with the X
+/// of the point that carried it.
+pub fn bucket_color_op(chunk: &[PlotData]) -> Option<(ColorOp, f64)> {
+    let mut found: Option<(ColorOp, f64)> = None;
+    for p in chunk {
+        let op = p.color_op();
+        let replace = match op {
+            ColorOp::None => false,
+            _ if found.is_none() => true,
+            _ => op.is_persistent_change(),
+        };
+        if replace {
+            found = Some((op, get_data_x(p)));
+        }
+    }
+    found
+}
+
+/// Moves the color change of `chunk` (see [`bucket_color_op`]) onto the kept
+/// point nearest in X to where it happened. The ops the kept points carried
+/// themselves are cleared, so a change is never applied twice.
+pub fn carry_color_op(chunk: &[PlotData], kept: &mut [PlotData]) {
+    let Some((op, x)) = bucket_color_op(chunk) else {
+        return;
+    };
+    let mut nearest: Option<(usize, f64)> = None;
+    for (i, p) in kept.iter_mut().enumerate() {
+        if let PlotData::Point(pt) = p {
+            pt.color_op = ColorOp::None;
+            let d = (pt.x - x).abs();
+            if nearest.is_none_or(|(_, best)| d < best) {
+                nearest = Some((i, d));
+            }
+        }
+    }
+    if let Some((i, _)) = nearest {
+        if let PlotData::Point(pt) = &mut kept[i] {
+            pt.color_op = op;
+        }
+    }
+}
+
+/// Extremes-preserving counterpart of [`aggregate_chunk`]: appends the min and
+/// max points of a chunk of line points to `output`, in X order, so spikes
+/// survive downsampling. OHLCV chunks fall back to [`aggregate_chunk`], which
+/// already keeps the high and low.
+pub fn aggregate_chunk_extremes(chunk: &[PlotData], output: &mut Vec<PlotData>) {
+    if chunk.is_empty() {
+        return;
+    }
+    if let PlotData::Ohlcv(_) = chunk[0] {
+        output.extend(aggregate_chunk(chunk));
+        return;
+    }
+
+    let (min_idx, max_idx) = find_extrema_indices_generic(chunk, get_data_y);
+    let (first, second) = if min_idx <= max_idx {
+        (min_idx, max_idx)
+    } else {
+        (max_idx, min_idx)
+    };
+    let start = output.len();
+    output.push(chunk[first].clone());
+    if second != first {
+        output.push(chunk[second].clone());
+    }
+    carry_color_op(chunk, &mut output[start..]);
+}
+
 pub fn aggregate_chunk(chunk: &[PlotData]) -> Option<PlotData> {
     if chunk.is_empty() {
         return None;
@@ -170,7 +241,7 @@ pub fn aggregate_chunk(chunk: &[PlotData]) -> Option<PlotData> {
         Some(PlotData::Point(PlotPoint {
             x: sum_x / len,
             y: sum_y / len,
-            color_op: ColorOp::None,
+            color_op: bucket_color_op(chunk).map_or(ColorOp::None, |(op, _)| op),
         }))
     } else if let PlotData::Ohlcv(_) = chunk[0] {
         let mut open = 0.0;
@@ -0,0 +1,44 @@
+use crate::data_types::PlotData;
+use crate::gaps::GapIndex;
+use rayon::prelude::*;
+use smallvec::SmallVec;
+
+/// Decimates `data` with a user-provided per-bucket reducer.
+///
+/// Buckets are the same stable, gap-aware buckets used by the built-in
+/// decimators, sized so that a reducer returning its maximum of 4 points per
+/// bucket stays within `max_points`.
+pub fn decimate_custom_slice_into(
+    data: &[PlotData],
+    max_points: usize,
+    output: &mut Vec<PlotData>,
+    gaps: Option<&GapIndex>,
+    reference_logical_range: Option<f64>,
+    reducer: &(dyn Fn(&[PlotData]) -> SmallVec<[PlotData; 4]> + Sync),
+) {
+    if data.is_empty() {
+        return;
+    }
+
+    if data.len() <= max_points {
+        output.extend_from_slice(data);
+        return;
+    }
+
+    let (_stable_bin_size, buckets) = super::bucketing::calculate_stable_buckets_data(
+        data,
+        gaps,
+        max_points,
+        4,
+        reference_logical_range,
+    );
+
+    let chunks: Vec<SmallVec<[PlotData; 4]>> = buckets
+        .into_par_iter()
+        .map(|range| reducer(&data[range.start..range.end]))
+        .collect();
+
+    for pts in chunks {
+        output.extend(pts);
+    }
+}
@@ -316,6 +316,137 @@ pub fn decimate_ilttb_arrays_par_into(
     }));
 }
 
+/// LTTB variant that picks the same points whatever the pan offset.
+///
+/// Buckets sit on the absolute, gap-aware grid of the M4/MinMax decimators,
+/// with a bin size derived from `reference_logical_range` (pass the logical
+/// width of the view, not of the data, so that it does not change while
+/// panning). Each bucket keeps the point forming the largest triangle with
+/// the averages of its two neighbor buckets, so, unlike classic LTTB where
+/// every choice depends on the previous one, a bucket's choice only changes
+/// when its neighbors' points change. Edge buckets use their own average for
+/// the missing neighbor instead of the first/last point of the window. The
+/// first and last points are kept for line continuity.
+pub fn decimate_lttb_stable_arrays_into(
+    x: &[f64],
+    y: &[f64],
+    max_points: usize,
+    output: &mut Vec<PlotData>,
+    gaps: Option<&GapIndex>,
+    reference_logical_range: Option<f64>,
+) {
+    if x.is_empty() || y.is_empty() || x.len() != y.len() {
+        return;
+    }
+
+    if x.len() <= max_points || max_points < 3 {
+        output.extend(x.iter().zip(y.iter()).map(|(x_val, y_val)| {
+            PlotData::Point(PlotPoint {
+                x: *x_val,
+                y: *y_val,
+                color_op: ColorOp::None,
+            })
+        }));
+        return;
+    }
+
+    let (_, buckets) = super::bucketing::calculate_stable_buckets(
+        x,
+        gaps,
+        max_points.saturating_sub(2).max(1),
+        1,
+        reference_logical_range,
+    );
+    if buckets.is_empty() {
+        return;
+    }
+
+    // Averages skip NaN so that a missing sample does not poison its neighbors
+    let averages: Vec<(f64, f64)> = buckets
+        .par_iter()
+        .map(|range| {
+            let mut cursor = gaps.map(|g| g.cursor());
+            let (mut sum_x, mut sum_y, mut count) = (0.0, 0.0, 0.0);
+            for j in range.clone() {
+                if x[j].is_nan() || y[j].is_nan() {
+                    continue;
+                }
+                sum_x += match &mut cursor {
+                    Some(c) => c.to_logical(x[j] as i64) as f64,
+                    None => x[j],
+                };
+                sum_y += y[j];
+                count += 1.0;
+            }
+            (sum_x / count, sum_y / count)
+        })
+        .collect();
+
+    let sampled: Vec<PlotPoint> = buckets
+        .par_iter()
+        .enumerate()
+        .map(|(i, range)| {
+            let (a_x, a_y) = if i > 0 { averages[i - 1] } else { averages[i] };
+            let (c_x, c_y) = averages.get(i + 1).copied().unwrap_or(averages[i]);
+            let mut cursor = gaps.map(|g| g.cursor());
+            let best_idx = range.start
+                + find_max_area_index_gap_aware(
+                    &x[range.clone()],
+                    &y[range.clone()],
+                    a_x,
+                    a_y,
+                    c_x,
+                    c_y,
+                    &mut cursor,
+                );
+            PlotPoint {
+                x: x[best_idx],
+                y: y[best_idx],
+                color_op: ColorOp::None,
+            }
+        })
+        .collect();
+
+    output.push(PlotData::Point(PlotPoint {
+        x: x[0],
+        y: y[0],
+        color_op: ColorOp::None,
+    }));
+    output.extend(sampled.into_iter().map(PlotData::Point));
+    output.push(PlotData::Point(PlotPoint {
+        x: x[x.len() - 1],
+        y: y[y.len() - 1],
+        color_op: ColorOp::None,
+    }));
+}
+
+/// `decimate_lttb_stable_arrays_into` on `PlotData`; OHLCV data falls back to
+/// MinMax like `decimate_lttb_slice`.
+pub fn decimate_lttb_stable_slice_into(
+    data: &[PlotData],
+    max_points: usize,
+    output: &mut Vec<PlotData>,
+    gaps: Option<&GapIndex>,
+    reference_logical_range: Option<f64>,
+) {
+    if data.is_empty() {
+        return;
+    }
+    if let PlotData::Ohlcv(_) = data[0] {
+        super::min_max::decimate_min_max_slice_into(
+            data,
+            max_points,
+            output,
+            gaps,
+            reference_logical_range,
+        );
+        return;
+    }
+    let x: Vec<f64> = data.iter().map(super::common::get_data_x).collect();
+    let y: Vec<f64> = data.iter().map(super::common::get_data_y).collect();
+    decimate_lttb_stable_arrays_into(&x, &y, max_points, output, gaps, reference_logical_range);
+}
+
 fn find_max_area_index_gap_aware(
     x: &[f64],
     y: &[f64],
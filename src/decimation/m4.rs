@@ -1,7 +1,7 @@
 use crate::data_types::{ColorOp, PlotData, PlotPoint};
 use crate::gaps::GapIndex;
 use rayon::prelude::*;
-use super::common::{find_extrema_indices_generic, get_data_y};
+use super::common::{carry_color_op, find_extrema_indices_generic, get_data_y};
 
 /// Decimates parallel arrays (Structure of Arrays) using M4 and Rayon.
 pub fn decimate_m4_arrays_par(
@@ -199,6 +199,7 @@ fn aggregate_m4_bucket_generic(chunk: &[PlotData]) -> ([PlotData; 4], usize) {
             count += 1;
         }
     }
+    carry_color_op(chunk, &mut result[..count]);
     (result, count)
 }
 
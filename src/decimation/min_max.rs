@@ -1,7 +1,7 @@
 use crate::data_types::{ColorOp, PlotData, PlotPoint};
 use crate::gaps::GapIndex;
 use rayon::prelude::*;
-use super::common::{find_extrema_indices_generic, get_data_x, get_data_y};
+use super::common::{carry_color_op, find_extrema_indices_generic, get_data_x, get_data_y};
 
 pub fn decimate_min_max_arrays_par(
     x: &[f64],
@@ -189,7 +189,7 @@ fn aggregate_min_max_bucket_generic(chunk: &[PlotData]) -> ([PlotData; 2], usize
 
     let (min_idx, max_idx) = find_extrema_indices_generic(chunk, &get_data_y);
 
-    if min_idx == max_idx {
+    let (mut pts, n) = if min_idx == max_idx {
         ([chunk[min_idx].clone(), PlotData::Point(PlotPoint::default())], 1)
     } else {
         let p1 = &chunk[min_idx];
@@ -199,7 +199,9 @@ fn aggregate_min_max_bucket_generic(chunk: &[PlotData]) -> ([PlotData; 2], usize
         } else {
             ([p2.clone(), p1.clone()], 2)
         }
-    }
+    };
+    carry_color_op(chunk, &mut pts[..n]);
+    (pts, n)
 }
 
 pub fn decimate_min_max_generic<T, FX, FY, FC>(
@@ -258,3 +260,50 @@ where
 
     aggregated
 }
+
+/// Per-bucket `(x, min, max)` of `data`, for drawing the data extent as a
+/// band. Buckets are the stable ones MinMax decimation uses, at most
+/// `max_buckets` of them; `x` is the middle of each bucket. OHLCV bars
+/// contribute their low and high. NaN values are skipped, and buckets
+/// holding only NaN are dropped.
+pub fn min_max_envelope_slice_into(
+    data: &[PlotData],
+    max_buckets: usize,
+    output: &mut Vec<(f64, f64, f64)>,
+    gaps: Option<&GapIndex>,
+    reference_logical_range: Option<f64>,
+) {
+    if data.is_empty() {
+        return;
+    }
+
+    let extent = |p: &PlotData| match p {
+        PlotData::Point(pt) => (pt.y, pt.y),
+        PlotData::Ohlcv(o) => (o.low, o.high),
+    };
+
+    if data.len() <= max_buckets {
+        output.extend(data.iter().filter_map(|p| {
+            let (lo, hi) = extent(p);
+            (!lo.is_nan() && !hi.is_nan()).then(|| (get_data_x(p), lo, hi))
+        }));
+        return;
+    }
+
+    let (_stable_bin_size, buckets) =
+        super::bucketing::calculate_stable_buckets_data(data, gaps, max_buckets, 1, reference_logical_range);
+
+    let bands: Vec<Option<(f64, f64, f64)>> = buckets
+        .into_par_iter()
+        .map(|range| {
+            let chunk = &data[range.start..range.end];
+            let (first, last) = (chunk.first()?, chunk.last()?);
+            let (lo, hi) = chunk.iter().map(extent).fold((f64::NAN, f64::NAN), |(lo, hi), (l, h)| {
+                (lo.min(l), hi.max(h))
+            });
+            (!lo.is_nan() && !hi.is_nan()).then(|| ((get_data_x(first) + get_data_x(last)) / 2.0, lo, hi))
+        })
+        .collect();
+
+    output.extend(bands.into_iter().flatten());
+}
@@ -4,16 +4,18 @@ pub mod min_max;
 pub mod m4;
 pub mod lttb;
 pub mod ohlcv;
+pub mod custom;
 
 // Re-export public functions to maintain API compatibility
 pub use bucketing::{
     calculate_gap_aware_buckets, calculate_gap_aware_buckets_data,
+    calculate_interval_buckets, calculate_interval_buckets_data,
     calculate_stable_buckets, calculate_stable_buckets_data, calculate_stable_buckets_generic
 };
 pub use min_max::{
     decimate_min_max_arrays_par, decimate_min_max_arrays_par_into,
     decimate_min_max_slice, decimate_min_max_slice_into,
-    decimate_min_max_generic
+    decimate_min_max_generic, min_max_envelope_slice_into
 };
 pub use m4::{
     decimate_m4_arrays_par, decimate_m4_arrays_par_into,
@@ -23,10 +25,12 @@ pub use m4::{
 pub use lttb::{
     decimate_lttb_arrays, decimate_lttb_arrays_into,
     decimate_lttb_slice, decimate_lttb_generic,
-    decimate_ilttb_arrays_par, decimate_ilttb_arrays_par_into
+    decimate_ilttb_arrays_par, decimate_ilttb_arrays_par_into,
+    decimate_lttb_stable_arrays_into, decimate_lttb_stable_slice_into
 };
 pub use ohlcv::{
     decimate_ohlcv_arrays_par, decimate_ohlcv_arrays_par_into,
-    decimate_ohlcv_slice_into
+    decimate_ohlcv_slice_into, resample_slice, resample_slice_into
 };
-pub use common::aggregate_chunk;
+pub use custom::decimate_custom_slice_into;
+pub use common::{aggregate_chunk, aggregate_chunk_extremes};
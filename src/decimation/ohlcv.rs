@@ -102,6 +102,42 @@ pub fn decimate_ohlcv_arrays_par(
 // Re-export common dependencies needed by other modules or used here
 use super::common::{aggregate_chunk, get_data_x};
 
+/// Resamples `data` to fixed intervals of X (e.g. 1-minute or 1-hour
+/// candles), regardless of how many points fall in each. Every interval
+/// holding data is aggregated into one sample timed at the interval start:
+/// OHLCV samples merge into a candle spanning `interval`, points are averaged.
+pub fn resample_slice_into(data: &[PlotData], interval: f64, output: &mut Vec<PlotData>) {
+    if data.is_empty() || !(interval > 0.0 && interval.is_finite()) {
+        return;
+    }
+
+    let buckets = super::bucketing::calculate_interval_buckets_data(data, interval);
+    let chunks: Vec<PlotData> = buckets
+        .into_par_iter()
+        .filter_map(|range| {
+            let chunk = &data[range.start..range.end];
+            let mut res = aggregate_chunk(chunk)?;
+            let start = super::common::snap_to_grid(get_data_x(&chunk[0]), interval, None);
+            match &mut res {
+                PlotData::Ohlcv(o) => {
+                    o.time = start;
+                    o.span = interval;
+                }
+                PlotData::Point(p) => p.x = start,
+            }
+            Some(res)
+        })
+        .collect();
+
+    output.extend(chunks);
+}
+
+pub fn resample_slice(data: &[PlotData], interval: f64) -> Vec<PlotData> {
+    let mut output = Vec::new();
+    resample_slice_into(data, interval, &mut output);
+    output
+}
+
 pub fn decimate_ohlcv_slice_into(
     data: &[PlotData],
     max_points: usize,
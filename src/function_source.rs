@@ -0,0 +1,201 @@
+//! Data source sampling a function of X (analytic curves, fitted models).
+
+use crate::data_types::{step_sorted_x, ColorOp, PlotData, PlotDataSource, PlotPoint};
+use crate::gaps::GapIndex;
+
+/// Function plotted by a `FunctionDataSource`.
+pub type SampledFn = Box<dyn Fn(f64) -> f64 + Send + Sync>;
+
+/// X window `(x_min, x_max)` and the Y extent of the function over it.
+type WindowYRange = ((f64, f64), Option<(f64, f64)>);
+
+/// Series computed on the fly from `f(x)` over `x_bounds`, e.g. `y = sin(x)`
+/// or a fitted model overlaid on the data.
+///
+/// Nothing is stored: rendering evaluates `f` at `max_points` evenly spaced
+/// X over the visible window (evenly spaced in trading time with a gap
+/// index), so the curve stays smooth at any zoom. `iter_range`,
+/// `get_y_range` and the bounds use `samples` points over the window
+/// instead. Non-finite results (poles, `ln` of a negative) are skipped, and
+/// `add_data`/`set_data` are ignored.
+pub struct FunctionDataSource {
+    f: SampledFn,
+    x_bounds: (f64, f64),
+    samples: usize,
+    /// Y extent over `x_bounds`, sampled once since `f` does not change
+    y_bounds: Option<(f64, f64)>,
+    /// Last window passed to `get_y_range` and its Y extent, asked again on
+    /// every frame while the view does not move
+    y_range_cache: parking_lot::Mutex<Option<WindowYRange>>,
+}
+
+impl FunctionDataSource {
+    pub fn new(f: impl Fn(f64) -> f64 + Send + Sync + 'static, x_min: f64, x_max: f64) -> Self {
+        let mut source = Self {
+            f: Box::new(f),
+            x_bounds: (x_min.min(x_max), x_min.max(x_max)),
+            samples: 1000,
+            y_bounds: None,
+            y_range_cache: parking_lot::Mutex::new(None),
+        };
+        source.update_y_bounds();
+        source
+    }
+
+    /// Number of samples used outside rendering (`iter_range`, Y ranges,
+    /// bounds). Defaults to 1000.
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(2);
+        self.update_y_bounds();
+        *self.y_range_cache.get_mut() = None;
+        self
+    }
+
+    pub fn x_bounds(&self) -> (f64, f64) {
+        self.x_bounds
+    }
+
+    /// Evaluates the function, None where it is not finite.
+    pub fn eval(&self, x: f64) -> Option<f64> {
+        let y = (self.f)(x);
+        y.is_finite().then_some(y)
+    }
+
+    fn update_y_bounds(&mut self) {
+        let (x_min, x_max) = self.x_bounds;
+        self.y_bounds = y_extent(&self.sample(x_min, x_max, self.samples));
+    }
+
+    fn sample(&self, x_min: f64, x_max: f64, count: usize) -> Vec<PlotData> {
+        let mut out = Vec::with_capacity(count);
+        self.sample_into(x_min, x_max, count, None, &mut out);
+        out
+    }
+
+    /// Appends `count` points evenly spread over `[x_min, x_max]` clipped to
+    /// `x_bounds`, both ends included; a single point when the window is a
+    /// single X.
+    fn sample_into(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        count: usize,
+        gaps: Option<&GapIndex>,
+        output: &mut Vec<PlotData>,
+    ) {
+        let lo = x_min.max(self.x_bounds.0);
+        let hi = x_max.min(self.x_bounds.1);
+        // Spread the samples over trading time, so none is wasted in a gap
+        let (start, end) = match gaps {
+            Some(g) => (g.to_logical(lo as i64) as f64, g.to_logical(hi as i64) as f64),
+            None => (lo, hi),
+        };
+        let count = if lo < hi {
+            count.max(1)
+        } else if lo == hi {
+            count.min(1)
+        } else {
+            0
+        };
+        let step = if count > 1 { (end - start) / (count - 1) as f64 } else { 0.0 };
+        let mut cursor = gaps.map(|g| g.cursor());
+        output.extend((0..count).filter_map(|i| {
+            let t = if i + 1 == count { end } else { start + step * i as f64 };
+            let x = match cursor.as_mut() {
+                Some(c) => (c.to_real(t as i64) as f64).clamp(lo, hi),
+                None => t,
+            };
+            let y = self.eval(x)?;
+            Some(PlotData::Point(PlotPoint {
+                x,
+                y,
+                color_op: ColorOp::None,
+            }))
+        }));
+    }
+}
+
+fn y_extent(points: &[PlotData]) -> Option<(f64, f64)> {
+    points.iter().fold(None, |acc, p| {
+        let y = p.y();
+        Some(acc.map_or((y, y), |(lo, hi): (f64, f64)| (lo.min(y), hi.max(y))))
+    })
+}
+
+impl PlotDataSource for FunctionDataSource {
+    fn get_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let (y_min, y_max) = self.y_bounds?;
+        Some((self.x_bounds.0, self.x_bounds.1, y_min, y_max))
+    }
+
+    fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+        let mut cache = self.y_range_cache.lock();
+        if let Some((window, y_range)) = *cache {
+            if window == (x_min, x_max) {
+                return y_range;
+            }
+        }
+        let y_range = y_extent(&self.sample(x_min, x_max, self.samples));
+        *cache = Some(((x_min, x_max), y_range));
+        y_range
+    }
+
+    fn iter_range(&self, x_min: f64, x_max: f64) -> Box<dyn Iterator<Item = PlotData> + '_> {
+        Box::new(self.sample(x_min, x_max, self.samples).into_iter())
+    }
+
+    fn iter_aggregated(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        gaps: Option<&GapIndex>,
+    ) -> Box<dyn Iterator<Item = PlotData> + '_> {
+        let mut out = Vec::with_capacity(max_points);
+        self.sample_into(x_min, x_max, max_points, gaps, &mut out);
+        Box::new(out.into_iter())
+    }
+
+    fn get_aggregated_data(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        output: &mut Vec<PlotData>,
+        gaps: Option<&GapIndex>,
+    ) {
+        output.clear();
+        self.sample_into(x_min, x_max, max_points, gaps, output);
+    }
+
+    fn add_data(&mut self, _data: PlotData) {}
+
+    fn set_data(&mut self, _data: Vec<PlotData>) {}
+
+    fn suggested_x_spacing(&self) -> f64 {
+        let spacing = (self.x_bounds.1 - self.x_bounds.0) / (self.samples - 1) as f64;
+        if spacing > 0.0 {
+            spacing
+        } else {
+            1.0
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.samples
+    }
+
+    /// Steps along the `samples` points spread over `x_bounds`.
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        let (lo, hi) = self.x_bounds;
+        let last = self.samples - 1;
+        let x_at = |i: usize| {
+            if i == last {
+                hi
+            } else {
+                lo + (hi - lo) * i as f64 / last as f64
+            }
+        };
+        step_sorted_x(self.samples, x_at, x, steps)
+    }
+}
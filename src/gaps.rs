@@ -58,6 +58,11 @@ impl GapIndex {
         Self { segments }
     }
 
+    /// True when the index holds no gap, so real and logical time are equal.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
     /// Converts real time to logical (compressed) time.
     pub fn to_logical(&self, real_ms: i64) -> i64 {
         if self.segments.is_empty() {
@@ -204,6 +209,40 @@ impl GapIndex {
         result
     }
 
+    /// Splits [start, end] around the gaps like `split_range` and shares
+    /// `max_points` between the sub-ranges in proportion to their logical
+    /// span, as (start, end, points). Returns None when the range is not
+    /// split (no gap inside it), so it can be decimated in one go.
+    pub fn split_point_budget(
+        &self,
+        start: i64,
+        end: i64,
+        max_points: usize,
+    ) -> Option<Vec<(i64, i64, usize)>> {
+        let intervals = self.split_range(start, end);
+        if intervals.len() <= 1 {
+            return None;
+        }
+        let total_logical_span: f64 = intervals
+            .iter()
+            .map(|(s, e)| (self.to_logical(*e) - self.to_logical(*s)) as f64)
+            .sum();
+        if total_logical_span <= 0.0 {
+            return None;
+        }
+        Some(
+            intervals
+                .into_iter()
+                .map(|(s, e)| {
+                    let logical_span = (self.to_logical(e) - self.to_logical(s)) as f64;
+                    let points =
+                        ((logical_span / total_logical_span) * max_points as f64).round() as usize;
+                    (s, e, points)
+                })
+                .collect(),
+        )
+    }
+
     /// Returns a stateful cursor for optimized sequential access.
     pub fn cursor(&self) -> MappingCursor<'_> {
         MappingCursor::new(self)
@@ -334,7 +373,7 @@ impl GapIndexBuilder {
                         );
                     }
                     Err(e) => {
-                        eprintln!("Failed to parse timezone {}: {}", timezone, e);
+                        tracing::warn!("Failed to parse timezone {}: {}", timezone, e);
                     }
                 },
                 ExclusionRule::Numeric {
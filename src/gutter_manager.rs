@@ -8,12 +8,15 @@ pub struct Gutters {
     pub right: Pixels,
     pub top: Pixels,
     pub bottom: Pixels,
+    /// Padding included in the gutters, once between the chart border and the
+    /// axes and once between the axes and the panes.
+    pub padding: Edges<Pixels>,
 }
 
 pub struct GutterManager;
 
 impl GutterManager {
-    pub fn calculate(panes: &[PaneState], x_axes: &[AxisState]) -> Gutters {
+    pub fn calculate(panes: &[PaneState], x_axes: &[AxisState], padding: Edges<Pixels>) -> Gutters {
         let mut left = px(0.0);
         let mut right = px(0.0);
         let mut top = px(0.0);
@@ -47,10 +50,22 @@ impl GutterManager {
         }
 
         Gutters {
-            left,
-            right,
-            top,
-            bottom,
+            left: left + padding.left * 2.0,
+            right: right + padding.right * 2.0,
+            top: top + padding.top * 2.0,
+            bottom: bottom + padding.bottom * 2.0,
+            padding,
+        }
+    }
+
+    /// Gutters of a sparkline: no axes, only the padding.
+    pub fn padding_only(padding: Edges<Pixels>) -> Gutters {
+        Gutters {
+            left: padding.left * 2.0,
+            right: padding.right * 2.0,
+            top: padding.top * 2.0,
+            bottom: padding.bottom * 2.0,
+            padding,
         }
     }
 }
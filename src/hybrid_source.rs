@@ -1,4 +1,6 @@
-use crate::data_types::{AggregationMode, PlotData, PlotDataSource, StreamingDataSource};
+use crate::data_types::{
+    AggregationMode, DecimationPolicy, PlotData, PlotDataSource, StreamingDataSource,
+};
 use std::time::Instant;
 
 pub struct HybridDataSource {
@@ -64,10 +66,36 @@ impl PlotDataSource for HybridDataSource {
         self.mode
     }
 
+    fn set_aggregation_mode(&mut self, mode: AggregationMode) {
+        self.mode = mode;
+    }
+
+    fn decimation_threshold(&self) -> f64 {
+        self.historical.decimation_threshold()
+    }
+
+    fn set_decimation_threshold(&mut self, threshold: f64) {
+        self.historical.set_decimation_threshold(threshold);
+        self.realtime.set_decimation_threshold(threshold);
+    }
+
+    fn decimation_policy(&self) -> DecimationPolicy {
+        self.historical.decimation_policy()
+    }
+
+    fn set_decimation_policy(&mut self, policy: DecimationPolicy) {
+        self.historical.set_decimation_policy(policy);
+        self.realtime.set_decimation_policy(policy);
+    }
+
     fn len(&self) -> usize {
         self.historical.len() + self.realtime.len()
     }
 
+    fn version(&self) -> u64 {
+        self.historical.version() + self.realtime.version()
+    }
+
     fn suggested_x_spacing(&self) -> f64 {
         self.historical.suggested_x_spacing()
     }
@@ -111,6 +139,20 @@ impl PlotDataSource for HybridDataSource {
         max_points: usize,
         output: &mut Vec<PlotData>,
         gaps: Option<&crate::gaps::GapIndex>,
+    ) {
+        self.get_aggregated_data_at(x_min, x_max, max_points, output, gaps, &mut None);
+    }
+
+    /// The zoom step applies to the historical part, the real-time buffer
+    /// is small enough to aggregate without one.
+    fn get_aggregated_data_at(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        output: &mut Vec<PlotData>,
+        gaps: Option<&crate::gaps::GapIndex>,
+        zoom_step: &mut Option<usize>,
     ) {
         output.clear();
 
@@ -128,7 +170,7 @@ impl PlotDataSource for HybridDataSource {
 
         if hist_budget > 0 {
             self.historical
-                .get_aggregated_data(x_min, x_max, hist_budget, output, gaps);
+                .get_aggregated_data_at(x_min, x_max, hist_budget, output, gaps, zoom_step);
         }
 
         if rt_budget > 0 {
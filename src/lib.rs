@@ -22,24 +22,32 @@ pub mod decimation;
 pub mod chart;
 pub mod chart_view;
 pub mod data_types;
+pub mod function_source;
 pub mod gaps;
 pub mod hybrid_source;
 pub mod navigator_view;
+pub mod ohlcv_source;
 pub mod plot_types;
 #[cfg(feature = "polars")]
 pub mod polars_source;
 pub mod scales;
 pub mod simd;
+pub mod spread_source;
 pub mod theme;
 pub mod transform;
 pub mod view_controller;
 
 // Re-exports for convenience
-pub use chart::{AxisState, Chart, PaneState};
+pub use chart::{
+    AutoFitBounds, AxisState, Chart, ChartEvent, DerivedAxis, PaneState, ANNOTATIONS_SERIES_ID,
+};
 pub use chart_view::ChartView;
 pub use data_types::{AxisDomain, Ohlcv, PlotData, Series};
 pub use navigator_view::NavigatorView;
 pub use plot_types::*;
 pub use scales::ChartScale;
-pub use theme::ChartTheme;
+pub use theme::{ChartTheme, GridVisibility};
 pub use transform::PlotTransform;
+
+// Needed to build the output of custom aggregation reducers
+pub use smallvec;
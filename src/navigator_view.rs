@@ -1,7 +1,8 @@
 // NavigatorView implementation
 
-use crate::data_types::{AxisDomain, AxisRange, Series, SharedPlotState};
+use crate::data_types::{AxisDomain, AxisRange, PlotData, Series, SharedPlotState};
 use crate::rendering::paint_plot;
+use crate::scales::ChartScale;
 use crate::utils::PixelsExt;
 use crate::view_controller::ViewController;
 use gpui::prelude::*;
@@ -14,6 +15,10 @@ pub struct NavigatorConfig {
     pub lock_x: bool,
     pub lock_y: bool,
     pub clamp_to_minimap: bool,
+    /// Draws each series as a thin line decimated to this many points over
+    /// the whole X range, recomputed only when the data changes, instead of
+    /// rendering the series' own plots. None renders the plots.
+    pub preview_points: Option<usize>,
 }
 
 impl Default for NavigatorConfig {
@@ -22,6 +27,7 @@ impl Default for NavigatorConfig {
             lock_x: false,
             lock_y: true,
             clamp_to_minimap: true,
+            preview_points: None,
         }
     }
 }
@@ -34,6 +40,10 @@ pub struct NavigatorView {
     pub series: Vec<Series>,
     pub config: NavigatorConfig,
     full_domain: AxisDomain,
+    /// Decimated (x, y) lines of the series, see `NavigatorConfig::preview_points`
+    preview: std::sync::Arc<Vec<Vec<(f64, f64)>>>,
+    /// Point budget and data source versions the preview was computed from
+    preview_key: Option<(usize, Vec<Option<u64>>)>,
 
     bounds: Rc<RefCell<Bounds<Pixels>>>,
     is_dragging: bool,
@@ -56,6 +66,8 @@ impl NavigatorView {
             series,
             config: NavigatorConfig::default(),
             full_domain: AxisDomain::default(),
+            preview: Default::default(),
+            preview_key: None,
             bounds: Rc::new(RefCell::new(Bounds::default())),
             is_dragging: false,
         };
@@ -89,6 +101,44 @@ impl NavigatorView {
         }
     }
 
+    /// Preview lines of the series as last drawn, one per series backed by
+    /// a data source.
+    pub fn preview(&self) -> &[Vec<(f64, f64)>] {
+        &self.preview
+    }
+
+    /// Recomputes the preview (and the full domain) if the series' data or
+    /// the point budget changed since the last call.
+    fn refresh_preview(&mut self, cx: &App) {
+        let Some(max_points) = self.config.preview_points else {
+            return;
+        };
+        let key: Vec<Option<u64>> = self
+            .series
+            .iter()
+            .map(|s| s.plot.read().data_source().map(|d| d.version()))
+            .collect();
+        if self.preview_key.as_ref() == Some(&(max_points, key.clone())) {
+            return;
+        }
+        self.update_full_domain();
+        let gaps = self.shared_state.read(cx).gap_index.clone();
+        let (x_min, x_max) = (self.full_domain.x_min, self.full_domain.x_max);
+        let mut buffer: Vec<PlotData> = Vec::new();
+        let preview = self
+            .series
+            .iter()
+            .filter_map(|s| {
+                let plot = s.plot.read();
+                let source = plot.data_source()?;
+                source.get_aggregated_data(x_min, x_max, max_points, &mut buffer, gaps.as_deref());
+                Some(buffer.iter().map(|p| (p.x(), p.y())).collect())
+            })
+            .collect();
+        self.preview = std::sync::Arc::new(preview);
+        self.preview_key = Some((max_points, key));
+    }
+
     fn handle_click(
         &mut self,
         event: &MouseDownEvent,
@@ -120,32 +170,37 @@ impl NavigatorView {
         cx.notify();
     }
 
+    /// Scales mapping the full data domain onto the navigator `bounds`
+    /// (relative to their origin). The preview, the view window and drags
+    /// all convert through these, so they stay aligned whatever the scales.
+    fn scales(
+        full_domain: &AxisDomain,
+        bounds: Bounds<Pixels>,
+        gaps: Option<std::sync::Arc<crate::gaps::GapIndex>>,
+    ) -> (ChartScale, ChartScale) {
+        let x_scale = ChartScale::new_linear(
+            (full_domain.x_min, full_domain.x_max),
+            (0.0, bounds.size.width.as_f32()),
+        )
+        .with_gaps(gaps);
+        let y_scale = ChartScale::new_linear(
+            (full_domain.y_min, full_domain.y_max),
+            (bounds.size.height.as_f32(), 0.0),
+        );
+        (x_scale, y_scale)
+    }
+
     fn move_to_pos(&mut self, pos: Point<Pixels>, cx: &mut Context<Self>) {
         let bounds = *self.bounds.borrow();
         if bounds.is_empty() {
             return;
         }
 
-        let shared_state = self.shared_state.read(cx);
-        let gaps = shared_state.gap_index.as_deref();
-
-        let center_x = ViewController::map_pixels_to_value(
-            (pos.x - bounds.origin.x).as_f32(),
-            bounds.size.width.as_f32(),
-            self.full_domain.x_min,
-            self.full_domain.x_max,
-            false,
-            gaps,
-        );
-
-        let center_y = ViewController::map_pixels_to_value(
-            (pos.y - bounds.origin.y).as_f32(),
-            bounds.size.height.as_f32(),
-            self.full_domain.y_min,
-            self.full_domain.y_max,
-            true,
-            None,
-        );
+        let gaps = self.shared_state.read(cx).gap_index.clone();
+        let (x_scale, y_scale) = Self::scales(&self.full_domain, bounds, gaps);
+        let local = pos - bounds.origin;
+        let center_x = x_scale.invert(local.x.as_f32().clamp(0.0, bounds.size.width.as_f32()));
+        let center_y = y_scale.invert(local.y.as_f32().clamp(0.0, bounds.size.height.as_f32()));
 
         let lock_x = self.config.lock_x;
         let lock_y = self.config.lock_y;
@@ -177,6 +232,8 @@ impl NavigatorView {
 
 impl Render for NavigatorView {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.refresh_preview(cx);
+        let preview = self.config.preview_points.map(|_| self.preview.clone());
         let full_domain = self.full_domain.clone();
         let x_axis_val = self.x_axis.read(cx).clone();
         let y_axis_val = self.y_axis.read(cx).clone();
@@ -200,53 +257,49 @@ impl Render for NavigatorView {
                     move |bounds, (), window, cx| {
                         *bounds_rc.borrow_mut() = bounds;
                         let shared_state = shared_state_handle.read(cx).clone();
-                        paint_plot(
-                            window,
-                            bounds,
-                            &series,
-                            &[(full_domain.x_min, full_domain.x_max)],
-                            &[(full_domain.y_min, full_domain.y_max)],
-                            cx,
-                            &shared_state,
-                        );
-
-                        let (w, h) = (
-                            bounds.size.width.as_f32() as f64,
-                            bounds.size.height.as_f32() as f64,
-                        );
-
-                        let (left_pct, right_pct) = if let Some(g) = &shared_state.gap_index {
-                            let l_min_full = g.to_logical(full_domain.x_min as i64) as f64;
-                            let l_max_full = g.to_logical(full_domain.x_max as i64) as f64;
-                            let l_span_full = l_max_full - l_min_full;
-
-                            let l_min_view = g.to_logical(x_axis_val.min as i64) as f64;
-                            let l_max_view = g.to_logical(x_axis_val.max as i64) as f64;
-
-                            (
-                                (l_min_view - l_min_full) / l_span_full,
-                                (l_max_view - l_min_full) / l_span_full,
-                            )
-                        } else {
-                            (
-                                (x_axis_val.min - full_domain.x_min) / full_domain.width(),
-                                (x_axis_val.max - full_domain.x_min) / full_domain.width(),
-                            )
-                        };
+                        let (x_scale, y_scale) =
+                            Self::scales(&full_domain, bounds, shared_state.gap_index.clone());
+                        match &preview {
+                            Some(lines) => {
+                                for line in lines.iter().filter(|l| l.len() >= 2) {
+                                    let mut builder = PathBuilder::stroke(px(1.0));
+                                    for (i, &(x, y)) in line.iter().enumerate() {
+                                        let p = bounds.origin
+                                            + point(px(x_scale.map(x)), px(y_scale.map(y)));
+                                        if i == 0 {
+                                            builder.move_to(p);
+                                        } else {
+                                            builder.line_to(p);
+                                        }
+                                    }
+                                    if let Ok(path) = builder.build() {
+                                        window.paint_path(path, theme.accent.opacity(0.7));
+                                    }
+                                }
+                            }
+                            None => {
+                                paint_plot(
+                                    window,
+                                    bounds,
+                                    &series,
+                                    &[(full_domain.x_min, full_domain.x_max)],
+                                    &[(full_domain.y_min, full_domain.y_max)],
+                                    cx,
+                                    &shared_state,
+                                );
+                            }
+                        }
 
-                        let rect_left = (w * left_pct).clamp(0.0, w) as f32;
-                        let rect_right = (w * right_pct).clamp(0.0, w) as f32;
+                        let (w, h) = (bounds.size.width.as_f32(), bounds.size.height.as_f32());
+                        let rect_left = x_scale.map(x_axis_val.min).clamp(0.0, w);
+                        let rect_right = x_scale.map(x_axis_val.max).clamp(0.0, w);
 
                         let (rect_top, rect_bot) = if lock_y {
-                            (0.0, h as f32)
+                            (0.0, h)
                         } else {
-                            let top_pct =
-                                (full_domain.y_max - y_axis_val.max) / full_domain.height();
-                            let bot_pct =
-                                (full_domain.y_max - y_axis_val.min) / full_domain.height();
                             (
-                                (h * top_pct).clamp(0.0, h) as f32,
-                                (h * bot_pct).clamp(0.0, h) as f32,
+                                y_scale.map(y_axis_val.max).clamp(0.0, h),
+                                y_scale.map(y_axis_val.min).clamp(0.0, h),
                             )
                         };
 
@@ -0,0 +1,332 @@
+//! Column-oriented OHLCV data source, for candles kept as separate arrays.
+
+use crate::data_types::{step_sorted_x, DecimationPolicy, Ohlcv, PlotData, PlotDataSource};
+use crate::gaps::GapIndex;
+use std::cmp::Ordering;
+
+/// OHLCV series stored as one vector per field (struct of arrays), as
+/// returned by most market data APIs, so the columns are used without being
+/// zipped into `Ohlcv` values first.
+///
+/// Rows are kept sorted by time. A row is drawn as-is only when its time and
+/// four prices are finite; NaN prices are skipped by the bounds, Y ranges and
+/// aggregated candles, and a NaN volume reads as 0.
+pub struct OhlcvArraySource {
+    time: Vec<f64>,
+    open: Vec<f64>,
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    volume: Vec<f64>,
+    /// Candle width, derived from the time spacing unless set explicitly
+    span: Option<f64>,
+    /// Smallest positive time step, infinite with fewer than two candles
+    min_step: f64,
+    x_range: Option<(f64, f64)>,
+    y_range: Option<(f64, f64)>,
+    decimation_threshold: f64,
+    decimation_policy: DecimationPolicy,
+    version: u64,
+}
+
+impl OhlcvArraySource {
+    /// Builds the source from its columns. Returns None when the columns
+    /// differ in length (`volume`, when given, included). Rows are sorted by
+    /// time if needed.
+    pub fn new(
+        time: Vec<f64>,
+        open: Vec<f64>,
+        high: Vec<f64>,
+        low: Vec<f64>,
+        close: Vec<f64>,
+        volume: Option<Vec<f64>>,
+    ) -> Option<Self> {
+        let len = time.len();
+        let volume = volume.unwrap_or_else(|| vec![0.0; len]);
+        if [open.len(), high.len(), low.len(), close.len(), volume.len()]
+            .iter()
+            .any(|&l| l != len)
+        {
+            return None;
+        }
+        let mut source = Self {
+            time,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            span: None,
+            min_step: f64::INFINITY,
+            x_range: None,
+            y_range: None,
+            decimation_threshold: 1.0,
+            decimation_policy: DecimationPolicy::Auto,
+            version: 0,
+        };
+        source.sort_by_time();
+        source.rebuild_cache();
+        Some(source)
+    }
+
+    /// Fixed candle width in X units, instead of the smallest time step.
+    pub fn with_span(mut self, span: f64) -> Self {
+        self.span = (span.is_finite() && span > 0.0).then_some(span);
+        self
+    }
+
+    pub fn with_decimation_threshold(mut self, threshold: f64) -> Self {
+        self.set_decimation_threshold(threshold);
+        self
+    }
+
+    /// Candle at row `i`, None when its time or a price is not finite.
+    pub fn candle(&self, i: usize) -> Option<Ohlcv> {
+        let candle = Ohlcv {
+            time: *self.time.get(i)?,
+            span: self.span(),
+            open: self.open[i],
+            high: self.high[i],
+            low: self.low[i],
+            close: self.close[i],
+            volume: if self.volume[i].is_nan() { 0.0 } else { self.volume[i] },
+        };
+        [candle.time, candle.open, candle.high, candle.low, candle.close]
+            .iter()
+            .all(|v| v.is_finite())
+            .then_some(candle)
+    }
+
+    fn span(&self) -> f64 {
+        match self.span {
+            Some(span) => span,
+            None if self.min_step.is_finite() => self.min_step,
+            None => 1.0,
+        }
+    }
+
+    fn push_row(&mut self, o: &Ohlcv) {
+        self.time.push(o.time);
+        self.open.push(o.open);
+        self.high.push(o.high);
+        self.low.push(o.low);
+        self.close.push(o.close);
+        self.volume.push(o.volume);
+    }
+
+    fn sort_by_time(&mut self) {
+        if self.time.windows(2).all(|w| w[1].partial_cmp(&w[0]) != Some(Ordering::Less)) {
+            return;
+        }
+        let mut order: Vec<usize> = (0..self.time.len()).collect();
+        order.sort_by(|&a, &b| self.time[a].total_cmp(&self.time[b]));
+        for col in [
+            &mut self.time,
+            &mut self.open,
+            &mut self.high,
+            &mut self.low,
+            &mut self.close,
+            &mut self.volume,
+        ] {
+            *col = order.iter().map(|&i| col[i]).collect();
+        }
+    }
+
+    /// Recomputes the time step and the finite X and Y ranges.
+    fn rebuild_cache(&mut self) {
+        self.min_step = self
+            .time
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|d| d.is_finite() && *d > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let finite = |v: &&f64| v.is_finite();
+        let x_min = self.time.iter().filter(finite).fold(f64::INFINITY, |a, &b| a.min(b));
+        let x_max = self.time.iter().filter(finite).fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        self.x_range = (x_min <= x_max).then_some((x_min, x_max));
+        self.y_range = self.y_range_in(0, self.time.len());
+    }
+
+    /// Widens the cached ranges with the last row, appended in time order.
+    fn extend_cache(&mut self) {
+        let n = self.time.len();
+        let t = self.time[n - 1];
+        if n >= 2 {
+            let step = t - self.time[n - 2];
+            if step.is_finite() && step > 0.0 {
+                self.min_step = self.min_step.min(step);
+            }
+        }
+        if t.is_finite() {
+            self.x_range = Some(self.x_range.map_or((t, t), |(lo, hi)| (lo.min(t), hi.max(t))));
+        }
+        if let Some((lo, hi)) = self.y_range_in(n - 1, n) {
+            self.y_range = Some(self.y_range.map_or((lo, hi), |(a, b)| (a.min(lo), b.max(hi))));
+        }
+    }
+
+    /// Row indices of the candles starting within `[x_min, x_max]`.
+    fn range_indices(&self, x_min: f64, x_max: f64) -> (usize, usize) {
+        let lo = self.time.partition_point(|&t| t < x_min);
+        let hi = self.time.partition_point(|&t| t <= x_max);
+        (lo, hi.max(lo))
+    }
+
+    /// Lowest low and highest high of rows `lo..hi`, skipping non-finite values.
+    fn y_range_in(&self, lo: usize, hi: usize) -> Option<(f64, f64)> {
+        let finite = |v: &&f64| v.is_finite();
+        let y_min = self.low[lo..hi].iter().filter(finite).fold(f64::INFINITY, |a, &b| a.min(b));
+        let y_max =
+            self.high[lo..hi].iter().filter(finite).fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        (y_min <= y_max).then_some((y_min, y_max))
+    }
+}
+
+fn as_candle(data: PlotData) -> Ohlcv {
+    match data {
+        PlotData::Ohlcv(o) => o,
+        PlotData::Point(p) => Ohlcv {
+            time: p.x,
+            span: 0.0,
+            open: p.y,
+            high: p.y,
+            low: p.y,
+            close: p.y,
+            volume: 0.0,
+        },
+    }
+}
+
+impl PlotDataSource for OhlcvArraySource {
+    fn decimation_threshold(&self) -> f64 {
+        self.decimation_threshold
+    }
+
+    fn set_decimation_threshold(&mut self, threshold: f64) {
+        self.decimation_threshold = threshold.max(1.0);
+    }
+
+    fn decimation_policy(&self) -> DecimationPolicy {
+        self.decimation_policy
+    }
+
+    fn set_decimation_policy(&mut self, policy: DecimationPolicy) {
+        self.decimation_policy = policy;
+    }
+
+    fn get_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let (x_min, x_max) = self.x_range?;
+        let (y_min, y_max) = self.y_range?;
+        Some((x_min, x_max + self.span(), y_min, y_max))
+    }
+
+    fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+        let (lo, hi) = self.range_indices(x_min, x_max);
+        self.y_range_in(lo, hi)
+    }
+
+    fn iter_range(&self, x_min: f64, x_max: f64) -> Box<dyn Iterator<Item = PlotData> + '_> {
+        let (lo, hi) = self.range_indices(x_min, x_max);
+        let end = (hi + 1).min(self.time.len());
+        Box::new((lo.saturating_sub(1)..end).filter_map(|i| self.candle(i).map(PlotData::Ohlcv)))
+    }
+
+    fn iter_aggregated(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        gaps: Option<&GapIndex>,
+    ) -> Box<dyn Iterator<Item = PlotData> + '_> {
+        let mut buffer = Vec::with_capacity(max_points);
+        self.get_aggregated_data(x_min, x_max, max_points, &mut buffer, gaps);
+        Box::new(buffer.into_iter())
+    }
+
+    fn get_aggregated_data(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_points: usize,
+        output: &mut Vec<PlotData>,
+        gaps: Option<&GapIndex>,
+    ) {
+        output.clear();
+        let (lo, hi) = self.range_indices(x_min, x_max);
+        let max_points = match self.decimation_budget(hi - lo, max_points) {
+            Some(budget) => budget,
+            None => {
+                output.extend(self.iter_range(x_min, x_max));
+                return;
+            }
+        };
+        let start = lo.saturating_sub(1);
+        let end = (hi + 1).min(self.time.len());
+        crate::decimation::decimate_ohlcv_arrays_par_into(
+            &self.time[start..end],
+            &self.open[start..end],
+            &self.high[start..end],
+            &self.low[start..end],
+            &self.close[start..end],
+            max_points,
+            output,
+            gaps,
+            Some(x_max - x_min),
+        );
+    }
+
+    /// Appends a candle; a point is stored as a flat candle at its Y.
+    fn add_data(&mut self, data: PlotData) {
+        self.version += 1;
+        let candle = as_candle(data);
+        let in_order =
+            self.time.last().is_none_or(|t| candle.time.partial_cmp(t) != Some(Ordering::Less));
+        self.push_row(&candle);
+        if in_order {
+            self.extend_cache();
+        } else {
+            self.sort_by_time();
+            self.rebuild_cache();
+        }
+    }
+
+    fn set_data(&mut self, data: Vec<PlotData>) {
+        self.version += 1;
+        for col in [
+            &mut self.time,
+            &mut self.open,
+            &mut self.high,
+            &mut self.low,
+            &mut self.close,
+            &mut self.volume,
+        ] {
+            col.clear();
+        }
+        for p in data {
+            self.push_row(&as_candle(p));
+        }
+        self.sort_by_time();
+        self.rebuild_cache();
+    }
+
+    fn suggested_x_spacing(&self) -> f64 {
+        self.span()
+    }
+
+    fn len(&self) -> usize {
+        self.time.len()
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        step_sorted_x(self.time.len(), |i| self.time[i], x, steps)
+    }
+
+    fn count_in_range(&self, x_min: f64, x_max: f64) -> usize {
+        let (lo, hi) = self.range_indices(x_min, x_max);
+        hi - lo
+    }
+}
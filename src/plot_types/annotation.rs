@@ -1,39 +1,103 @@
 use super::PlotRenderer;
-use crate::data_types::Annotation;
+use crate::data_types::{Annotation, AnnotationLayer, LineStyle};
 use crate::transform::PlotTransform;
 use gpui::*;
 
 /// Annotation plot type (Layer of annotations)
 pub struct AnnotationPlot {
     pub annotations: Vec<Annotation>,
+    /// Drawn behind the labels of line annotations so they stay legible over
+    /// busy data; the theme's `annotation_label_background` when None
+    pub label_background: Option<Hsla>,
 }
 
 impl AnnotationPlot {
     pub fn new(annotations: Vec<Annotation>) -> Self {
-        Self { annotations }
+        Self {
+            annotations,
+            label_background: None,
+        }
     }
-}
 
-impl PlotRenderer for AnnotationPlot {
-    fn render(
+    pub fn with_label_background(mut self, color: Hsla) -> Self {
+        self.label_background = Some(color);
+        self
+    }
+
+    fn paint_line(
+        window: &mut Window,
+        from: Point<Pixels>,
+        to: Point<Pixels>,
+        width: f32,
+        style: LineStyle,
+        color: Hsla,
+    ) {
+        let mut builder = PathBuilder::stroke(px(width));
+        let dash: Vec<Pixels> = style.dash_pattern(width).into_iter().map(px).collect();
+        if !dash.is_empty() {
+            builder = builder.dash_array(&dash);
+        }
+        builder.move_to(from);
+        builder.line_to(to);
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color);
+        }
+    }
+
+    /// Paints a line label at `origin` (top-left) over `label_background`.
+    fn paint_label(
+        window: &mut Window,
+        cx: &mut App,
+        text: &str,
+        color: Hsla,
+        origin: Point<Pixels>,
+        label_background: Hsla,
+    ) {
+        let font_size = px(10.0);
+        let run = TextRun {
+            len: text.len(),
+            font: TextStyle::default().font(),
+            color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let line =
+            window
+                .text_system()
+                .shape_line(text.to_string().into(), font_size, &[run], None);
+        let pad = point(px(2.0), px(1.0));
+        let line_height = font_size * 1.2;
+        let background = Bounds::new(
+            origin - pad,
+            size(line.width + pad.x * 2.0, line_height + pad.y * 2.0),
+        );
+        window.paint_quad(gpui::fill(background, label_background).corner_radii(px(2.0)));
+        let _ = line.paint(origin, line_height, window, cx);
+    }
+
+    fn paint_layer(
         &self,
         window: &mut Window,
         transform: &PlotTransform,
-        _series_id: &str,
         cx: &mut App,
-        _state: &crate::data_types::SharedPlotState,
+        layer: AnnotationLayer,
+        theme: &crate::theme::ChartTheme,
     ) {
+        let label_background = self.label_background.unwrap_or(theme.annotation_label_background);
         let bounds = transform.bounds;
         let origin = bounds.origin;
         let size = bounds.size;
 
-        for annotation in &self.annotations {
+        for annotation in self.annotations.iter().filter(|a| a.layer() == layer) {
             match annotation {
                 Annotation::VLine {
                     x,
                     color,
                     width,
+                    style,
                     label,
+                    ..
                 } => {
                     let is_inside = transform
                         .x_scale
@@ -53,44 +117,18 @@ impl PlotRenderer for AnnotationPlot {
                     {
                         let p1 = Point::new(screen_x, origin.y);
                         let p2 = Point::new(screen_x, origin.y + size.height);
-
-                        let mut builder = PathBuilder::stroke(px(*width));
-                        builder.move_to(p1);
-                        builder.line_to(p2);
-
-                        if let Ok(path) = builder.build() {
-                            window.paint_path(path, *color);
-                        }
+                        Self::paint_line(window, p1, p2, *width, *style, *color);
 
                         if let Some(text) = label {
                             // Render label near the top
-                            let font_size = px(10.0);
-                            let run = TextRun {
-                                len: text.len(),
-                                font: TextStyle::default().font(),
-                                color: *color,
-                                background_color: None,
-                                underline: None,
-                                strikethrough: None,
-                            };
-                            if let Ok(lines) = window.text_system().shape_text(
-                                text.clone().into(),
-                                font_size,
-                                &[run],
-                                None,
-                                None,
-                            ) {
-                                for line in lines {
-                                    let _ = line.paint(
-                                        p1 + point(px(2.0), px(2.0)),
-                                        font_size,
-                                        TextAlign::Left,
-                                        None,
-                                        window,
-                                        cx,
-                                    );
-                                }
-                            }
+                            Self::paint_label(
+                                window,
+                                cx,
+                                text,
+                                *color,
+                                p1 + point(px(4.0), px(3.0)),
+                                label_background,
+                            );
                         }
                     }
                 }
@@ -98,7 +136,9 @@ impl PlotRenderer for AnnotationPlot {
                     y,
                     color,
                     width,
+                    style,
                     label,
+                    ..
                 } => {
                     let screen_y = transform.y_data_to_screen(*y);
 
@@ -107,43 +147,18 @@ impl PlotRenderer for AnnotationPlot {
                     {
                         let p1 = Point::new(origin.x, screen_y);
                         let p2 = Point::new(origin.x + size.width, screen_y);
-
-                        let mut builder = PathBuilder::stroke(px(*width));
-                        builder.move_to(p1);
-                        builder.line_to(p2);
-
-                        if let Ok(path) = builder.build() {
-                            window.paint_path(path, *color);
-                        }
+                        Self::paint_line(window, p1, p2, *width, *style, *color);
 
                         if let Some(text) = label {
-                            let font_size = px(10.0);
-                            let run = TextRun {
-                                len: text.len(),
-                                font: TextStyle::default().font(),
-                                color: *color,
-                                background_color: None,
-                                underline: None,
-                                strikethrough: None,
-                            };
-                            if let Ok(lines) = window.text_system().shape_text(
-                                text.clone().into(),
-                                font_size,
-                                &[run],
-                                None,
-                                None,
-                            ) {
-                                for line in lines {
-                                    let _ = line.paint(
-                                        p1 + point(px(2.0), px(-12.0)),
-                                        font_size,
-                                        TextAlign::Left,
-                                        None,
-                                        window,
-                                        cx,
-                                    );
-                                }
-                            }
+                            // Above the line
+                            Self::paint_label(
+                                window,
+                                cx,
+                                text,
+                                *color,
+                                p1 + point(px(4.0), px(-15.0)),
+                                label_background,
+                            );
                         }
                     }
                 }
@@ -154,6 +169,7 @@ impl PlotRenderer for AnnotationPlot {
                     y_max,
                     color,
                     fill,
+                    ..
                 } => {
                     let ranges = if let Some(gaps) = transform.x_scale.gap_index() {
                         gaps.split_range(*x_min as i64, *x_max as i64)
@@ -183,6 +199,7 @@ impl PlotRenderer for AnnotationPlot {
                     text,
                     color,
                     font_size,
+                    ..
                 } => {
                     let pos = transform.data_to_screen(Point::new(*x, *y));
                     let font_size_px = px(*font_size);
@@ -212,12 +229,64 @@ impl PlotRenderer for AnnotationPlot {
             }
         }
     }
+}
 
+impl PlotRenderer for AnnotationPlot {
+    fn render(
+        &self,
+        window: &mut Window,
+        transform: &PlotTransform,
+        _series_id: &str,
+        cx: &mut App,
+        state: &crate::data_types::SharedPlotState,
+    ) {
+        self.paint_layer(window, transform, cx, AnnotationLayer::Foreground, &state.theme);
+    }
+
+    fn render_background(
+        &self,
+        window: &mut Window,
+        transform: &PlotTransform,
+        _series_id: &str,
+        cx: &mut App,
+        state: &crate::data_types::SharedPlotState,
+    ) {
+        self.paint_layer(window, transform, cx, AnnotationLayer::Background, &state.theme);
+    }
+
+    /// Bounds of the annotations flagged `affects_autofit`. A dimension that no
+    /// such annotation constrains (e.g. X with only HLines) is NaN, which the
+    /// `f64::min`/`f64::max` folds used by auto-fit ignore.
     fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
-        None
+        let mut x = (f64::NAN, f64::NAN);
+        let mut y = (f64::NAN, f64::NAN);
+        let mut found = false;
+        for annotation in self.annotations.iter().filter(|a| a.affects_autofit()) {
+            if let Some((lo, hi)) = annotation.x_extent() {
+                x = (x.0.min(lo), x.1.max(hi));
+            }
+            if let Some((lo, hi)) = annotation.y_extent() {
+                y = (y.0.min(lo), y.1.max(hi));
+            }
+            found = true;
+        }
+        found.then_some((x.0, x.1, y.0, y.1))
     }
 
-    fn get_y_range(&self, _x_min: f64, _x_max: f64) -> Option<(f64, f64)> {
-        None
+    fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+        let mut range: Option<(f64, f64)> = None;
+        for annotation in self.annotations.iter().filter(|a| a.affects_autofit()) {
+            let Some((lo, hi)) = annotation.y_extent() else {
+                continue;
+            };
+            if annotation.x_extent().is_some_and(|(ax_lo, ax_hi)| ax_hi < x_min || ax_lo > x_max) {
+                continue;
+            }
+            range = Some(match range {
+                Some((r_lo, r_hi)) => (r_lo.min(lo), r_hi.max(hi)),
+                None => (lo, hi),
+            });
+        }
+        range
     }
 }
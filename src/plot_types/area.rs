@@ -1,15 +1,23 @@
 use super::PlotRenderer;
-use crate::data_types::{AreaPlotConfig, PlotData, PlotDataSource, PlotPoint, VecDataSource};
+use crate::data_types::{
+    AreaBaseline, AreaPlotConfig, PlotData, PlotDataSource, PlotPoint, VecDataSource,
+};
 use crate::transform::PlotTransform;
 use crate::utils::PixelsExt;
 use gpui::*;
 
-/// Area plot type
+/// Area plot type, filled between the line and `config.baseline`
 pub struct AreaPlot {
     pub source: Box<dyn PlotDataSource>,
     pub config: AreaPlotConfig,
+    /// Level filled to while `config` sets no baseline
     pub baseline: f64,
     buffer: parking_lot::Mutex<Vec<PlotData>>,
+    /// `ZoomAggregation` step of the last frame, see `get_aggregated_data_at`
+    zoom_step: parking_lot::Mutex<Option<usize>>,
+    /// Points of the fill painted by `render_background`, reused by `render`
+    /// in the same frame
+    background_points: parking_lot::Mutex<Option<Vec<Point<Pixels>>>>,
 }
 
 impl AreaPlot {
@@ -20,6 +28,8 @@ impl AreaPlot {
             config: AreaPlotConfig::default(),
             baseline: 0.0,
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
+            background_points: parking_lot::Mutex::new(None),
         }
     }
 
@@ -29,79 +39,209 @@ impl AreaPlot {
             config: AreaPlotConfig::default(),
             baseline: 0.0,
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
+            background_points: parking_lot::Mutex::new(None),
         }
     }
 
+    pub fn with_config(mut self, config: AreaPlotConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Fills down (or up) to a fixed value.
     pub fn with_baseline(mut self, baseline: f64) -> Self {
         self.baseline = baseline;
         self
     }
+
+    /// Baseline in data units.
+    pub fn baseline(&self) -> f64 {
+        let Some(baseline) = self.config.baseline else {
+            return self.baseline;
+        };
+        let series_min = match baseline {
+            AreaBaseline::SeriesMin => self.source.get_bounds().map(|b| b.2),
+            _ => None,
+        };
+        baseline.resolve(series_min)
+    }
+
+    /// Baseline the auto-fit includes: only one set explicitly, so the
+    /// default zero does not stretch the range.
+    fn fitted_baseline(&self) -> Option<f64> {
+        (self.config.baseline.is_some() || self.baseline != 0.0).then(|| self.baseline())
+    }
 }
 
-impl PlotRenderer for AreaPlot {
-    fn render(
+/// Inserts a vertex wherever the line crosses `baseline_y`, so the parts on
+/// each side of the baseline can be filled separately.
+fn split_at_baseline(points: &[Point<Pixels>], baseline_y: f32) -> Vec<Point<Pixels>> {
+    let mut out = Vec::with_capacity(points.len() * 2);
+    out.extend(points.first());
+    for w in points.windows(2) {
+        let (y0, y1) = (w[0].y.as_f32() - baseline_y, w[1].y.as_f32() - baseline_y);
+        if y0 * y1 < 0.0 {
+            let t = y0 / (y0 - y1);
+            let x = w[0].x.as_f32() + (w[1].x.as_f32() - w[0].x.as_f32()) * t;
+            out.push(Point::new(px(x), px(baseline_y)));
+        }
+        out.push(w[1]);
+    }
+    out
+}
+
+/// Closed polygon between the line (each Y passed through `clamp`) and the
+/// baseline.
+fn fill_path(
+    points: &[Point<Pixels>],
+    baseline_y: f32,
+    clamp: impl Fn(f32) -> f32,
+) -> Option<Path<Pixels>> {
+    let (first, last) = (points.first()?, points.last()?);
+    let mut builder = PathBuilder::fill();
+    builder.move_to(Point::new(first.x, px(baseline_y)));
+    for p in points {
+        builder.line_to(Point::new(p.x, px(clamp(p.y.as_f32()))));
+    }
+    builder.line_to(Point::new(last.x, px(baseline_y)));
+    builder.close();
+    builder.build().ok()
+}
+
+impl AreaPlot {
+    /// Screen positions of the visible points, decimated.
+    fn screen_points(
         &self,
-        window: &mut Window,
         transform: &PlotTransform,
-        _series_id: &str,
-        _cx: &mut App,
         state: &crate::data_types::SharedPlotState,
-    ) {
+    ) -> Vec<Point<Pixels>> {
         let (x_min, x_max) = transform.x_scale.domain();
-        let max_points = transform.bounds.size.width.as_f32() as usize * 2;
-        let baseline_y = transform.y_data_to_screen(self.baseline);
-
-        let mut fill_builder = PathBuilder::fill();
-        let mut line_builder = PathBuilder::stroke(px(self.config.line_width));
-
-        let mut first_pt: Option<Point<Pixels>> = None;
-        let mut last_pt: Option<Point<Pixels>> = None;
-
+        let max_points = self.max_points(transform.bounds.size.width.as_f32());
         let mut buffer = self.buffer.lock();
-        self.source.get_aggregated_data(
+        self.source.get_aggregated_data_at(
             x_min,
             x_max,
             max_points,
             &mut buffer,
             state.gap_index.as_deref(),
+            &mut self.zoom_step.lock(),
         );
+        buffer
+            .iter()
+            .filter_map(|data| match data {
+                PlotData::Point(p) => Some(transform.data_to_screen(Point::new(p.x, p.y))),
+                _ => None,
+            })
+            .collect()
+    }
 
-        for data in buffer.iter() {
-            if let PlotData::Point(point) = data {
-                let screen_point = transform.data_to_screen(Point::new(point.x, point.y));
-
-                if first_pt.is_none() {
-                    fill_builder.move_to(Point::new(screen_point.x, baseline_y));
-                    fill_builder.line_to(screen_point);
-                    line_builder.move_to(screen_point);
-                    first_pt = Some(screen_point);
-                } else {
-                    fill_builder.line_to(screen_point);
-                    line_builder.line_to(screen_point);
+    fn paint_fill(
+        &self,
+        window: &mut Window,
+        points: &[Point<Pixels>],
+        transform: &PlotTransform,
+        opacity: f32,
+    ) {
+        let baseline_y = transform.y_data_to_screen(self.baseline()).as_f32();
+        // Screen Y grows downwards: above the baseline means a smaller Y
+        match self.config.negative_fill_color {
+            Some(negative) => {
+                let points = split_at_baseline(points, baseline_y);
+                if let Some(path) = fill_path(&points, baseline_y, |y| y.min(baseline_y)) {
+                    window.paint_path(path, self.config.fill_color.opacity(opacity));
+                }
+                if let Some(path) = fill_path(&points, baseline_y, |y| y.max(baseline_y)) {
+                    window.paint_path(path, negative.opacity(opacity));
+                }
+            }
+            None => {
+                if let Some(path) = fill_path(points, baseline_y, |y| y) {
+                    window.paint_path(path, self.config.fill_color.opacity(opacity));
                 }
-                last_pt = Some(screen_point);
             }
         }
+    }
+}
 
-        if let (Some(first), Some(last)) = (first_pt, last_pt) {
-            fill_builder.line_to(Point::new(last.x, baseline_y));
-            fill_builder.line_to(Point::new(first.x, baseline_y));
-            fill_builder.close();
+impl PlotRenderer for AreaPlot {
+    /// Paints the fill when `config.fill_behind_series` is set.
+    fn render_background(
+        &self,
+        window: &mut Window,
+        transform: &PlotTransform,
+        series_id: &str,
+        _cx: &mut App,
+        state: &crate::data_types::SharedPlotState,
+    ) {
+        if !self.config.fill_behind_series {
+            return;
+        }
+        let points = self.screen_points(transform, state);
+        let opacity = state.series_emphasis(series_id).opacity;
+        self.paint_fill(window, &points, transform, opacity);
+        *self.background_points.lock() = Some(points);
+    }
 
-            if let Ok(path) = fill_builder.build() {
-                window.paint_path(path, self.config.fill_color);
-            }
-            if let Ok(path) = line_builder.build() {
-                window.paint_path(path, self.config.line_color);
-            }
+    fn render(
+        &self,
+        window: &mut Window,
+        transform: &PlotTransform,
+        series_id: &str,
+        _cx: &mut App,
+        state: &crate::data_types::SharedPlotState,
+    ) {
+        let emphasis = state.series_emphasis(series_id);
+        let points = match self.background_points.lock().take() {
+            Some(points) => points,
+            None => self.screen_points(transform, state),
+        };
+        if points.is_empty() {
+            return;
+        }
+        if !self.config.fill_behind_series {
+            self.paint_fill(window, &points, transform, emphasis.opacity);
+        }
+
+        let line_width = self.config.line_width * emphasis.width_scale;
+        let mut line_builder = PathBuilder::stroke(px(line_width));
+        line_builder.move_to(points[0]);
+        for &p in &points[1..] {
+            line_builder.line_to(p);
+        }
+        if let Ok(path) = line_builder.build() {
+            window.paint_path(path, self.config.line_color.opacity(emphasis.opacity));
         }
     }
 
+    /// Data bounds, stretched to include an explicit baseline.
     fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
-        self.source.get_bounds()
+        let (x_min, x_max, y_min, y_max) = self.source.get_bounds()?;
+        let Some(baseline) = self.fitted_baseline() else {
+            return Some((x_min, x_max, y_min, y_max));
+        };
+        Some((x_min, x_max, y_min.min(baseline), y_max.max(baseline)))
     }
 
+    /// Y range of the data in `[x_min, x_max]`, stretched to include an
+    /// explicit baseline.
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
-        self.source.get_y_range(x_min, x_max)
+        let (y_min, y_max) = self.source.get_y_range(x_min, x_max)?;
+        let Some(baseline) = self.fitted_baseline() else {
+            return Some((y_min, y_max));
+        };
+        Some((y_min.min(baseline), y_max.max(baseline)))
+    }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(self.buffer.lock().len())
+    }
+
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        Some(self.source.as_ref())
+    }
+
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        Some(self.source.as_mut())
     }
 }
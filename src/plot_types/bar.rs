@@ -10,6 +10,8 @@ pub struct BarPlot {
     pub config: BarPlotConfig,
     pub baseline: f64,
     buffer: parking_lot::Mutex<Vec<PlotData>>,
+    /// `ZoomAggregation` step of the last frame, see `get_aggregated_data_at`
+    zoom_step: parking_lot::Mutex<Option<usize>>,
 }
 
 impl BarPlot {
@@ -20,6 +22,7 @@ impl BarPlot {
             config: BarPlotConfig::default(),
             baseline: 0.0,
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
         }
     }
 
@@ -29,6 +32,7 @@ impl BarPlot {
             config: BarPlotConfig::default(),
             baseline: 0.0,
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
         }
     }
 }
@@ -38,23 +42,23 @@ impl PlotRenderer for BarPlot {
         &self,
         window: &mut Window,
         transform: &PlotTransform,
-        _series_id: &str,
+        series_id: &str,
         _cx: &mut App,
         state: &crate::data_types::SharedPlotState,
     ) {
+        let emphasis = state.series_emphasis(series_id);
         let (x_min, x_max) = transform.x_scale.domain();
 
-        // Limit aggregated data to ~2000 points to prevent performance issues
-        let screen_width = transform.bounds.size.width.as_f32() as usize;
-        let max_points = screen_width.clamp(1, 2000); // Cap at 2000 for safety
+        let max_points = self.max_points(transform.bounds.size.width.as_f32());
 
         let mut buffer = self.buffer.lock();
-        self.source.get_aggregated_data(
+        self.source.get_aggregated_data_at(
             x_min,
             x_max,
             max_points,
             &mut buffer,
             state.gap_index.as_deref(),
+            &mut self.zoom_step.lock(),
         );
 
         if buffer.is_empty() {
@@ -126,7 +130,7 @@ impl PlotRenderer for BarPlot {
                     Size::new(px(rect_w), rect_h),
                 );
 
-                window.paint_quad(fill(rect, self.config.color));
+                window.paint_quad(fill(rect, self.config.color.opacity(emphasis.opacity)));
             }
         }
     }
@@ -138,4 +142,21 @@ impl PlotRenderer for BarPlot {
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
         self.source.get_y_range(x_min, x_max)
     }
+
+    /// One bar per pixel, capped at 2000 to bound the number of quads.
+    fn max_points(&self, width_px: f32) -> usize {
+        (width_px as usize).clamp(1, 2000)
+    }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(self.buffer.lock().len())
+    }
+
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        Some(self.source.as_ref())
+    }
+
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        Some(self.source.as_mut())
+    }
 }
@@ -1,5 +1,6 @@
 use super::PlotRenderer;
-use crate::data_types::{CandlestickConfig, Ohlcv, PlotData, PlotDataSource, VecDataSource};
+use crate::data_types::{CandleStyle, CandlestickConfig, Ohlcv, PlotData, PlotDataSource, VecDataSource};
+use crate::ohlcv_source::OhlcvArraySource;
 use crate::transform::PlotTransform;
 use crate::utils::PixelsExt;
 use gpui::*;
@@ -9,6 +10,8 @@ pub struct CandlestickPlot {
     pub source: Box<dyn PlotDataSource>,
     pub config: CandlestickConfig,
     buffer: parking_lot::Mutex<Vec<PlotData>>,
+    /// `ZoomAggregation` step of the last frame, see `get_aggregated_data_at`
+    zoom_step: parking_lot::Mutex<Option<usize>>,
 }
 
 impl CandlestickPlot {
@@ -18,6 +21,7 @@ impl CandlestickPlot {
             source: Box::new(VecDataSource::new(plot_data)),
             config: CandlestickConfig::default(),
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
         }
     }
 
@@ -26,27 +30,83 @@ impl CandlestickPlot {
             source,
             config: CandlestickConfig::default(),
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
         }
     }
+
+    /// Candles from separate columns, e.g. as returned by a market data API.
+    /// Returns None when the columns differ in length (see `OhlcvArraySource`).
+    pub fn from_arrays(
+        time: Vec<f64>,
+        open: Vec<f64>,
+        high: Vec<f64>,
+        low: Vec<f64>,
+        close: Vec<f64>,
+        volume: Option<Vec<f64>>,
+    ) -> Option<Self> {
+        let source = OhlcvArraySource::new(time, open, high, low, close, volume)?;
+        Some(Self::with_source(Box::new(source)))
+    }
+
+    pub fn with_style(mut self, style: CandleStyle) -> Self {
+        self.config.style = style;
+        self
+    }
+
+    /// Start time of the newest candle.
+    fn newest_time(&self) -> Option<f64> {
+        let (_, x_max, _, _) = self.source.get_bounds()?;
+        let lookback = 2.0 * self.source.suggested_x_spacing();
+        self.source.iter_range(x_max - lookback, x_max).last().map(|p| p.x())
+    }
+
+    /// Marks the newest candle as still forming (see `CandlestickConfig::provisional_last`).
+    pub fn with_provisional_last(mut self, provisional: bool) -> Self {
+        self.config.provisional_last = provisional;
+        self
+    }
 }
 
 impl PlotRenderer for CandlestickPlot {
+    /// Y bounds span the lowest low and highest high, so fits include the wicks.
     fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
         self.source.get_bounds()
     }
 
+    /// Lowest low and highest high of the candles whose wick is in view. A
+    /// wick is drawn half a span after the candle's time, so the candle
+    /// starting just before `x_min` counts too.
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
-        self.source.get_y_range(x_min, x_max)
+        let half_span = self.source.suggested_x_spacing() / 2.0;
+        self.source.get_y_range(x_min - half_span, x_max)
+    }
+
+    /// One candle per pixel.
+    fn max_points(&self, width_px: f32) -> usize {
+        width_px as usize
+    }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(self.buffer.lock().len())
+    }
+
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        Some(self.source.as_ref())
+    }
+
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        Some(self.source.as_mut())
     }
 
     fn render(
         &self,
         window: &mut Window,
         transform: &PlotTransform,
-        _series_id: &str,
+        series_id: &str,
         _cx: &mut App,
         state: &crate::data_types::SharedPlotState,
     ) {
+        let emphasis = state.series_emphasis(series_id);
         let bounds = transform.bounds;
         let width_px = bounds.size.width.as_f32();
         if width_px <= 0.0 {
@@ -56,16 +116,16 @@ impl PlotRenderer for CandlestickPlot {
         let (x_min, x_max) = transform.x_scale.domain();
         let ms_per_px = (x_max - x_min) / width_px as f64;
 
-        // Request aggregated data matching screen resolution
-        let max_points = width_px as usize;
+        let max_points = self.max_points(width_px);
 
         let mut buffer = self.buffer.lock();
-        self.source.get_aggregated_data(
+        self.source.get_aggregated_data_at(
             x_min,
             x_max,
             max_points,
             &mut buffer,
             state.gap_index.as_deref(),
+            &mut self.zoom_step.lock(),
         );
 
         let count = buffer.len();
@@ -79,10 +139,17 @@ impl PlotRenderer for CandlestickPlot {
         let body_pct = theme.candle_body_width_pct;
         let wick_pct = theme.candle_wick_width_pct;
         let contour_thickness = theme.candle_contour_thickness_px;
+        let newest_time = if self.config.provisional_last {
+            self.newest_time()
+        } else {
+            None
+        }
+        .unwrap_or(f64::INFINITY);
 
         for data in buffer.iter() {
             if let PlotData::Ohlcv(candle) = data {
                 let is_up = candle.close >= candle.open;
+                let opacity = emphasis.opacity * self.config.candle_opacity(candle, newest_time);
                 let t_start_px = transform.x_data_to_screen(candle.time).as_f32();
 
                 // 1. Calculate base width
@@ -96,15 +163,16 @@ impl PlotRenderer for CandlestickPlot {
                 let width_px = theoretical_span_px.min(avg_px_per_point * 1.5).max(1.0);
                 let center_x = t_start_px + (theoretical_span_px / 2.0);
 
-                // 2. High density mode: just a vertical line if we have less than 2px to draw
-                if width_px < 2.0 {
+                // 2. High density mode: just the high-low line when bars get too narrow
+                if width_px < self.config.min_bar_width_px {
                     let y_h = transform.y_data_to_screen(candle.high).as_f32();
                     let y_l = transform.y_data_to_screen(candle.low).as_f32();
                     let color = if is_up {
                         theme.up_candle_contour_color
                     } else {
                         theme.down_candle_contour_color
-                    };
+                    }
+                    .opacity(opacity);
                     window.paint_quad(fill(
                         Bounds::new(
                             Point::new(px(center_x - 0.5), px(y_h)),
@@ -123,18 +191,50 @@ impl PlotRenderer for CandlestickPlot {
                 let y_l = transform.y_data_to_screen(candle.low).as_f32();
                 let y_o = transform.y_data_to_screen(candle.open).as_f32();
                 let y_c = transform.y_data_to_screen(candle.close).as_f32();
-                let (b_top, b_bot) = if is_up { (y_c, y_o) } else { (y_o, y_c) };
 
                 let body_color = if is_up {
                     theme.up_candle_body_color
                 } else {
                     theme.down_candle_body_color
-                };
+                }
+                .opacity(opacity);
                 let contour_color = if is_up {
                     theme.up_candle_contour_color
                 } else {
                     theme.down_candle_contour_color
-                };
+                }
+                .opacity(opacity);
+
+                if self.config.style == CandleStyle::OhlcBar {
+                    let tick = contour_thickness.max(1.0);
+                    let half = b_w / 2.0;
+                    // High-low line
+                    window.paint_quad(fill(
+                        Bounds::new(
+                            Point::new(px(center_x - tick / 2.0), px(y_h)),
+                            Size::new(px(tick), px((y_l - y_h).max(1.0))),
+                        ),
+                        contour_color,
+                    ));
+                    // Open tick (left) and close tick (right)
+                    window.paint_quad(fill(
+                        Bounds::new(
+                            Point::new(px(center_x - half), px(y_o - tick / 2.0)),
+                            Size::new(px(half), px(tick)),
+                        ),
+                        contour_color,
+                    ));
+                    window.paint_quad(fill(
+                        Bounds::new(
+                            Point::new(px(center_x), px(y_c - tick / 2.0)),
+                            Size::new(px(half), px(tick)),
+                        ),
+                        contour_color,
+                    ));
+                    continue;
+                }
+
+                let (b_top, b_bot) = self.config.body_span_px(y_o, y_c);
 
                 // Top Wick (High to Body Top)
                 if y_h < b_top {
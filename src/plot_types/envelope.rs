@@ -0,0 +1,162 @@
+//! Min/max envelope of the raw data
+
+use super::PlotRenderer;
+use crate::data_types::{AreaPlotConfig, PlotData, PlotDataSource, VecDataSource};
+use crate::decimation::min_max_envelope_slice_into;
+use crate::gaps::GapIndex;
+use crate::transform::PlotTransform;
+use crate::utils::PixelsExt;
+use gpui::*;
+
+/// Band filled between the minimum and maximum of the data in each bucket.
+///
+/// Unlike a decimated line, the band keeps the whole extent of the data at
+/// any zoom: a single spike still widens its bucket. Buckets are recomputed
+/// from the visible data on every render, so zooming in refines the band down
+/// to the raw line. `config.line_color` strokes the upper and lower edges
+/// when `config.line_width` is positive.
+pub struct EnvelopePlot {
+    pub source: Box<dyn PlotDataSource>,
+    pub config: AreaPlotConfig,
+    buffer: parking_lot::Mutex<Vec<PlotData>>,
+    bands: parking_lot::Mutex<Vec<(f64, f64, f64)>>,
+}
+
+impl EnvelopePlot {
+    pub fn new(data: Vec<PlotData>) -> Self {
+        Self::with_source(Box::new(VecDataSource::new(data)))
+    }
+
+    pub fn with_source(source: Box<dyn PlotDataSource>) -> Self {
+        Self {
+            source,
+            config: AreaPlotConfig {
+                line_width: 0.0,
+                ..Default::default()
+            },
+            buffer: parking_lot::Mutex::new(Vec::new()),
+            bands: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn with_config(mut self, config: AreaPlotConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Per-bucket `(x, min, max)` of the data in `[x_min, x_max]`, at most
+    /// `max_buckets` of them.
+    pub fn envelope(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_buckets: usize,
+        gaps: Option<&GapIndex>,
+    ) -> Vec<(f64, f64, f64)> {
+        let mut bands = Vec::new();
+        let mut buffer = self.buffer.lock();
+        self.compute(x_min, x_max, max_buckets, gaps, &mut buffer, &mut bands);
+        bands
+    }
+
+    fn compute(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        max_buckets: usize,
+        gaps: Option<&GapIndex>,
+        buffer: &mut Vec<PlotData>,
+        bands: &mut Vec<(f64, f64, f64)>,
+    ) {
+        buffer.clear();
+        bands.clear();
+        buffer.extend(self.source.iter_range(x_min, x_max));
+        min_max_envelope_slice_into(buffer, max_buckets, bands, gaps, None);
+    }
+}
+
+impl PlotRenderer for EnvelopePlot {
+    fn render(
+        &self,
+        window: &mut Window,
+        transform: &PlotTransform,
+        series_id: &str,
+        _cx: &mut App,
+        state: &crate::data_types::SharedPlotState,
+    ) {
+        let emphasis = state.series_emphasis(series_id);
+        let (x_min, x_max) = transform.x_scale.domain();
+        let gaps = state.gap_index.as_deref();
+        // One vertex per bucket and edge
+        let max_buckets = self.max_points(transform.bounds.size.width.as_f32()) / 2;
+
+        let mut buffer = self.buffer.lock();
+        let mut bands = self.bands.lock();
+        self.compute(x_min, x_max, max_buckets, gaps, &mut buffer, &mut bands);
+        if bands.is_empty() {
+            return;
+        }
+
+        if let Some(gaps) = gaps {
+            let mut cursor = gaps.cursor();
+            for band in bands.iter_mut() {
+                band.0 = cursor.to_logical(band.0 as i64) as f64;
+            }
+        }
+        let upper: Vec<Point<Pixels>> = bands
+            .iter()
+            .map(|&(x, _, hi)| transform.data_to_screen(Point::new(x, hi)))
+            .collect();
+        let lower: Vec<Point<Pixels>> = bands
+            .iter()
+            .map(|&(x, lo, _)| transform.data_to_screen(Point::new(x, lo)))
+            .collect();
+
+        let mut fill_builder = PathBuilder::fill();
+        fill_builder.move_to(upper[0]);
+        for p in &upper[1..] {
+            fill_builder.line_to(*p);
+        }
+        for p in lower.iter().rev() {
+            fill_builder.line_to(*p);
+        }
+        fill_builder.close();
+        if let Ok(path) = fill_builder.build() {
+            window.paint_path(path, self.config.fill_color.opacity(emphasis.opacity));
+        }
+
+        if self.config.line_width > 0.0 {
+            let mut line_builder =
+                PathBuilder::stroke(px(self.config.line_width * emphasis.width_scale));
+            for edge in [&upper, &lower] {
+                line_builder.move_to(edge[0]);
+                for p in &edge[1..] {
+                    line_builder.line_to(*p);
+                }
+            }
+            if let Ok(path) = line_builder.build() {
+                window.paint_path(path, self.config.line_color.opacity(emphasis.opacity));
+            }
+        }
+    }
+
+    fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
+        self.source.get_bounds()
+    }
+
+    fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+        self.source.get_y_range(x_min, x_max)
+    }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(self.bands.lock().len() * 2)
+    }
+
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        Some(self.source.as_ref())
+    }
+
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        Some(self.source.as_mut())
+    }
+}
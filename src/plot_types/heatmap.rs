@@ -1,7 +1,7 @@
 //! Heatmap plot implementation
 
 use super::PlotRenderer;
-use crate::data_types::HeatmapCell;
+use crate::data_types::{ColorMap, ColorScale, HeatmapCell};
 use crate::transform::PlotTransform;
 use gpui::*;
 
@@ -9,11 +9,73 @@ use gpui::*;
 #[derive(Clone)]
 pub struct HeatmapPlot {
     pub cells: Vec<HeatmapCell>,
+    /// Colors cells from their `value` instead of their fixed `color`
+    pub colormap: Option<ColorMap>,
+    pub color_scale: ColorScale,
+    /// Values mapped to the ends of the colormap; the range of the cell
+    /// values (positive ones for a log scale) when `None`.
+    pub value_range: Option<(f64, f64)>,
 }
 
 impl HeatmapPlot {
     pub fn new(cells: Vec<HeatmapCell>) -> Self {
-        Self { cells }
+        Self {
+            cells,
+            colormap: None,
+            color_scale: ColorScale::Linear,
+            value_range: None,
+        }
+    }
+
+    pub fn with_colormap(
+        mut self,
+        colormap: ColorMap,
+        scale: ColorScale,
+        range: Option<(f64, f64)>,
+    ) -> Self {
+        self.colormap = Some(colormap);
+        self.color_scale = scale;
+        self.value_range = range;
+        self
+    }
+
+    /// Range the colormap spans, before the scale's own clamping.
+    pub fn color_range(&self) -> Option<(f64, f64)> {
+        if self.value_range.is_some() {
+            return self.value_range;
+        }
+        let log = self.color_scale == ColorScale::Log;
+        self.cells
+            .iter()
+            .filter_map(|c| c.value)
+            .filter(|v| v.is_finite() && (!log || *v > 0.0))
+            .fold(None, |acc, v| {
+                Some(acc.map_or((v, v), |(lo, hi): (f64, f64)| (lo.min(v), hi.max(v))))
+            })
+    }
+
+    /// Color a cell is painted with.
+    pub fn cell_color(&self, cell: &HeatmapCell) -> Hsla {
+        self.color_in_range(cell, self.color_range())
+    }
+
+    fn color_in_range(&self, cell: &HeatmapCell, range: Option<(f64, f64)>) -> Hsla {
+        match (&self.colormap, cell.value, range) {
+            (Some(map), Some(v), Some(range)) => map.color_at(self.color_scale.normalize(v, range)),
+            _ => cell.color,
+        }
+    }
+
+    /// Ticks for a colorbar: values and their position along the colormap.
+    pub fn colorbar_ticks(&self, count: usize) -> Vec<(f64, f32)> {
+        let Some(range) = self.color_range() else {
+            return Vec::new();
+        };
+        self.color_scale
+            .ticks(range, count)
+            .into_iter()
+            .map(|v| (v, self.color_scale.normalize(v, range)))
+            .collect()
     }
 }
 
@@ -29,6 +91,8 @@ impl PlotRenderer for HeatmapPlot {
         let bounds = transform.bounds;
         let origin = bounds.origin;
         let size = bounds.size;
+        // Computed once rather than per cell
+        let range = self.colormap.as_ref().and_then(|_| self.color_range());
 
         for cell in &self.cells {
             // Determine cell bounds in screen coordinates
@@ -56,10 +120,14 @@ impl PlotRenderer for HeatmapPlot {
                 continue;
             }
 
-            window.paint_quad(gpui::fill(rect, cell.color));
+            window.paint_quad(gpui::fill(rect, self.color_in_range(cell, range)));
         }
     }
 
+    fn clear_data(&mut self) {
+        self.cells.clear();
+    }
+
     fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
         if self.cells.is_empty() {
             return None;
@@ -1,5 +1,5 @@
 use super::PlotRenderer;
-use crate::data_types::{LinePlotConfig, PlotData, PlotDataSource, PlotPoint, VecDataSource};
+use crate::data_types::{ColorMap, ColorOp, LineCap, LineJoin, LinePlotConfig, PlotData, PlotDataSource, PlotPoint, VecDataSource};
 use crate::simd::batch_transform_points;
 use crate::transform::PlotTransform;
 use crate::utils::PixelsExt;
@@ -10,7 +10,10 @@ pub struct LinePlot {
     pub source: Box<dyn PlotDataSource>,
     pub config: LinePlotConfig,
     buffer: parking_lot::Mutex<Vec<PlotData>>,
+    /// `ZoomAggregation` step of the last frame, see `get_aggregated_data_at`
+    zoom_step: parking_lot::Mutex<Option<usize>>,
     screen_buffer: parking_lot::Mutex<Vec<Point<Pixels>>>,
+    segment_colors_buffer: parking_lot::Mutex<Vec<Hsla>>,
 }
 
 impl LinePlot {
@@ -20,7 +23,9 @@ impl LinePlot {
             source: Box::new(VecDataSource::new(plot_data)),
             config: LinePlotConfig::default(),
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
             screen_buffer: parking_lot::Mutex::new(Vec::new()),
+            segment_colors_buffer: parking_lot::Mutex::new(Vec::new()),
         }
     }
 
@@ -29,7 +34,93 @@ impl LinePlot {
             source,
             config: LinePlotConfig::default(),
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
             screen_buffer: parking_lot::Mutex::new(Vec::new()),
+            segment_colors_buffer: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Rounds the corners and ends of the line, which reads smoother on dense
+    /// or jagged data.
+    pub fn with_round_joins(mut self) -> Self {
+        self.config.line_join = LineJoin::Round;
+        self.config.line_cap = LineCap::Round;
+        self
+    }
+
+    /// Stroke builder with the configured width, joins and caps.
+    fn stroke(&self, width: Pixels) -> PathBuilder {
+        PathBuilder::stroke(width).with_style(PathStyle::Stroke(
+            StrokeOptions::default()
+                .with_line_width(width.as_f32())
+                .with_line_join(self.config.line_join.to_lyon())
+                .with_line_cap(self.config.line_cap.to_lyon()),
+        ))
+    }
+
+    /// Colors each segment by its Y value through `colormap`. `range` gives the
+    /// Y values mapped to the ends of the map; `None` follows the visible Y range.
+    pub fn with_colormap(mut self, colormap: ColorMap, range: Option<(f64, f64)>) -> Self {
+        self.config.colormap = Some(colormap);
+        self.config.colormap_range = range;
+        self
+    }
+
+    /// Color of each segment (the one leading to point `i + 1`): the point's
+    /// `ColorOp` when one is in effect, else the colormap, else the series color.
+    fn segment_colors(
+        &self,
+        data: &[PlotData],
+        points: &[Point<Pixels>],
+        screen_range: Option<(f64, f64)>,
+        out: &mut Vec<Hsla>,
+    ) {
+        // Quantized so that runs of similar values share a path
+        const LEVELS: f32 = 64.0;
+        out.clear();
+        let mut current = data
+            .first()
+            .and_then(|p| self.source.persistent_color_before(p.x()));
+        if let Some(first) = data.first() {
+            first.color_op().apply(&mut current);
+        }
+        for (i, seg) in points.windows(2).enumerate() {
+            let op_color = data.get(i + 1).and_then(|p| p.color_op().apply(&mut current));
+            let color = match (op_color, &self.config.colormap, screen_range) {
+                (Some(c), _, _) => c,
+                (None, Some(map), Some(range)) => {
+                    let mid = (seg[0].y.as_f32() + seg[1].y.as_f32()) as f64 / 2.0;
+                    let t = LinePlotConfig::colormap_position(mid, range);
+                    map.color_at((t * LEVELS).round() / LEVELS)
+                }
+                _ => self.config.color,
+            };
+            out.push(color);
+        }
+    }
+
+    /// Strokes consecutive segments of the same color as one path, so long
+    /// series stay at a handful of paint calls.
+    fn paint_runs(
+        &self,
+        window: &mut Window,
+        points: &[Point<Pixels>],
+        colors: &[Hsla],
+        width: Pixels,
+        opacity: f32,
+    ) {
+        let mut i = 0;
+        while i < colors.len() {
+            let color = colors[i];
+            let mut builder = self.stroke(width);
+            builder.move_to(points[i]);
+            while i < colors.len() && colors[i] == color {
+                builder.line_to(points[i + 1]);
+                i += 1;
+            }
+            if let Ok(path) = builder.build() {
+                window.paint_path(path, color.opacity(opacity));
+            }
         }
     }
 }
@@ -39,20 +130,22 @@ impl PlotRenderer for LinePlot {
         &self,
         window: &mut Window,
         transform: &PlotTransform,
-        _series_id: &str,
+        series_id: &str,
         _cx: &mut App,
         state: &crate::data_types::SharedPlotState,
     ) {
+        let emphasis = state.series_emphasis(series_id);
         let (x_min, x_max) = transform.x_scale.domain();
-        let max_points = transform.bounds.size.width.as_f32() as usize * 2;
+        let max_points = self.max_points(transform.bounds.size.width.as_f32());
 
         let mut buffer = self.buffer.lock();
-        self.source.get_aggregated_data(
+        self.source.get_aggregated_data_at(
             x_min,
             x_max,
             max_points,
             &mut buffer,
             state.gap_index.as_deref(),
+            &mut self.zoom_step.lock(),
         );
 
         if let Some(gaps) = &state.gap_index {
@@ -68,8 +161,29 @@ impl PlotRenderer for LinePlot {
         let (xm, xc, ym, yc) = transform.get_scale_coefficients();
         batch_transform_points(&buffer, xm, xc, ym, yc, &mut screen_buffer);
 
+        let width = px(self.config.line_width * emphasis.width_scale);
+        let colored = self.config.colormap.is_some()
+            || buffer.iter().any(|p| p.color_op() != ColorOp::None)
+            || buffer
+                .first()
+                .is_some_and(|p| self.source.persistent_color_before(p.x()).is_some());
+        if colored {
+            let screen_range = self.config.colormap.as_ref().map(|_| {
+                let (lo, hi) = self
+                    .config
+                    .colormap_range
+                    .unwrap_or_else(|| transform.y_scale.domain());
+                let to_screen = |y: f64| y * ym as f64 + yc as f64;
+                (to_screen(lo), to_screen(hi))
+            });
+            let mut colors = self.segment_colors_buffer.lock();
+            self.segment_colors(&buffer, &screen_buffer, screen_range, &mut colors);
+            self.paint_runs(window, &screen_buffer, &colors, width, emphasis.opacity);
+            return;
+        }
+
         let mut first = true;
-        let mut builder = PathBuilder::stroke(px(self.config.line_width));
+        let mut builder = self.stroke(width);
 
         for pt in screen_buffer.iter() {
             if first {
@@ -81,7 +195,7 @@ impl PlotRenderer for LinePlot {
         }
 
         if let Ok(path) = builder.build() {
-            window.paint_path(path, self.config.color);
+            window.paint_path(path, self.config.color.opacity(emphasis.opacity));
         }
     }
 
@@ -92,4 +206,16 @@ impl PlotRenderer for LinePlot {
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
         self.source.get_y_range(x_min, x_max)
     }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(self.buffer.lock().len())
+    }
+
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        Some(self.source.as_ref())
+    }
+
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        Some(self.source.as_mut())
+    }
 }
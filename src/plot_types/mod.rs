@@ -4,19 +4,23 @@ pub mod annotation;
 pub mod area;
 pub mod bar;
 pub mod candlestick;
+pub mod envelope;
 pub mod heatmap;
 pub mod line;
+pub mod multi_line;
 pub mod step_line;
 
 pub use annotation::AnnotationPlot;
 pub use area::AreaPlot;
 pub use bar::BarPlot;
 pub use candlestick::CandlestickPlot;
+pub use envelope::EnvelopePlot;
 pub use heatmap::HeatmapPlot;
 pub use line::LinePlot;
+pub use multi_line::{LineChannel, MultiLinePlot};
 pub use step_line::StepLinePlot;
 
-use crate::data_types::SharedPlotState;
+use crate::data_types::{Ohlcv, PlotData, PlotDataSource, SharedPlotState};
 use crate::transform::PlotTransform;
 use gpui::*;
 
@@ -31,9 +35,120 @@ pub trait PlotRenderer: Send + Sync {
         state: &SharedPlotState,
     );
 
+    /// Paints the part of the plot that must sit behind every series of the pane.
+    /// Called for all series before any `render` call. Does nothing by default.
+    fn render_background(
+        &self,
+        _window: &mut Window,
+        _transform: &PlotTransform,
+        _series_id: &str,
+        _cx: &mut App,
+        _state: &SharedPlotState,
+    ) {
+    }
+
     /// Get min/max bounds for auto-fitting (x_min, x_max, y_min, y_max)
     fn get_min_max(&self) -> Option<(f64, f64, f64, f64)>;
 
     /// Get Y min/max range within a specific X range.
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)>;
+
+    /// Point budget `render` decimates the visible data to for a plot area
+    /// `width_px` wide.
+    fn max_points(&self, width_px: f32) -> usize {
+        width_px as usize * 2
+    }
+
+    /// Number of points drawn by the last `render` call, after decimation.
+    fn drawn_points(&self) -> Option<usize> {
+        None
+    }
+
+    /// Underlying data source, for plots backed by one.
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        None
+    }
+
+    /// Mutable access to the underlying data source.
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        None
+    }
+
+    /// Empties the plot's data (see `PlotDataSource::clear`).
+    fn clear_data(&mut self) {
+        if let Some(source) = self.data_source_mut() {
+            source.clear();
+        }
+    }
+
+    /// Y value of the series at `x`, linearly interpolated between the surrounding
+    /// points (close for OHLCV). `None` outside the data or without a data source.
+    fn value_at(&self, x: f64) -> Option<f64> {
+        interpolate_at(self.data_source()?, x)
+    }
+
+    /// Candle covering `x` (`time <= x < time + span`), for OHLCV series.
+    fn ohlcv_at(&self, x: f64) -> Option<Ohlcv> {
+        candle_at(self.data_source()?, x)
+    }
+}
+
+/// Points immediately before and after `x` (or the point at `x` as both).
+pub(crate) fn bracket_at(source: &dyn PlotDataSource, x: f64) -> Option<((f64, f64), (f64, f64))> {
+    let point_at = |px: f64| {
+        source
+            .iter_range(px, px)
+            .find(|p| p.x() == px)
+            .map(|p| (p.x(), p.y()))
+    };
+
+    // Sources that pad `iter_range` with one neighbor on each side answer in one lookup
+    let mut before = None;
+    let mut after = None;
+    for p in source.iter_range(x, x) {
+        let (px, py) = (p.x(), p.y());
+        if px == x {
+            return Some(((px, py), (px, py)));
+        } else if px < x {
+            before = Some((px, py));
+        } else if after.is_none() {
+            after = Some((px, py));
+        }
+    }
+
+    let before = match before {
+        Some(b) => b,
+        None => point_at(source.step_x(x, -1)?)?,
+    };
+    let after = match after {
+        Some(a) => a,
+        None => point_at(source.step_x(x, 1)?)?,
+    };
+    Some((before, after))
+}
+
+/// Linear interpolation of `source` at `x`.
+pub fn interpolate_at(source: &dyn PlotDataSource, x: f64) -> Option<f64> {
+    let ((x0, y0), (x1, y1)) = bracket_at(source, x)?;
+    if x1 <= x0 {
+        return Some(y0);
+    }
+    Some(y0 + (y1 - y0) * (x - x0) / (x1 - x0))
+}
+
+/// Candle of `source` covering `x`. Candles without a span are assumed to
+/// extend to the source's suggested X spacing.
+pub fn candle_at(source: &dyn PlotDataSource, x: f64) -> Option<Ohlcv> {
+    let spacing = source.suggested_x_spacing();
+    source
+        .iter_range(x - spacing, x)
+        .filter_map(|p| match p {
+            PlotData::Ohlcv(o) => Some(o),
+            PlotData::Point(_) => None,
+        })
+        .filter(|o| {
+            let span = if o.span > 0.0 { o.span } else { spacing };
+            o.time <= x && x < o.time + span
+        })
+        .last()
 }
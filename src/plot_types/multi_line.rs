@@ -0,0 +1,186 @@
+//! Batched rendering of many line series sharing the same axes
+
+use super::PlotRenderer;
+use crate::data_types::{LineCap, LineJoin, PlotData, PlotDataSource};
+use crate::gaps::GapIndex;
+use crate::simd::batch_transform_points;
+use crate::transform::PlotTransform;
+use crate::utils::PixelsExt;
+use gpui::*;
+
+/// One line of a `MultiLinePlot`.
+pub struct LineChannel {
+    pub source: Box<dyn PlotDataSource>,
+    pub color: Hsla,
+    pub line_width: f32,
+    /// `ZoomAggregation` step of the last frame, see `get_aggregated_data_at`
+    zoom_step: parking_lot::Mutex<Option<usize>>,
+}
+
+impl LineChannel {
+    pub fn new(source: Box<dyn PlotDataSource>, color: Hsla) -> Self {
+        Self {
+            source,
+            color,
+            line_width: 1.0,
+            zoom_step: parking_lot::Mutex::new(None),
+        }
+    }
+
+    pub fn with_line_width(mut self, width: f32) -> Self {
+        self.line_width = width;
+        self
+    }
+}
+
+/// Many lines drawn as a single series, e.g. dozens of sensor channels.
+///
+/// Every channel is decimated and transformed through the same reused
+/// buffers, and channels sharing a color and width are stroked as one path,
+/// so 50 channels of one color cost one `paint_path` instead of 50. The
+/// channels act as one series for the legend, hover emphasis and
+/// `value_at`, which this plot does not provide; use separate `LinePlot`s
+/// when channels must be told apart.
+pub struct MultiLinePlot {
+    pub channels: Vec<LineChannel>,
+    pub line_join: LineJoin,
+    pub line_cap: LineCap,
+    buffer: parking_lot::Mutex<Vec<PlotData>>,
+    screen_buffer: parking_lot::Mutex<Vec<Point<Pixels>>>,
+    drawn: parking_lot::Mutex<usize>,
+}
+
+impl MultiLinePlot {
+    pub fn new(channels: Vec<LineChannel>) -> Self {
+        Self {
+            channels,
+            line_join: LineJoin::default(),
+            line_cap: LineCap::default(),
+            buffer: parking_lot::Mutex::new(Vec::new()),
+            screen_buffer: parking_lot::Mutex::new(Vec::new()),
+            drawn: parking_lot::Mutex::new(0),
+        }
+    }
+
+    pub fn push(&mut self, channel: LineChannel) {
+        self.channels.push(channel);
+    }
+
+    /// Decimates and projects every channel, then builds one path per
+    /// distinct (color, width), in order of first appearance. `width_scale`
+    /// multiplies every line width (hover emphasis).
+    pub fn build_paths(
+        &self,
+        transform: &PlotTransform,
+        gaps: Option<&GapIndex>,
+        width_scale: f32,
+    ) -> Vec<(Hsla, Path<Pixels>)> {
+        let (x_min, x_max) = transform.x_scale.domain();
+        let max_points = self.max_points(transform.bounds.size.width.as_f32());
+        let (xm, xc, ym, yc) = transform.get_scale_coefficients();
+
+        let mut buffer = self.buffer.lock();
+        let mut screen_buffer = self.screen_buffer.lock();
+        let mut groups: Vec<(Hsla, f32, PathBuilder)> = Vec::new();
+        let mut drawn = 0;
+
+        for channel in &self.channels {
+            let mut zoom_step = channel.zoom_step.lock();
+            channel.source.get_aggregated_data_at(
+                x_min,
+                x_max,
+                max_points,
+                &mut buffer,
+                gaps,
+                &mut zoom_step,
+            );
+            if let Some(gaps) = gaps {
+                let mut cursor = gaps.cursor();
+                for data in buffer.iter_mut() {
+                    if let PlotData::Point(pt) = data {
+                        pt.x = cursor.to_logical(pt.x as i64) as f64;
+                    }
+                }
+            }
+            batch_transform_points(&buffer, xm, xc, ym, yc, &mut screen_buffer);
+            drawn += buffer.len();
+
+            let Some((first, rest)) = screen_buffer.split_first() else {
+                continue;
+            };
+            let width = channel.line_width * width_scale;
+            let idx = match groups
+                .iter()
+                .position(|(c, w, _)| *c == channel.color && *w == width)
+            {
+                Some(idx) => idx,
+                None => {
+                    groups.push((channel.color, width, self.stroke(px(width))));
+                    groups.len() - 1
+                }
+            };
+            let builder = &mut groups[idx].2;
+            builder.move_to(*first);
+            for pt in rest {
+                builder.line_to(*pt);
+            }
+        }
+
+        *self.drawn.lock() = drawn;
+        groups
+            .into_iter()
+            .filter_map(|(color, _, builder)| Some((color, builder.build().ok()?)))
+            .collect()
+    }
+
+    /// Stroke builder with the configured joins and caps.
+    fn stroke(&self, width: Pixels) -> PathBuilder {
+        PathBuilder::stroke(width).with_style(PathStyle::Stroke(
+            StrokeOptions::default()
+                .with_line_width(width.as_f32())
+                .with_line_join(self.line_join.to_lyon())
+                .with_line_cap(self.line_cap.to_lyon()),
+        ))
+    }
+}
+
+impl PlotRenderer for MultiLinePlot {
+    fn render(
+        &self,
+        window: &mut Window,
+        transform: &PlotTransform,
+        series_id: &str,
+        _cx: &mut App,
+        state: &crate::data_types::SharedPlotState,
+    ) {
+        let emphasis = state.series_emphasis(series_id);
+        let paths = self.build_paths(transform, state.gap_index.as_deref(), emphasis.width_scale);
+        for (color, path) in paths {
+            window.paint_path(path, color.opacity(emphasis.opacity));
+        }
+    }
+
+    fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
+        self.channels
+            .iter()
+            .filter_map(|c| c.source.get_bounds())
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1), a.2.min(b.2), a.3.max(b.3)))
+    }
+
+    fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+        self.channels
+            .iter()
+            .filter_map(|c| c.source.get_y_range(x_min, x_max))
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+    }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(*self.drawn.lock())
+    }
+
+    fn clear_data(&mut self) {
+        for channel in &mut self.channels {
+            channel.source.clear();
+        }
+    }
+}
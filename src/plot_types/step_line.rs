@@ -11,6 +11,8 @@ pub struct StepLinePlot {
     pub source: Box<dyn PlotDataSource>,
     pub config: StepLinePlotConfig,
     buffer: parking_lot::Mutex<Vec<PlotData>>,
+    /// `ZoomAggregation` step of the last frame, see `get_aggregated_data_at`
+    zoom_step: parking_lot::Mutex<Option<usize>>,
 }
 
 impl StepLinePlot {
@@ -20,6 +22,7 @@ impl StepLinePlot {
             source: Box::new(VecDataSource::new(plot_data)),
             config: StepLinePlotConfig::default(),
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
         }
     }
 
@@ -28,6 +31,7 @@ impl StepLinePlot {
             source,
             config: StepLinePlotConfig::default(),
             buffer: parking_lot::Mutex::new(Vec::new()),
+            zoom_step: parking_lot::Mutex::new(None),
         }
     }
 }
@@ -37,23 +41,25 @@ impl PlotRenderer for StepLinePlot {
         &self,
         window: &mut Window,
         transform: &PlotTransform,
-        _series_id: &str,
+        series_id: &str,
         _cx: &mut App,
         state: &crate::data_types::SharedPlotState,
     ) {
+        let emphasis = state.series_emphasis(series_id);
         let (x_min, x_max) = transform.x_scale.domain();
-        let max_points = transform.bounds.size.width.as_f32() as usize * 2;
+        let max_points = self.max_points(transform.bounds.size.width.as_f32());
 
         let mut buffer = self.buffer.lock();
-        self.source.get_aggregated_data(
+        self.source.get_aggregated_data_at(
             x_min,
             x_max,
             max_points,
             &mut buffer,
             state.gap_index.as_deref(),
+            &mut self.zoom_step.lock(),
         );
 
-        let mut builder = PathBuilder::stroke(px(self.config.line_width));
+        let mut builder = PathBuilder::stroke(px(self.config.line_width * emphasis.width_scale));
         let mut prev_pt: Option<Point<Pixels>> = None;
 
         for data in buffer.iter() {
@@ -85,7 +91,7 @@ impl PlotRenderer for StepLinePlot {
         }
 
         if let Ok(path) = builder.build() {
-            window.paint_path(path, self.config.color);
+            window.paint_path(path, self.config.color.opacity(emphasis.opacity));
         }
     }
 
@@ -96,4 +102,35 @@ impl PlotRenderer for StepLinePlot {
     fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
         self.source.get_y_range(x_min, x_max)
     }
+
+    fn drawn_points(&self) -> Option<usize> {
+        Some(self.buffer.lock().len())
+    }
+
+    fn data_source(&self) -> Option<&dyn PlotDataSource> {
+        Some(self.source.as_ref())
+    }
+
+    fn data_source_mut(&mut self) -> Option<&mut dyn PlotDataSource> {
+        Some(self.source.as_mut())
+    }
+
+    /// Value held by the step at `x`, matching the drawn geometry.
+    fn value_at(&self, x: f64) -> Option<f64> {
+        let ((x0, y0), (x1, y1)) = super::bracket_at(self.source.as_ref(), x)?;
+        if x == x0 {
+            return Some(y0);
+        }
+        Some(match self.config.mode {
+            StepMode::Post => y0,
+            StepMode::Pre => y1,
+            StepMode::Mid => {
+                if x < (x0 + x1) / 2.0 {
+                    y0
+                } else {
+                    y1
+                }
+            }
+        })
+    }
 }
@@ -1,19 +1,38 @@
 #![cfg(feature = "polars")]
 
-use crate::data_types::{ColorOp, Ohlcv, PlotData, PlotDataSource, PlotPoint};
+use crate::data_types::{
+    step_sorted_x, ColorOp, DecimationPolicy, Ohlcv, PlotData, PlotDataSource, PlotPoint,
+};
 use polars::prelude::*;
 use polars_ops::prelude::{search_sorted, SearchSortedSide};
 
+/// Min and max of a column, skipping nulls, NaN and infinities.
+fn finite_min_max(s: &Series) -> Option<(f64, f64)> {
+    let s = s.cast(&DataType::Float64).ok()?;
+    s.f64()
+        .ok()?
+        .into_iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .fold(None, |acc, v| {
+            Some(acc.map_or((v, v), |(lo, hi): (f64, f64)| (lo.min(v), hi.max(v))))
+        })
+}
+
 pub struct PolarsDataSource {
     df: DataFrame,
     x_col: String,
     y_col: String,
     mode: crate::data_types::AggregationMode,
+    custom_aggregator: Option<crate::data_types::CustomAggregator>,
+    decimation_threshold: f64,
+    decimation_policy: DecimationPolicy,
     // Optional columns for OHLCV
     open_col: Option<String>,
     high_col: Option<String>,
     low_col: Option<String>,
     close_col: Option<String>,
+    version: u64,
 }
 
 impl PolarsDataSource {
@@ -25,10 +44,14 @@ impl PolarsDataSource {
             x_col: x_col.to_string(),
             y_col: y_col.to_string(),
             mode: crate::data_types::AggregationMode::M4,
+            custom_aggregator: None,
+            decimation_threshold: 1.0,
+            decimation_policy: DecimationPolicy::Auto,
             open_col: None,
             high_col: None,
             low_col: None,
             close_col: None,
+            version: 0,
         }
     }
 
@@ -37,6 +60,22 @@ impl PolarsDataSource {
         self
     }
 
+    pub fn with_decimation_threshold(mut self, threshold: f64) -> Self {
+        self.set_decimation_threshold(threshold);
+        self
+    }
+
+    /// Decimates with `reducer`, called once per gap-aware bucket, and switches
+    /// the source to `AggregationMode::Custom`.
+    pub fn with_custom_aggregation(
+        mut self,
+        reducer: impl Fn(&[PlotData]) -> smallvec::SmallVec<[PlotData; 4]> + Send + Sync + 'static,
+    ) -> Self {
+        self.custom_aggregator = Some(Box::new(reducer));
+        self.mode = crate::data_types::AggregationMode::Custom;
+        self
+    }
+
     pub fn with_ohlcv(mut self, open: &str, high: &str, low: &str, close: &str) -> Self {
         self.open_col = Some(open.to_string());
         self.high_col = Some(high.to_string());
@@ -45,6 +84,17 @@ impl PolarsDataSource {
         self
     }
 
+    /// Finite Y extent of `df`: lowest low and highest high for OHLCV data.
+    fn y_bounds(&self, df: &DataFrame) -> Option<(f64, f64)> {
+        if let (Some(l), Some(h)) = (&self.low_col, &self.high_col) {
+            let (low, _) = finite_min_max(df.column(l).ok()?.as_series()?)?;
+            let (_, high) = finite_min_max(df.column(h).ok()?.as_series()?)?;
+            Some((low, high))
+        } else {
+            finite_min_max(df.column(&self.y_col).ok()?.as_series()?)
+        }
+    }
+
     fn get_range_indices(&self, x_min: f64, x_max: f64) -> (usize, usize) {
         let x_series = match self.df.column(&self.x_col).ok().and_then(|c| c.as_series()) {
             Some(s) => s,
@@ -179,9 +229,15 @@ impl PolarsDataSource {
         }
 
         let (target_bins, m4_mode) = match self.mode {
-            crate::data_types::AggregationMode::M4 => ((max_points / 4).max(1), true),
+            // Custom without a reducer falls back to M4
+            crate::data_types::AggregationMode::M4 | crate::data_types::AggregationMode::Custom => {
+                ((max_points / 4).max(1), true)
+            }
             crate::data_types::AggregationMode::MinMax => ((max_points / 2).max(1), false),
-            crate::data_types::AggregationMode::LTTB => unreachable!("LTTB is handled above"),
+            crate::data_types::AggregationMode::LTTB
+            | crate::data_types::AggregationMode::StableLTTB => {
+                unreachable!("LTTB is handled above")
+            }
         };
 
         let bin_size = (count as f64 / target_bins as f64).ceil() as i64;
@@ -296,23 +352,29 @@ impl PlotDataSource for PolarsDataSource {
         self.mode
     }
 
-    fn get_bounds(&self) -> Option<(f64, f64, f64, f64)> {
-        let x = self.df.column(&self.x_col).ok()?.as_series()?;
-        let y = self.df.column(&self.y_col).ok()?.as_series()?;
+    fn set_aggregation_mode(&mut self, mode: crate::data_types::AggregationMode) {
+        self.mode = mode;
+    }
 
-        let x_min = x.min::<f64>().ok()??;
-        let x_max = x.max::<f64>().ok()??;
+    fn decimation_threshold(&self) -> f64 {
+        self.decimation_threshold
+    }
 
-        let (y_min, y_max) = if let (Some(l), Some(h)) = (&self.low_col, &self.high_col) {
-            let low = self.df.column(l).ok()?.as_series()?.min::<f64>().ok()??;
-            let high = self.df.column(h).ok()?.as_series()?.max::<f64>().ok()??;
-            (low, high)
-        } else {
-            let y_min = y.min::<f64>().ok()??;
-            let y_max = y.max::<f64>().ok()??;
-            (y_min, y_max)
-        };
+    fn set_decimation_threshold(&mut self, threshold: f64) {
+        self.decimation_threshold = threshold.max(1.0);
+    }
+
+    fn decimation_policy(&self) -> DecimationPolicy {
+        self.decimation_policy
+    }
 
+    fn set_decimation_policy(&mut self, policy: DecimationPolicy) {
+        self.decimation_policy = policy;
+    }
+
+    fn get_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let (x_min, x_max) = finite_min_max(self.df.column(&self.x_col).ok()?.as_series()?)?;
+        let (y_min, y_max) = self.y_bounds(&self.df)?;
         Some((x_min, x_max, y_min, y_max))
     }
 
@@ -324,18 +386,7 @@ impl PlotDataSource for PolarsDataSource {
         }
 
         let len = end_idx - start_idx;
-        let sliced_df = self.df.slice(start_idx as i64, len);
-
-        if let (Some(l), Some(h)) = (&self.low_col, &self.high_col) {
-            let low = sliced_df.column(l).ok()?.as_series()?.min::<f64>().ok()??;
-            let high = sliced_df.column(h).ok()?.as_series()?.max::<f64>().ok()??;
-            Some((low, high))
-        } else {
-            let y_s = sliced_df.column(&self.y_col).ok()?.as_series()?;
-            let y_min = y_s.min::<f64>().ok()??;
-            let y_max = y_s.max::<f64>().ok()??;
-            Some((y_min, y_max))
-        }
+        self.y_bounds(&self.df.slice(start_idx as i64, len))
     }
 
     fn iter_range(&self, x_min: f64, x_max: f64) -> Box<dyn Iterator<Item = PlotData> + '_> {
@@ -453,6 +504,15 @@ impl PlotDataSource for PolarsDataSource {
     ) {
         output.clear();
 
+        let (lo, hi) = self.get_range_indices(x_min, x_max);
+        let max_points = match self.decimation_budget(hi.saturating_sub(lo), max_points) {
+            Some(budget) => budget,
+            None => {
+                output.extend(self.iter_range(x_min, x_max));
+                return;
+            }
+        };
+
         // Use REAL range for bin size calculation to ensure absolute stability during pan.
         let view_range = x_max - x_min;
 
@@ -460,9 +520,12 @@ impl PlotDataSource for PolarsDataSource {
             max_points.max(1)
         } else {
             match self.mode {
-                crate::data_types::AggregationMode::M4 => (max_points / 4).max(1),
+                crate::data_types::AggregationMode::M4 | crate::data_types::AggregationMode::Custom => {
+                    (max_points / 4).max(1)
+                }
                 crate::data_types::AggregationMode::MinMax => (max_points / 2).max(1),
-                crate::data_types::AggregationMode::LTTB => max_points.max(1),
+                crate::data_types::AggregationMode::LTTB
+                | crate::data_types::AggregationMode::StableLTTB => max_points.max(1),
             }
         };
 
@@ -507,10 +570,22 @@ impl PlotDataSource for PolarsDataSource {
             return;
         }
 
+        if let (crate::data_types::AggregationMode::Custom, Some(reducer), None) =
+            (self.mode, &self.custom_aggregator, &self.open_col)
+        {
+            let data: Vec<PlotData> = self.iter_range(x_min, x_max).collect();
+            crate::decimation::decimate_custom_slice_into(
+                &data, max_points, output, gaps, Some(view_range), reducer.as_ref(),
+            );
+            return;
+        }
+
         // Optimized Zero-Copy Path for Points (M4, MinMax, LTTB)
         if (matches!(self.mode, crate::data_types::AggregationMode::M4)
             || matches!(self.mode, crate::data_types::AggregationMode::MinMax)
-            || matches!(self.mode, crate::data_types::AggregationMode::LTTB))
+            || matches!(self.mode, crate::data_types::AggregationMode::LTTB)
+            || matches!(self.mode, crate::data_types::AggregationMode::StableLTTB)
+            || matches!(self.mode, crate::data_types::AggregationMode::Custom))
             && self.open_col.is_none()
         {
             let sliced = self.df.slice(start as i64, count);
@@ -546,7 +621,8 @@ impl PlotDataSource for PolarsDataSource {
                     };
 
                     match self.mode {
-                        crate::data_types::AggregationMode::M4 => {
+                        crate::data_types::AggregationMode::M4
+                        | crate::data_types::AggregationMode::Custom => {
                             crate::decimation::decimate_m4_arrays_par_into(
                                 x_slice, y_slice, max_points, output, gaps, Some(view_range),
                             )
@@ -561,6 +637,11 @@ impl PlotDataSource for PolarsDataSource {
                                 x_slice, y_slice, max_points, output, gaps, Some(view_range),
                             )
                         }
+                        crate::data_types::AggregationMode::StableLTTB => {
+                            crate::decimation::decimate_lttb_stable_arrays_into(
+                                x_slice, y_slice, max_points, output, gaps, Some(view_range),
+                            )
+                        }
                     };
                     return;
                 }
@@ -661,6 +742,7 @@ impl PlotDataSource for PolarsDataSource {
     fn add_data(&mut self, _data: PlotData) {}
 
     fn set_data(&mut self, data: Vec<PlotData>) {
+        self.version += 1;
         if data.is_empty() {
             self.df = DataFrame::default();
             return;
@@ -711,4 +793,13 @@ impl PlotDataSource for PolarsDataSource {
     fn len(&self) -> usize {
         self.df.height()
     }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        let xs = self.df.column(&self.x_col).ok()?.as_series()?.f64().ok()?;
+        step_sorted_x(xs.len(), |i| xs.get(i).unwrap_or(f64::NAN), x, steps)
+    }
 }
@@ -1,7 +1,7 @@
 //! Rendering functions for the chart
 #![allow(clippy::collapsible_if)]
 
-use crate::data_types::{AxisDomain, Series, SharedPlotState};
+use crate::data_types::{AxisDomain, CrosshairLineStyle, DataOverflow, Series, SharedPlotState};
 use crate::scales::ChartScale;
 use crate::transform::PlotTransform;
 use crate::utils::PixelsExt;
@@ -9,7 +9,13 @@ use gpui::*;
 
 /// Stats about the last paint operation.
 #[derive(Debug, Clone, Default)]
-pub struct PaintStats {}
+pub struct PaintStats {
+    /// Points of the visible series inside the X window, before decimation.
+    /// Only counted in debug mode.
+    pub raw_points: usize,
+    /// Points actually drawn, after decimation.
+    pub drawn_points: usize,
+}
 
 /// Paints the chart data on the canvas.
 pub fn paint_plot(
@@ -25,30 +31,116 @@ pub fn paint_plot(
     let width_px = bounds.size.width.as_f32();
     let height_px = bounds.size.height.as_f32();
 
-    for series in series {
-        let x_domain = x_domains
-            .get(series.x_axis_id.0)
-            .copied()
-            .unwrap_or((0.0, 1.0));
-        let mut x_scale = ChartScale::new_linear(x_domain, (0.0, width_px));
-        if let Some(gaps) = &state.gap_index {
-            x_scale = x_scale.with_gaps(Some(gaps.clone()));
-        }
+    let transforms: Vec<PlotTransform> = series
+        .iter()
+        .map(|series| {
+            let x_domain = x_domains
+                .get(series.x_axis_id.0)
+                .copied()
+                .unwrap_or((0.0, 1.0));
+            let mut x_scale = ChartScale::new_linear(x_domain, (0.0, width_px));
+            if let Some(gaps) = &state.gap_index {
+                x_scale = x_scale.with_gaps(Some(gaps.clone()));
+            }
 
-        let y_domain = y_domains
-            .get(series.y_axis_id.0)
-            .copied()
-            .unwrap_or((0.0, 1.0));
-        let y_scale = ChartScale::new_linear(y_domain, (height_px, 0.0));
+            let y_domain = y_domains
+                .get(series.y_axis_id.0)
+                .copied()
+                .unwrap_or((0.0, 1.0));
+            let y_scale = ChartScale::new_linear(y_domain, (height_px, 0.0));
 
-        let transform = PlotTransform::new(x_scale, y_scale, bounds);
-        series
+            PlotTransform::new(x_scale, y_scale, bounds)
+        })
+        .collect();
+
+    // Back to front; stable, so equal z-orders keep the pane order
+    let mut order: Vec<usize> = (0..series.len()).collect();
+    order.sort_by_key(|&i| series[i].z_order);
+
+    // Background layers first so they never cover another series
+    for &i in &order {
+        series[i]
             .plot
             .read()
-            .render(window, &transform, &series.id, _cx, state);
+            .render_background(window, &transforms[i], &series[i].id, _cx, state);
+    }
+    let mut stats = PaintStats::default();
+    for &i in &order {
+        let (series, transform) = (&series[i], &transforms[i]);
+        let plot = series.plot.read();
+        plot.render(window, transform, &series.id, _cx, state);
+        stats.drawn_points += plot.drawn_points().unwrap_or(0);
+        if state.debug_mode {
+            if let Some(source) = plot.data_source() {
+                let (x_min, x_max) = x_domains
+                    .get(series.x_axis_id.0)
+                    .copied()
+                    .unwrap_or((0.0, 1.0));
+                stats.raw_points += source.count_in_range(x_min, x_max);
+            }
+        }
+    }
+
+    stats
+}
+
+/// Paints one crosshair line from `from` to `to` with the given style.
+pub fn paint_crosshair_line(
+    window: &mut Window,
+    from: Point<Pixels>,
+    to: Point<Pixels>,
+    style: &CrosshairLineStyle,
+    theme: &crate::theme::ChartTheme,
+) {
+    let mut builder = PathBuilder::stroke(px(style.width));
+    if !style.dash.is_empty() {
+        let dash: Vec<Pixels> = style.dash.iter().map(|d| px(*d)).collect();
+        builder = builder.dash_array(&dash);
     }
+    builder.move_to(from);
+    builder.line_to(to);
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, style.color.unwrap_or(theme.crosshair_line));
+    }
+}
 
-    PaintStats {}
+/// Paints a dot on each series at the data point nearest to `hx`.
+#[allow(clippy::too_many_arguments)]
+pub fn paint_crosshair_markers(
+    window: &mut Window,
+    bounds: Bounds<Pixels>,
+    series: &[Series],
+    x_scale: &ChartScale,
+    y_domains: &[(f64, f64)],
+    hx: f64,
+    radius: f32,
+    color: Hsla,
+) {
+    for s in series {
+        let plot = s.plot.read();
+        let Some(source) = plot.data_source() else {
+            continue;
+        };
+        let Some(nearest) = source.nearest_point(hx) else {
+            continue;
+        };
+        let Some(&y_domain) = y_domains.get(s.y_axis_id.0) else {
+            continue;
+        };
+        let y_scale = ChartScale::new_linear(y_domain, (bounds.size.height.as_f32(), 0.0));
+        let center = point(
+            bounds.origin.x + px(x_scale.map(nearest.x())),
+            bounds.origin.y + px(y_scale.map(nearest.y())),
+        );
+        let r = px(radius);
+        window.paint_quad(
+            fill(
+                Bounds::new(center - point(r, r), size(r * 2.0, r * 2.0)),
+                color,
+            )
+            .corner_radii(r),
+        );
+    }
 }
 
 #[derive(Clone)]
@@ -59,7 +151,20 @@ pub struct YAxisRenderInfo {
     pub limits: (Option<f64>, Option<f64>),
 }
 
-/// Paints the grid lines on the canvas.
+/// Offsets from the left of the plot of the vertical grid lines: one per
+/// tick inside the domain and the limits, mapped through `x_scale` like the
+/// axis labels, so gaps are skipped the same way.
+pub fn grid_x_positions(x_domain: &AxisDomain, x_scale: &ChartScale, x_ticks: &[f64]) -> Vec<f32> {
+    x_ticks
+        .iter()
+        .filter(|&&t| t >= x_domain.x_min && t <= x_domain.x_max)
+        .filter(|&&t| x_domain.x_min_limit.is_none_or(|l| t >= l))
+        .filter(|&&t| x_domain.x_max_limit.is_none_or(|l| t <= l))
+        .map(|&t| x_scale.map(t))
+        .collect()
+}
+
+/// Paints the grid lines on the canvas, as selected by `theme.grid`.
 pub fn paint_grid(
     window: &mut Window,
     bounds: Bounds<Pixels>,
@@ -71,34 +176,27 @@ pub fn paint_grid(
 ) {
     let origin_x = bounds.origin.x.as_f32();
     let origin_y = bounds.origin.y.as_f32();
+    let scale_factor = window.scale_factor();
+    let snap = |pos: f32| {
+        if theme.snap_to_pixels {
+            crate::transform::snap_line_to_pixel(pos, 1.0, scale_factor)
+        } else {
+            pos
+        }
+    };
 
     let mut vertical_builder = PathBuilder::stroke(px(1.0));
     let mut has_vertical = false;
 
-    let x_span = x_domain.x_max - x_domain.x_min;
-
-    for tick_x in x_ticks {
-        if let Some(l) = x_domain.x_min_limit {
-            if *tick_x < l {
-                continue;
-            }
-        }
-        if let Some(l) = x_domain.x_max_limit {
-            if *tick_x > l {
-                continue;
-            }
-        }
-
-        let x_pct = (*tick_x - x_domain.x_min) / x_span;
-        if (0.0..=1.0).contains(&x_pct) {
-            let pixel_x = origin_x + x_scale.map(*tick_x);
-            vertical_builder.move_to(Point::new(px(pixel_x), px(origin_y + 0.5)));
-            vertical_builder.line_to(Point::new(
-                px(pixel_x),
-                px(origin_y + bounds.size.height.as_f32() - 0.5),
-            ));
-            has_vertical = true;
-        }
+    let x_ticks = if theme.grid.vertical() { x_ticks } else { &[] };
+    for offset_x in grid_x_positions(x_domain, x_scale, x_ticks) {
+        let pixel_x = snap(origin_x + offset_x);
+        vertical_builder.move_to(Point::new(px(pixel_x), px(origin_y + 0.5)));
+        vertical_builder.line_to(Point::new(
+            px(pixel_x),
+            px(origin_y + bounds.size.height.as_f32() - 0.5),
+        ));
+        has_vertical = true;
     }
     if has_vertical {
         if let Ok(path) = vertical_builder.build() {
@@ -109,7 +207,8 @@ pub fn paint_grid(
     let mut horizontal_builder = PathBuilder::stroke(px(1.0));
     let mut has_horizontal = false;
 
-    for tick_y in &primary_y_axis.ticks {
+    let y_ticks = if theme.grid.horizontal() { &primary_y_axis.ticks[..] } else { &[] };
+    for tick_y in y_ticks {
         if let Some(l) = primary_y_axis.limits.0 {
             if *tick_y < l {
                 continue;
@@ -124,7 +223,7 @@ pub fn paint_grid(
         let y_pct = (*tick_y - primary_y_axis.domain.1)
             / (primary_y_axis.domain.0 - primary_y_axis.domain.1);
         if (0.0..=1.0).contains(&y_pct) {
-            let pixel_y = origin_y + primary_y_axis.scale.map(*tick_y);
+            let pixel_y = snap(origin_y + primary_y_axis.scale.map(*tick_y));
             horizontal_builder.move_to(Point::new(px(origin_x + 0.5), px(pixel_y)));
             horizontal_builder.line_to(Point::new(
                 px(origin_x + bounds.size.width.as_f32() - 0.5),
@@ -140,6 +239,58 @@ pub fn paint_grid(
     }
 }
 
+/// Paints a horizontal reference line `offset_y` pixels below the top of the pane.
+pub fn paint_baseline(window: &mut Window, bounds: Bounds<Pixels>, offset_y: f32, color: Hsla) {
+    let y = bounds.origin.y.as_f32() + offset_y;
+    let mut builder = PathBuilder::stroke(px(1.5));
+    builder.move_to(Point::new(bounds.origin.x, px(y)));
+    builder.line_to(Point::new(bounds.origin.x + bounds.size.width, px(y)));
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Paints a chevron pointing outward at the middle of each edge of `bounds`
+/// flagged in `overflow`.
+pub fn paint_overflow_indicators(
+    window: &mut Window,
+    bounds: Bounds<Pixels>,
+    overflow: DataOverflow,
+    color: Hsla,
+) {
+    const HALF: f32 = 5.0;
+    const INSET: f32 = 4.0;
+    let (x0, y0) = (bounds.origin.x.as_f32(), bounds.origin.y.as_f32());
+    let (w, h) = (bounds.size.width.as_f32(), bounds.size.height.as_f32());
+    let (mid_x, mid_y) = (x0 + w / 2.0, y0 + h / 2.0);
+    // Tip of the chevron and the direction it points to
+    let chevrons = [
+        (overflow.left, (x0 + INSET, mid_y), (-1.0, 0.0)),
+        (overflow.right, (x0 + w - INSET, mid_y), (1.0, 0.0)),
+        (overflow.top, (mid_x, y0 + INSET), (0.0, -1.0)),
+        (overflow.bottom, (mid_x, y0 + h - INSET), (0.0, 1.0)),
+    ];
+    for (show, (tx, ty), (dx, dy)) in chevrons {
+        if !show {
+            continue;
+        }
+        // Arms go back from the tip, spread across the direction
+        let arm = |side: f32| {
+            Point::new(
+                px(tx - dx * HALF + dy * HALF * side),
+                px(ty - dy * HALF + dx * HALF * side),
+            )
+        };
+        let mut builder = PathBuilder::stroke(px(1.5));
+        builder.move_to(arm(-1.0));
+        builder.line_to(Point::new(px(tx), px(ty)));
+        builder.line_to(arm(1.0));
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color);
+        }
+    }
+}
+
 /// Helper to create a tag element on an axis.
 pub fn create_axis_tag(
     text: String,
@@ -3,10 +3,57 @@ use d3rs::scale::LogScale;
 use d3rs::scale::{LinearScale, Scale as D3Scale};
 use std::sync::Arc;
 
+/// Evenly spaced bands over the indices `0..count`, with padding expressed as a
+/// fraction of the step (applied between bands and at both ends).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BandScale {
+    pub count: usize,
+    pub range: (f32, f32),
+    pub padding: f32,
+}
+
+impl BandScale {
+    /// Distance between the centers of two consecutive bands (signed like the range).
+    pub fn step(&self) -> f32 {
+        let n = self.count as f32;
+        let padding = self.padding.clamp(0.0, 1.0);
+        // n bands, n - 1 inner paddings and two outer paddings
+        (self.range.1 - self.range.0) / (n + padding).max(1.0)
+    }
+
+    pub fn bandwidth(&self) -> f32 {
+        self.step().abs() * (1.0 - self.padding.clamp(0.0, 1.0))
+    }
+
+    /// Center of the band at (possibly fractional) `index`.
+    pub fn center(&self, index: f64) -> f32 {
+        let step = self.step() as f64;
+        // Outer padding plus half a band
+        let first = self.range.0 as f64 + step * (1.0 + self.padding.clamp(0.0, 1.0) as f64) / 2.0;
+        (first + index * step) as f32
+    }
+
+    /// Fractional index whose band center is at `pixel`.
+    pub fn invert(&self, pixel: f32) -> f64 {
+        let step = self.step() as f64;
+        if step == 0.0 {
+            return 0.0;
+        }
+        (pixel as f64 - self.center(0.0) as f64) / step
+    }
+
+    /// Index of the band whose slot (band plus its share of padding) contains `pixel`.
+    pub fn index_at(&self, pixel: f32) -> Option<usize> {
+        let idx = self.invert(pixel).round();
+        (idx >= 0.0 && (idx as usize) < self.count).then_some(idx as usize)
+    }
+}
+
 #[derive(Clone)]
 pub enum ChartScale {
     Linear(LinearScale, Option<Arc<GapIndex>>),
     Log(LogScale, Option<Arc<GapIndex>>),
+    Band(BandScale),
 }
 
 impl ChartScale {
@@ -23,12 +70,41 @@ impl ChartScale {
         Self::Linear(scale, None)
     }
 
+    /// Band scale placing `count` evenly spaced items (indices `0..count`) across
+    /// `range`. `padding` is the fraction of each step left empty between items.
+    pub fn new_band(count: usize, range: (f32, f32), padding: f32) -> Self {
+        Self::Band(BandScale {
+            count,
+            range,
+            padding,
+        })
+    }
+
+    /// Width of one band, for band scales.
+    pub fn bandwidth(&self) -> Option<f32> {
+        match self {
+            Self::Band(b) => Some(b.bandwidth()),
+            _ => None,
+        }
+    }
+
+    /// Index of the band under `pixel`, for band scales.
+    pub fn band_index(&self, pixel: f32) -> Option<usize> {
+        match self {
+            Self::Band(b) => b.index_at(pixel),
+            _ => None,
+        }
+    }
+
     pub fn with_gaps(mut self, gaps: Option<Arc<GapIndex>>) -> Self {
+        let gaps = gaps.filter(|g| !g.is_empty());
         // Force refresh domain if gaps changed
         let (d_min, d_max) = self.domain();
         match &mut self {
             Self::Linear(_, g) => *g = gaps,
             Self::Log(_, g) => *g = gaps,
+            // Bands are laid out by index, gaps do not apply
+            Self::Band(_) => return self,
         }
         self.update_domain(d_min, d_max);
         self
@@ -38,6 +114,7 @@ impl ChartScale {
         match self {
             Self::Linear(_, g) => g.as_ref(),
             Self::Log(_, g) => g.as_ref(),
+            Self::Band(_) => None,
         }
     }
 
@@ -51,6 +128,7 @@ impl ChartScale {
         let res = match self {
             Self::Linear(s, _) => s.scale(logical_value) as f32,
             Self::Log(s, _) => s.scale(logical_value) as f32,
+            Self::Band(b) => b.center(logical_value),
         };
         if res.is_nan() || res.is_infinite() {
             0.0
@@ -63,6 +141,7 @@ impl ChartScale {
         let logical_value = match self {
             Self::Linear(s, _) => s.invert(pixel as f64).unwrap_or(0.0),
             Self::Log(s, _) => s.invert(pixel as f64).unwrap_or(0.0),
+            Self::Band(b) => b.invert(pixel),
         };
 
         if let Some(gaps) = self.gap_index() {
@@ -76,6 +155,7 @@ impl ChartScale {
         match self {
             Self::Linear(s, _) => (s.range().0 as f32, s.range().1 as f32),
             Self::Log(s, _) => (s.range().0 as f32, s.range().1 as f32),
+            Self::Band(b) => b.range,
         }
     }
 
@@ -83,6 +163,8 @@ impl ChartScale {
         let (l_min, l_max) = match self {
             Self::Linear(s, _) => (s.domain().0, s.domain().1),
             Self::Log(s, _) => (s.domain().0, s.domain().1),
+            // Covers the outer half of the first and last bands
+            Self::Band(b) => (-0.5, b.count as f64 - 0.5),
         };
 
         if let Some(gaps) = self.gap_index() {
@@ -99,6 +181,10 @@ impl ChartScale {
         let logical_ticks = match self {
             Self::Linear(s, _) => s.ticks(count),
             Self::Log(s, _) => s.ticks(count),
+            Self::Band(b) => {
+                let stride = b.count.div_ceil(count.max(1)).max(1);
+                (0..b.count).step_by(stride).map(|i| i as f64).collect()
+            }
         };
 
         if let Some(gaps) = self.gap_index() {
@@ -129,6 +215,19 @@ impl ChartScale {
                 let fmt = crate::utils::date_formatter::determine_date_format(span_sec);
                 return crate::utils::date_formatter::format_timestamp(value, fmt, *unit, *tz);
             }
+            crate::data_types::AxisFormat::PercentOfRange => {
+                let (d_min, d_max) = self.domain();
+                let span = d_max - d_min;
+                if span == 0.0 {
+                    return "0%".to_string();
+                }
+                let pct = (value - d_min) / span * 100.0;
+                return if (pct - pct.round()).abs() < 1e-6 {
+                    format!("{:.0}%", pct)
+                } else {
+                    format!("{:.1}%", pct)
+                };
+            }
             crate::data_types::AxisFormat::Numeric => {
                 // Keep heuristic ONLY for numeric fallback if it looks really like a timestamp
                 if value.abs() > 100_000_000_000.0 {
@@ -182,6 +281,9 @@ impl ChartScale {
             Self::Log(s, _) => {
                 *s = s.domain(d_min, d_max);
             }
+            Self::Band(b) => {
+                b.count = (max - min).round().max(0.0) as usize;
+            }
         }
     }
 
@@ -193,6 +295,9 @@ impl ChartScale {
             Self::Log(s, _) => {
                 s.range(min as f64, max as f64);
             }
+            Self::Band(b) => {
+                b.range = (min, max);
+            }
         }
     }
 
@@ -202,6 +307,7 @@ impl ChartScale {
         let (l_min, l_max) = match self {
             Self::Linear(s, _) => (s.domain().0, s.domain().1),
             Self::Log(s, _) => (s.domain().0, s.domain().1),
+            Self::Band(b) => return (b.step(), b.center(0.0)),
         };
         let (r_min, r_max) = self.range();
 
@@ -209,8 +315,8 @@ impl ChartScale {
         let c = r_min as f64 - m * l_min;
 
         match self {
-            Self::Linear(_, _) => (m as f32, c as f32),
             Self::Log(_, _) => (1.0, 0.0), // Fallback, manual map needed for log
+            _ => (m as f32, c as f32),
         }
     }
 }
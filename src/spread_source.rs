@@ -0,0 +1,198 @@
+//! Data source deriving a series from two others (spread, ratio...).
+
+use crate::data_types::{step_sorted_x, ColorOp, PlotData, PlotDataSource, PlotPoint};
+use crate::plot_types::PlotRenderer;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Plot shared with the series it is read from.
+pub type SharedPlot = Arc<RwLock<dyn PlotRenderer + Send + Sync>>;
+
+/// How the two inputs of a `SpreadDataSource` are combined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpreadOp {
+    /// `a - b`
+    Difference,
+    /// `a / b`
+    Ratio,
+    /// `ln(a / b)`, symmetric around 0 for relative moves
+    LogRatio,
+}
+
+impl SpreadOp {
+    pub fn apply(self, a: f64, b: f64) -> Option<f64> {
+        let v = match self {
+            SpreadOp::Difference => a - b,
+            SpreadOp::Ratio => a / b,
+            SpreadOp::LogRatio => (a / b).ln(),
+        };
+        v.is_finite().then_some(v)
+    }
+
+    /// Name of the derived series, e.g. `a - b`.
+    pub fn label(self, a: &str, b: &str) -> String {
+        match self {
+            SpreadOp::Difference => format!("{a} - {b}"),
+            SpreadOp::Ratio => format!("{a} / {b}"),
+            SpreadOp::LogRatio => format!("ln({a} / {b})"),
+        }
+    }
+}
+
+/// Series derived from two plots: one point per X sample of `a`,
+/// combined with `b` linearly interpolated at that X (close for OHLCV).
+/// Samples of `a` outside `b`'s data, or where the result is not finite
+/// (division by zero, log of a negative ratio), are skipped.
+///
+/// The derived series is computed over the whole of `a` and cached until
+/// the length or bounds of either input change, so the result follows them
+/// as they stream; `add_data`/`set_data` are ignored.
+pub struct SpreadDataSource {
+    a: SharedPlot,
+    b: SharedPlot,
+    op: SpreadOp,
+    cache: RwLock<Option<(InputsKey, Arc<Derived>)>>,
+}
+
+struct Derived {
+    points: Vec<PlotData>,
+    bounds: Option<(f64, f64, f64, f64)>,
+}
+
+/// Versions of both inputs the cached series was computed from.
+type InputsKey = [u64; 2];
+
+impl SpreadDataSource {
+    pub fn new(a: SharedPlot, b: SharedPlot, op: SpreadOp) -> Self {
+        Self {
+            a,
+            b,
+            op,
+            cache: RwLock::new(None),
+        }
+    }
+
+    pub fn op(&self) -> SpreadOp {
+        self.op
+    }
+
+    /// The derived series, recomputed if either input changed.
+    fn derived(&self) -> Arc<Derived> {
+        let a = self.a.read();
+        // The same plot on both sides must not be locked twice
+        let b_guard = (!Arc::ptr_eq(&self.a, &self.b)).then(|| self.b.read());
+        let b = b_guard.as_deref().unwrap_or(&*a);
+        let (Some(src_a), Some(src_b)) = (a.data_source(), b.data_source()) else {
+            return Arc::new(Derived {
+                points: Vec::new(),
+                bounds: None,
+            });
+        };
+        let key = [src_a, src_b].map(|s| s.version());
+        if let Some((cached_key, derived)) = self.cache.read().as_ref() {
+            if *cached_key == key {
+                return derived.clone();
+            }
+        }
+        let points = self.compute(src_a, src_b);
+        let bounds: Option<(f64, f64, f64, f64)> = points.iter().fold(None, |acc, p| {
+            let (x, y) = (p.x(), p.y());
+            Some(match acc {
+                None => (x, x, y, y),
+                Some((x0, x1, y0, y1)) => (x0.min(x), x1.max(x), y0.min(y), y1.max(y)),
+            })
+        });
+        let derived = Arc::new(Derived { points, bounds });
+        *self.cache.write() = Some((key, derived.clone()));
+        derived
+    }
+
+    fn compute(&self, src_a: &dyn PlotDataSource, src_b: &dyn PlotDataSource) -> Vec<PlotData> {
+        let all = |s: &dyn PlotDataSource| -> Vec<(f64, f64)> {
+            let Some((x_min, x_max, _, _)) = s.get_bounds() else {
+                return Vec::new();
+            };
+            s.iter_range(x_min, x_max).map(|p| (p.x(), p.y())).collect()
+        };
+        let (samples, reference) = (all(src_a), all(src_b));
+
+        let mut out = Vec::with_capacity(samples.len());
+        let mut j = 0;
+        for (x, ya) in samples {
+            while j + 1 < reference.len() && reference[j + 1].0 <= x {
+                j += 1;
+            }
+            let Some(&(x0, y0)) = reference.get(j) else {
+                break;
+            };
+            let yb = if x0 == x {
+                y0
+            } else if let (true, Some(&(x1, y1))) = (x0 < x, reference.get(j + 1)) {
+                y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+            } else {
+                continue;
+            };
+            if let Some(y) = self.op.apply(ya, yb) {
+                out.push(PlotData::Point(PlotPoint {
+                    x,
+                    y,
+                    color_op: ColorOp::None,
+                }));
+            }
+        }
+        out
+    }
+
+    /// Index range of the cached points with `x_min <= x <= x_max`.
+    fn range(points: &[PlotData], x_min: f64, x_max: f64) -> std::ops::Range<usize> {
+        let start = points.partition_point(|p| p.x() < x_min);
+        let end = points.partition_point(|p| p.x() <= x_max);
+        start..end.max(start)
+    }
+}
+
+impl PlotDataSource for SpreadDataSource {
+    fn get_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        self.derived().bounds
+    }
+
+    fn get_y_range(&self, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+        let derived = self.derived();
+        let points = &derived.points;
+        points[Self::range(points, x_min, x_max)].iter().fold(None, |acc, p| {
+            let y = p.y();
+            Some(acc.map_or((y, y), |(lo, hi): (f64, f64)| (lo.min(y), hi.max(y))))
+        })
+    }
+
+    fn iter_range(&self, x_min: f64, x_max: f64) -> Box<dyn Iterator<Item = PlotData> + '_> {
+        let derived = self.derived();
+        let range = Self::range(&derived.points, x_min, x_max);
+        Box::new(range.map(move |i| derived.points[i].clone()))
+    }
+
+    fn add_data(&mut self, _data: PlotData) {}
+
+    fn set_data(&mut self, _data: Vec<PlotData>) {}
+
+    fn suggested_x_spacing(&self) -> f64 {
+        self.a
+            .read()
+            .data_source()
+            .map_or(1.0, |s| s.suggested_x_spacing())
+    }
+
+    fn len(&self) -> usize {
+        self.derived().points.len()
+    }
+
+    fn step_x(&self, x: f64, steps: isize) -> Option<f64> {
+        let points = &self.derived().points;
+        step_sorted_x(points.len(), |i| points[i].x(), x, steps)
+    }
+
+    fn version(&self) -> u64 {
+        let version_of = |plot: &SharedPlot| plot.read().data_source().map_or(0, |s| s.version());
+        version_of(&self.a) + version_of(&self.b)
+    }
+}
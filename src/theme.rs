@@ -1,9 +1,38 @@
 use gpui::*;
 
+/// Grid lines drawn behind the series.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridVisibility {
+    #[default]
+    Both,
+    /// Lines at the Y ticks only, e.g. for candlestick charts
+    HorizontalOnly,
+    /// Lines at the X ticks only, e.g. for bar charts
+    VerticalOnly,
+    None,
+}
+
+impl GridVisibility {
+    pub fn horizontal(self) -> bool {
+        matches!(self, Self::Both | Self::HorizontalOnly)
+    }
+
+    pub fn vertical(self) -> bool {
+        matches!(self, Self::Both | Self::VerticalOnly)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct ChartTheme {
     pub background: Hsla,
     pub grid_line: Hsla,
+    pub(crate) grid: GridVisibility,
+    /// Snaps grid lines and axis ticks to whole device pixels so 1px lines
+    /// stay crisp on low-DPI displays. Off by default: snapped lines move by
+    /// whole pixels, which shows as a slight jitter during a smooth pan.
+    pub(crate) snap_to_pixels: bool,
+    /// Pane baseline (see `Chart::set_pane_baseline`), stronger than the grid
+    pub baseline_line: Hsla,
     pub axis_line: Hsla,
     pub axis_label: Hsla,
     pub axis_label_size: Pixels,
@@ -12,7 +41,14 @@ pub struct ChartTheme {
     pub tooltip_text: Hsla,
     pub tag_background: Hsla,
     pub tag_text: Hsla,
+    /// Behind the labels of line annotations (see `AnnotationPlot`)
+    pub annotation_label_background: Hsla,
     pub accent: Hsla,
+    /// Color of the "No data" / "Loading…" message shown in empty panes
+    pub placeholder_text: Hsla,
+    /// Chevrons at the pane edges beyond which there is more data (see
+    /// `Chart::set_show_overflow_indicators`)
+    pub overflow_indicator: Hsla,
     pub candle_body_width_pct: f32,
     pub candle_wick_width_pct: f32,
     pub candle_contour_thickness_px: f32,
@@ -27,6 +63,9 @@ impl ChartTheme {
         Self {
             background: gpui::black(),
             grid_line: gpui::white().opacity(0.1),
+            grid: GridVisibility::Both,
+            snap_to_pixels: false,
+            baseline_line: gpui::white().opacity(0.35),
             axis_line: gpui::white().opacity(0.2),
             axis_label: gpui::white().opacity(0.8),
             axis_label_size: px(11.0),
@@ -35,7 +74,10 @@ impl ChartTheme {
             tooltip_text: gpui::white(),
             tag_background: gpui::white(),
             tag_text: gpui::black(),
+            annotation_label_background: gpui::black().opacity(0.6),
             accent: gpui::blue(),
+            placeholder_text: gpui::white().opacity(0.5),
+            overflow_indicator: gpui::white().opacity(0.4),
             candle_body_width_pct: 0.8,
             candle_wick_width_pct: 0.1,
             candle_contour_thickness_px: 1.0,
@@ -50,6 +92,9 @@ impl ChartTheme {
         Self {
             background: gpui::white(),
             grid_line: gpui::black().opacity(0.1),
+            grid: GridVisibility::Both,
+            snap_to_pixels: false,
+            baseline_line: gpui::black().opacity(0.35),
             axis_line: gpui::black().opacity(0.2),
             axis_label: gpui::black().opacity(0.8),
             axis_label_size: px(11.0),
@@ -58,7 +103,10 @@ impl ChartTheme {
             tooltip_text: gpui::black(),
             tag_background: gpui::black(),
             tag_text: gpui::white(),
+            annotation_label_background: gpui::white().opacity(0.7),
             accent: gpui::blue(),
+            placeholder_text: gpui::black().opacity(0.5),
+            overflow_indicator: gpui::black().opacity(0.4),
             candle_body_width_pct: 0.8,
             candle_wick_width_pct: 0.1,
             candle_contour_thickness_px: 1.0,
@@ -68,6 +116,25 @@ impl ChartTheme {
             down_candle_contour_color: Hsla::from(gpui::red()),
         }
     }
+
+    /// Selects the grid lines drawn, all by default.
+    pub fn with_grid(mut self, grid: GridVisibility) -> Self {
+        self.grid = grid;
+        self
+    }
+
+    pub fn grid(&self) -> GridVisibility {
+        self.grid
+    }
+
+    pub fn with_snap_to_pixels(mut self, snap: bool) -> Self {
+        self.snap_to_pixels = snap;
+        self
+    }
+
+    pub fn snap_to_pixels(&self) -> bool {
+        self.snap_to_pixels
+    }
 }
 
 impl Default for ChartTheme {
@@ -56,3 +56,27 @@ impl PlotTransform {
         (x_m, x_offset, y_m, y_offset)
     }
 }
+
+/// Moves a coordinate (logical pixels) so that a straight line `width` pixels
+/// wide drawn at it covers whole device pixels instead of straddling two,
+/// which antialiasing would render as a blurry two-pixel line.
+pub fn snap_line_to_pixel(pos: f32, width: f32, scale_factor: f32) -> f32 {
+    let s = if scale_factor > 0.0 { scale_factor } else { 1.0 };
+    // Odd device widths are centered on a pixel center, even ones on an edge
+    let offset = if (width * s).round() as i64 % 2 == 1 { 0.5 } else { 0.0 };
+    ((pos * s - offset).round() + offset) / s
+}
+
+/// Clip rectangle for a plot area: `bounds` shrunk to whole device pixels, so
+/// antialiased strokes, caps and joins cannot bleed into adjacent panes or gutters.
+pub fn plot_clip_bounds(bounds: Bounds<Pixels>, scale_factor: f32) -> Bounds<Pixels> {
+    let s = if scale_factor > 0.0 { scale_factor } else { 1.0 };
+    let left = (bounds.origin.x.as_f32() * s).ceil() / s;
+    let top = (bounds.origin.y.as_f32() * s).ceil() / s;
+    let right = ((bounds.origin.x + bounds.size.width).as_f32() * s).floor() / s;
+    let bottom = ((bounds.origin.y + bounds.size.height).as_f32() * s).floor() / s;
+    Bounds::new(
+        point(px(left), px(top)),
+        size(px((right - left).max(0.0)), px((bottom - top).max(0.0))),
+    )
+}
@@ -1,6 +1,10 @@
 use crate::data_types::AxisRange;
 use crate::gaps::GapIndex;
 
+/// Smallest height, in pixels, a splitter drag leaves to a pane next to a
+/// fixed-height pane.
+pub const MIN_PANE_HEIGHT: f32 = 20.0;
+
 /// ViewController handles the business logic of interactions (zoom, pan, resize)
 /// independently of the GPUI infrastructure to facilitate testing.
 pub struct ViewController;
@@ -78,6 +82,7 @@ impl ViewController {
             let pivot_data = range.min + real_span * pivot_pct;
             range.zoom_at(pivot_data, pivot_pct, new_factor);
         }
+        range.snap_to_data();
         range.clamp();
     }
 
@@ -108,17 +113,115 @@ impl ViewController {
         weights[index + 1] = w2 - actual_dw;
     }
 
+    /// Like `resize_panes` for a layout mixing weighted panes and panes with
+    /// a fixed pixel height (`fixed[i]` set). A fixed pane next to the
+    /// splitter grows or shrinks by `delta_pixels` itself, the weighted panes
+    /// absorbing the difference; two weighted panes trade weight over the
+    /// height left to weighted panes. No pane gets smaller than
+    /// `MIN_PANE_HEIGHT` through a fixed neighbor.
+    pub fn resize_mixed_panes(
+        weights: &mut [f32],
+        fixed: &mut [Option<f32>],
+        index: usize,
+        delta_pixels: f32,
+        total_height: f32,
+    ) {
+        if index + 1 >= weights.len() || fixed.len() != weights.len() || total_height <= 0.0 {
+            return;
+        }
+        let heights = Self::pane_heights(weights, fixed, total_height);
+
+        match (fixed[index], fixed[index + 1]) {
+            (None, None) => {
+                // Weights only share the height left by fixed panes
+                let flexible: Vec<usize> =
+                    (0..weights.len()).filter(|&i| fixed[i].is_none()).collect();
+                let flex_height: f32 = flexible.iter().map(|&i| heights[i]).sum();
+                let mut flex_weights: Vec<f32> = flexible.iter().map(|&i| weights[i]).collect();
+                let pos = flexible.iter().position(|&i| i == index).unwrap_or(0);
+                Self::resize_panes(&mut flex_weights, pos, delta_pixels, flex_height);
+                weights[index] = flex_weights[pos];
+                weights[index + 1] = flex_weights[pos + 1];
+            }
+            (Some(h1), Some(h2)) => {
+                let d = delta_pixels.min(h2 - MIN_PANE_HEIGHT).max(MIN_PANE_HEIGHT - h1);
+                fixed[index] = Some(h1 + d);
+                fixed[index + 1] = Some(h2 - d);
+            }
+            (Some(h1), None) => {
+                let d = delta_pixels.min(heights[index + 1] - MIN_PANE_HEIGHT);
+                fixed[index] = Some((h1 + d).max(MIN_PANE_HEIGHT));
+            }
+            (None, Some(h2)) => {
+                let d = delta_pixels.max(MIN_PANE_HEIGHT - heights[index]);
+                fixed[index + 1] = Some((h2 - d).max(MIN_PANE_HEIGHT));
+            }
+        }
+    }
+
+    /// Height of each pane in pixels: fixed heights first (scaled down if
+    /// they do not fit), then the rest shared by weight among the other
+    /// panes, equally when their weights sum to zero. Without weighted panes
+    /// the remaining height is left empty.
+    pub fn pane_heights(weights: &[f32], fixed: &[Option<f32>], total_height: f32) -> Vec<f32> {
+        let total_height = total_height.max(0.0);
+        let fixed_at = |i: usize| fixed.get(i).copied().flatten().map(|h| h.max(0.0));
+        let fixed_sum: f32 = (0..weights.len()).filter_map(fixed_at).sum();
+        let fixed_scale = if fixed_sum > total_height {
+            total_height / fixed_sum
+        } else {
+            1.0
+        };
+        let remaining = (total_height - fixed_sum).max(0.0);
+
+        let flexible: Vec<usize> =
+            (0..weights.len()).filter(|&i| fixed_at(i).is_none()).collect();
+        let flex_weights: Vec<f32> = flexible.iter().map(|&i| weights[i]).collect();
+        let flex_fractions = Self::pane_fractions(&flex_weights);
+
+        let mut heights: Vec<f32> = (0..weights.len())
+            .map(|i| fixed_at(i).map_or(0.0, |h| h * fixed_scale))
+            .collect();
+        for (&i, f) in flexible.iter().zip(flex_fractions) {
+            heights[i] = remaining * f;
+        }
+        heights
+    }
+
+    /// Fraction of the chart height given to each pane, from the pane weights.
+    /// Panes share the height equally when the weights sum to zero.
+    pub fn pane_fractions(weights: &[f32]) -> Vec<f32> {
+        let total_weight: f32 = weights.iter().sum();
+        weights
+            .iter()
+            .map(|w| {
+                if total_weight > 0.0 {
+                    w / total_weight
+                } else {
+                    1.0 / weights.len() as f32
+                }
+            })
+            .collect()
+    }
+
     /// Calculates the new bounds for an auto-fit with an optional margin.
+    ///
+    /// A zero-span range (single point, flat series) is first widened around its
+    /// value by 5% of the value (at least 0.5 on each side), so it renders as a
+    /// centered line even with a zero margin.
     pub fn compute_auto_fit(min: f64, max: f64, margin_pct: f64) -> (f64, f64) {
-        if min == f64::INFINITY || max == f64::NEG_INFINITY {
+        if !min.is_finite() || !max.is_finite() || min > max {
             return (0.0, 100.0);
         }
 
-        let span = if (max - min).abs() < f64::EPSILON {
-            1.0 // Avoids a zero span
+        let (min, max) = if max - min <= f64::EPSILON * max.abs().max(1.0) {
+            let center = (min + max) / 2.0;
+            let half = (center.abs() * 0.05).max(0.5);
+            (center - half, center + half)
         } else {
-            max - min
+            (min, max)
         };
+        let span = max - min;
 
         (min - span * margin_pct, max + span * margin_pct)
     }
@@ -131,6 +234,18 @@ impl ViewController {
         range.clamp();
     }
 
+    /// `auto_fit_axis` with the axis' own `autofit_padding`, falling back to
+    /// `default_padding` (the chart-wide setting).
+    pub fn auto_fit_axis_padded(
+        range: &mut AxisRange,
+        data_min: f64,
+        data_max: f64,
+        default_padding: f64,
+    ) {
+        let padding = range.autofit_padding.unwrap_or(default_padding);
+        Self::auto_fit_axis(range, data_min, data_max, padding);
+    }
+
     /// Centers the axis on a given value, optionally respecting strict limits.
     pub fn move_to_center(range: &mut AxisRange, center_data: f64, clamp_to: Option<(f64, f64)>) {
         let span = range.span();
@@ -156,6 +271,23 @@ impl ViewController {
         range.clamp();
     }
 
+    /// Zoom pivot for a cursor at screen coordinate `pos` on an axis spanning
+    /// `length` pixels from `origin`, as the fraction of the domain from its
+    /// minimum expected by `zoom_axis_at`. Clamped to the axis; Y is flipped
+    /// since it grows downward on screen. Shared by axis drags and pane drags
+    /// so both zoom around the same point for the same cursor position.
+    pub fn zoom_pivot_pct(pos: f32, origin: f32, length: f32, is_y: bool) -> f64 {
+        if length <= 0.0 {
+            return 0.5;
+        }
+        let pct = ((pos - origin) / length).clamp(0.0, 1.0) as f64;
+        if is_y {
+            1.0 - pct
+        } else {
+            pct
+        }
+    }
+
     /// Calculates a zoom factor based on a pixel delta.
     pub fn compute_zoom_factor(delta: f32, sensitivity: f32) -> f64 {
         let factor = 1.0 + (delta.abs() / sensitivity) as f64;
@@ -174,6 +306,38 @@ impl ViewController {
         }
     }
 
+    /// Scales a drag velocity (px/s) down so its magnitude does not exceed
+    /// `max`, keeping its direction.
+    pub fn clamp_velocity(vx: f64, vy: f64, max: f64) -> (f64, f64) {
+        let speed = vx.hypot(vy);
+        if speed > max && speed > 0.0 {
+            let k = max.max(0.0) / speed;
+            (vx * k, vy * k)
+        } else {
+            (vx, vy)
+        }
+    }
+
+    /// Pans by `delta_data` and applies the axis limits. Returns false when
+    /// the limits stopped the pan short, i.e. the axis hit a wall.
+    pub fn pan_clamped(range: &mut AxisRange, delta_data: f64) -> bool {
+        let expected = range.min + delta_data;
+        range.pan(delta_data);
+        range.clamp();
+        (range.min - expected).abs() <= range.span().abs() * 1e-9
+    }
+
+    /// Offset from the top of a vertical axis `height_px` tall showing
+    /// `domain` at which `value` is drawn, or None when it is not visible.
+    pub fn value_to_offset_px(value: f64, domain: (f64, f64), height_px: f32) -> Option<f32> {
+        let (lo, hi) = (domain.0.min(domain.1), domain.0.max(domain.1));
+        if hi <= lo || !(lo..=hi).contains(&value) {
+            return None;
+        }
+        let pct = (value - domain.0) / (domain.1 - domain.0);
+        Some(((1.0 - pct) * height_px as f64) as f32)
+    }
+
     /// Maps a pixel position to a value in a given domain.
     pub fn map_pixels_to_value(
         pixels: f32,
@@ -0,0 +1,380 @@
+use gpui::{AppContext, TestAppContext};
+use gpui_chart::data_types::{Annotation, AnnotationLayer, AxisRange, LineStyle, SharedPlotState};
+use gpui_chart::plot_types::{AnnotationPlot, PlotRenderer};
+use gpui_chart::{Chart, ANNOTATIONS_SERIES_ID};
+
+#[test]
+fn test_annotation_layer() {
+    assert_eq!(AnnotationLayer::default(), AnnotationLayer::Foreground);
+
+    let zone = Annotation::Rect {
+        x_min: 0.0,
+        x_max: 10.0,
+        y_min: 0.0,
+        y_max: 5.0,
+        color: gpui::green(),
+        fill: true,
+        layer: AnnotationLayer::Background,
+        affects_autofit: false,
+    };
+    assert_eq!(zone.layer(), AnnotationLayer::Background);
+
+    let callout = Annotation::Text {
+        x: 5.0,
+        y: 2.5,
+        text: "Peak".into(),
+        color: gpui::white(),
+        font_size: 12.0,
+        layer: AnnotationLayer::Foreground,
+        affects_autofit: false,
+    };
+    assert_eq!(callout.layer(), AnnotationLayer::Foreground);
+}
+
+fn hline(y: f64, affects_autofit: bool) -> Annotation {
+    Annotation::HLine {
+        y,
+        color: gpui::red(),
+        width: 1.0,
+        style: LineStyle::Solid,
+        label: None,
+        layer: AnnotationLayer::Foreground,
+        affects_autofit,
+    }
+}
+
+#[test]
+fn test_annotation_bounds_for_autofit() {
+    // Nothing opted in: annotations are ignored by auto-fit
+    let plot = AnnotationPlot::new(vec![hline(150.0, false)]);
+    assert!(plot.get_min_max().is_none());
+    assert!(plot.get_y_range(0.0, 10.0).is_none());
+
+    let plot = AnnotationPlot::new(vec![
+        hline(150.0, true),
+        Annotation::VLine {
+            x: 1_000.0,
+            color: gpui::red(),
+            width: 1.0,
+            style: LineStyle::Solid,
+            label: None,
+            layer: AnnotationLayer::Foreground,
+            affects_autofit: false,
+        },
+        Annotation::Text {
+            x: 20.0,
+            y: -5.0,
+            text: "low".into(),
+            color: gpui::white(),
+            font_size: 12.0,
+            layer: AnnotationLayer::Foreground,
+            affects_autofit: true,
+        },
+    ]);
+
+    let (x_min, x_max, y_min, y_max) = plot.get_min_max().unwrap();
+    assert_eq!((x_min, x_max), (20.0, 20.0));
+    assert_eq!((y_min, y_max), (-5.0, 150.0));
+
+    // HLines span every X window, the text only counts when it is visible
+    assert_eq!(plot.get_y_range(0.0, 10.0), Some((150.0, 150.0)));
+    assert_eq!(plot.get_y_range(0.0, 30.0), Some((-5.0, 150.0)));
+}
+
+#[test]
+fn test_hline_only_bounds_leave_x_unconstrained() {
+    let plot = AnnotationPlot::new(vec![hline(150.0, true)]);
+    let (x_min, x_max, _, y_max) = plot.get_min_max().unwrap();
+    assert_eq!(y_max, 150.0);
+    // Folding with series bounds keeps the series' X extent
+    assert_eq!(0.0f64.min(x_min), 0.0);
+    assert_eq!(100.0f64.max(x_max), 100.0);
+}
+
+#[gpui::test]
+fn test_chart_annotation_management(cx: &mut TestAppContext) {
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        assert!(c.add_annotation(5, hline(1.0, true), cx).is_none());
+
+        let a = c.add_annotation(0, hline(10.0, true), cx).unwrap();
+        let b = c.add_annotation(0, hline(20.0, true), cx).unwrap();
+        assert_ne!(a, b);
+        let series_count = |c: &Chart| {
+            c.panes[0]
+                .series
+                .iter()
+                .filter(|s| s.id == ANNOTATIONS_SERIES_ID)
+                .count()
+        };
+        assert_eq!(series_count(c), 1);
+        let y_range = |c: &Chart| c.panes[0].series[0].plot.read().get_y_range(0.0, 100.0);
+        assert_eq!(y_range(c), Some((10.0, 20.0)));
+
+        assert!(c.update_annotation(a, hline(5.0, true), cx));
+        assert_eq!(c.annotation(a), Some(&hline(5.0, true)));
+        assert_eq!(y_range(c), Some((5.0, 20.0)));
+
+        assert!(c.remove_annotation(b, cx));
+        assert!(!c.remove_annotation(b, cx));
+        assert!(!c.update_annotation(b, hline(0.0, true), cx));
+        assert_eq!(y_range(c), Some((5.0, 5.0)));
+
+        // The series goes away with the last annotation
+        assert!(c.remove_annotation(a, cx));
+        assert_eq!(series_count(c), 0);
+    });
+}
+
+#[test]
+fn test_line_style_dash_pattern() {
+    assert!(LineStyle::default().dash_pattern(2.0).is_empty());
+    assert_eq!(LineStyle::Dashed.dash_pattern(1.0), vec![6.0, 4.0]);
+    // Scaled with the width, never below a 1px line
+    assert_eq!(LineStyle::Dashed.dash_pattern(2.0), vec![12.0, 8.0]);
+    assert_eq!(LineStyle::Dotted.dash_pattern(0.5), vec![1.0, 2.0]);
+}
+
+#[gpui::test]
+fn test_styled_line_annotations_render(cx: &mut TestAppContext) {
+    use gpui_chart::ChartView;
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        for (i, style) in [LineStyle::Solid, LineStyle::Dashed, LineStyle::Dotted]
+            .into_iter()
+            .enumerate()
+        {
+            let label = Some(format!("{style:?}"));
+            c.add_annotation(
+                0,
+                Annotation::VLine {
+                    x: 20.0 * (i + 1) as f64,
+                    color: gpui::red(),
+                    width: 1.5,
+                    style,
+                    label: label.clone(),
+                    layer: AnnotationLayer::Foreground,
+                    affects_autofit: false,
+                },
+                cx,
+            );
+            c.add_annotation(
+                0,
+                Annotation::HLine {
+                    y: 0.2 * (i + 1) as f64,
+                    color: gpui::red(),
+                    width: 1.0,
+                    style,
+                    label,
+                    layer: AnnotationLayer::Foreground,
+                    affects_autofit: true,
+                },
+                cx,
+            );
+        }
+    });
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    cx.run_until_parked();
+}
+
+#[gpui::test]
+fn test_press_on_annotation_drags_it_instead_of_panning(cx: &mut TestAppContext) {
+    use gpui::{px, MouseButton, Point};
+    use gpui_chart::{ChartEvent, ChartView};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let (chart, shared_x) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        (cx.new(|cx| Chart::new(shared_x.clone(), shared_state, cx)), shared_x)
+    });
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events_clone = events.clone();
+    let _sub = cx.update(|cx| {
+        cx.subscribe(&chart, move |_, event: &ChartEvent, _| {
+            events_clone.borrow_mut().push(event.clone());
+        })
+    });
+    let id = chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let vline = Annotation::VLine {
+            x: 50.0,
+            color: gpui::red(),
+            width: 1.0,
+            style: LineStyle::Solid,
+            label: None,
+            layer: AnnotationLayer::Foreground,
+            affects_autofit: false,
+        };
+        c.add_annotation(0, vline, cx).unwrap()
+    });
+    let window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    cx.run_until_parked();
+
+    // Horizontal extent of the pane on its middle row
+    let (left, right, y) = window
+        .update(cx, |view, window, cx| {
+            let size = window.viewport_size();
+            let y = size.height / 2.0;
+            let columns: Vec<f32> = (0..f32::from(size.width) as usize)
+                .map(|x| x as f32 + 0.5)
+                .filter(|&x| view.probe(Point::new(px(x), y), cx).is_some())
+                .collect();
+            (columns[0] - 0.5, columns[columns.len() - 1] + 0.5, y)
+        })
+        .unwrap();
+    let line_x = left + (right - left) / 2.0;
+
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    // Within the tolerance of the line: the annotation moves, the view does not
+    let start = Point::new(px(line_x + 3.0), y);
+    vcx.simulate_mouse_down(start, MouseButton::Left, Default::default());
+    for dx in [10.0, 20.0] {
+        vcx.simulate_mouse_move(
+            Point::new(start.x + px(dx), y),
+            Some(MouseButton::Left),
+            Default::default(),
+        );
+    }
+    vcx.simulate_mouse_up(Point::new(start.x + px(20.0), y), MouseButton::Left, Default::default());
+
+    assert_eq!(
+        *events.borrow(),
+        vec![ChartEvent::AnnotationSelected(id), ChartEvent::AnnotationMoved(id)]
+    );
+    let expected = 50.0 + 20.0 / (right - left) as f64 * 100.0;
+    chart.read_with(&vcx, |c, _| {
+        let Some(Annotation::VLine { x, .. }) = c.annotation(id) else {
+            panic!("annotation removed");
+        };
+        assert!((x - expected).abs() < 0.5, "{x} vs {expected}");
+    });
+    shared_x.read_with(&vcx, |x, _| assert_eq!((x.min, x.max), (0.0, 100.0)));
+
+    // Away from it the press pans as usual
+    events.borrow_mut().clear();
+    let start = Point::new(px(left + 10.0), y);
+    vcx.simulate_mouse_down(start, MouseButton::Left, Default::default());
+    vcx.simulate_mouse_move(
+        Point::new(start.x + px(20.0), y),
+        Some(MouseButton::Left),
+        Default::default(),
+    );
+    vcx.simulate_mouse_up(Point::new(start.x + px(20.0), y), MouseButton::Left, Default::default());
+    assert!(events.borrow().is_empty(), "{:?}", events.borrow());
+    shared_x.read_with(&vcx, |x, _| assert!(x.min < 0.0, "{}", x.min));
+
+    // Without hit-testing the line does not catch the press
+    chart.update(&mut vcx, |c, _| c.set_annotation_hit_tolerance(None));
+    let (min, max) = shared_x.read_with(&vcx, |x, _| (x.min, x.max));
+    let line_x = left + ((expected - min) / (max - min)) as f32 * (right - left);
+    let start = Point::new(px(line_x), y);
+    vcx.simulate_mouse_down(start, MouseButton::Left, Default::default());
+    vcx.simulate_mouse_move(
+        Point::new(start.x + px(20.0), y),
+        Some(MouseButton::Left),
+        Default::default(),
+    );
+    vcx.simulate_mouse_up(Point::new(start.x + px(20.0), y), MouseButton::Left, Default::default());
+    assert!(events.borrow().is_empty());
+    shared_x.read_with(&vcx, |x, _| assert!(x.min < min));
+}
+
+#[gpui::test]
+fn test_price_level(cx: &mut TestAppContext) {
+    use gpui_chart::ChartView;
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let level = chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        assert!(c.add_price_level(3, 150.0, gpui::red(), cx).is_none());
+        let level = c.add_price_level(0, 50.0, gpui::red(), cx).unwrap();
+        assert_eq!(c.panes[0].price_levels, vec![level]);
+        // A plain line that does not pull auto-fit towards it
+        match c.annotation(level) {
+            Some(Annotation::HLine { y, affects_autofit, .. }) => {
+                assert_eq!((*y, *affects_autofit), (50.0, false));
+            }
+            other => panic!("{other:?}"),
+        }
+        level
+    });
+
+    // Rendered with its tag, in and out of view
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    cx.run_until_parked();
+    chart.update(cx, |c, cx| {
+        let y = c.panes[0].y_axes[0].entity.clone();
+        y.update(cx, |r, _| {
+            r.min = 200.0;
+            r.max = 300.0;
+        });
+        cx.notify();
+    });
+    cx.run_until_parked();
+
+    // Hidden with the annotations series
+    chart.update(cx, |c, cx| {
+        let id = gpui_chart::chart::ANNOTATIONS_SERIES_ID.to_string();
+        c.panes[0].hidden_series.insert(id);
+        cx.notify();
+    });
+    cx.run_until_parked();
+
+    chart.update(cx, |c, cx| {
+        assert!(c.remove_annotation(level, cx));
+        assert!(c.panes[0].price_levels.is_empty());
+    });
+}
+
+#[gpui::test]
+fn test_clear_series_removes_annotations_and_price_levels(cx: &mut TestAppContext) {
+    use gpui::{point, px, size, Bounds};
+    use gpui_chart::chart_view::input::ChartInputHandler;
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let line = hline(50.0, false);
+        let id = c.add_annotation(0, line, cx).unwrap();
+        let level = c.add_price_level(0, 20.0, gpui::red(), cx).unwrap();
+        c.clear_series(cx);
+
+        let pane = &c.panes[0];
+        assert!(pane.series.is_empty());
+        assert!(pane.annotations.is_empty() && pane.price_levels.is_empty());
+        assert!(c.annotation(id).is_none() && c.annotation(level).is_none());
+        let bounds = Bounds::new(point(px(0.0), px(0.0)), size(px(100.0), px(100.0)));
+        let hit = ChartInputHandler::annotation_under_cursor(
+            pane,
+            (0.0, 100.0),
+            &[(0.0, 100.0)],
+            bounds,
+            point(px(50.0), px(50.0)),
+            6.0,
+            None,
+        );
+        assert_eq!(hit, None);
+    });
+}
@@ -1,4 +1,6 @@
-use gpui_chart::data_types::{AxisFormat, AxisRange};
+use gpui_chart::data_types::{
+    percent_of_range_ticks, visible_tick_labels, AxisFormat, AxisRange, TickCount,
+};
 use gpui_chart::scales::ChartScale;
 
 #[test]
@@ -57,6 +59,26 @@ fn test_axis_range_clamp() {
     assert_eq!(range.min, 150.0); // Span preserved
 }
 
+#[test]
+fn test_axis_range_clamp_disabled_keeps_limits() {
+    let mut range = AxisRange::new(100.0, 200.0);
+    range.min_limit = Some(50.0);
+    range.max_limit = Some(250.0);
+    range.clamp_enabled = false;
+
+    // Panning past the max limit is allowed and rendered as-is
+    range.pan(100.0); // [200, 300]
+    range.clamp();
+    assert_eq!((range.min, range.max), (200.0, 300.0));
+    assert_eq!(range.clamped_bounds(), (200.0, 300.0));
+    assert_eq!(range.max_limit, Some(250.0));
+
+    // Re-enabling brings the limits back into effect
+    range.clamp_enabled = true;
+    range.clamp();
+    assert_eq!((range.min, range.max), (150.0, 250.0));
+}
+
 #[test]
 fn test_axis_range_zoom_pivot_with_clamping() {
     let mut range = AxisRange::new(100.0, 200.0);
@@ -96,7 +118,7 @@ fn test_chart_scale_formatting() {
     let ts = 1736500000000.0; // Sometime in 2025
     let formatted = scale.format_tick(
         ts,
-        &AxisFormat::Time(gpui_chart::data_types::TimeUnit::Milliseconds),
+        &AxisFormat::Time(gpui_chart::data_types::TimeUnit::Milliseconds, None),
     );
     assert!(
         formatted.contains(":"),
@@ -109,3 +131,212 @@ fn test_chart_scale_formatting() {
     assert_eq!(scale.format_tick(123.456, &AxisFormat::Numeric), "123.46");
     assert_eq!(scale.format_tick(1234.56, &AxisFormat::Numeric), "1235");
 }
+
+#[test]
+fn test_tick_count_resolve() {
+    assert_eq!(TickCount::Fixed(7).resolve(1000.0, &AxisFormat::Numeric, 20.0), 7);
+    assert_eq!(TickCount::Fixed(0).resolve(1000.0, &AxisFormat::Numeric, 20.0), 1);
+
+    // Auto: one tick per estimated label footprint, clamped to 2..=20
+    assert_eq!(TickCount::Auto.resolve(700.0, &AxisFormat::Numeric, 20.0), 10);
+    assert_eq!(TickCount::Auto.resolve(50.0, &AxisFormat::Numeric, 20.0), 2);
+    assert_eq!(TickCount::Auto.resolve(10_000.0, &AxisFormat::Numeric, 20.0), 20);
+    // Wider labels and spacing mean fewer ticks
+    let time = AxisFormat::Time(gpui_chart::data_types::TimeUnit::Seconds, None);
+    assert!(
+        TickCount::Auto.resolve(700.0, &time, 40.0)
+            < TickCount::Auto.resolve(700.0, &AxisFormat::Numeric, 20.0)
+    );
+}
+
+#[test]
+fn test_gap_ticks_align_with_gap_aware_scale() {
+    use gpui_chart::gaps::{ExclusionRule, GapIndexBuilder};
+    use std::sync::Arc;
+
+    let mut builder = GapIndexBuilder::new();
+    builder.add_rule(ExclusionRule::Fixed {
+        start: 40_000,
+        end: 60_000,
+    });
+    let gaps = Arc::new(builder.build(0, 100_000));
+    let mut range = AxisRange::new(0.0, 100_000.0);
+
+    // Ticks cached without gaps are not reused once gaps apply
+    let plain = range.ticks(10, None).to_vec();
+    assert!(plain.iter().any(|&t| gaps.is_inside(t as i64)));
+    let ticks = range.ticks(10, Some(&gaps)).to_vec();
+    assert_ne!(ticks, plain);
+    assert!(ticks.iter().all(|&t| !gaps.is_inside(t as i64)), "{ticks:?}");
+
+    // Grid lines and labels both map the ticks through the gap-aware scale:
+    // they land on evenly spaced logical positions, not real ones
+    let scale =
+        ChartScale::new_linear((0.0, 100_000.0), (0.0, 800.0)).with_gaps(Some(gaps.clone()));
+    let pixels: Vec<f32> = ticks.iter().map(|&t| scale.map(t)).collect();
+    for (t, p) in ticks.iter().zip(&pixels) {
+        let logical = gaps.to_logical(*t as i64) as f32;
+        assert!((p - logical * 800.0 / 80_000.0).abs() < 0.01, "{t}: {p}");
+    }
+    assert!(pixels.windows(2).all(|w| w[1] > w[0]), "{pixels:?}");
+    let steps: Vec<f32> = pixels.windows(2).map(|w| w[1] - w[0]).collect();
+    assert!(steps.iter().all(|s| (s - steps[0]).abs() < 0.01), "{steps:?}");
+}
+
+#[test]
+fn test_ticks_recomputed_when_count_changes() {
+    let mut range = AxisRange::new(0.0, 100.0);
+    let many = range.ticks(20, None).len();
+    let few = range.ticks(2, None).len();
+    assert!(few < many, "cached ticks must follow the requested count");
+
+    // Both densities stay cached, e.g. an overview and a main axis on one range
+    assert_eq!(range.cached_ticks.len(), 2);
+    let cached = range.cached_ticks.clone();
+    assert_eq!(range.ticks(20, None).len(), many);
+    assert_eq!(range.cached_ticks, cached);
+
+    // Panning refreshes the entry of the count asked for
+    range.min = 50.0;
+    range.max = 150.0;
+    assert_eq!(range.ticks(2, None).first(), Some(&50.0));
+    assert_eq!(range.cached_ticks.len(), 2);
+}
+
+#[test]
+fn test_tick_labels_all_visible_when_spaced() {
+    let ticks = [0.0, 10.0, 20.0, 30.0];
+    let px = [0.0, 100.0, 200.0, 300.0];
+    let extents = [30.0; 4];
+    assert_eq!(visible_tick_labels(&ticks, &px, &extents, 20.0), vec![true; 4]);
+}
+
+#[test]
+fn test_crowded_tick_labels_keep_nice_values() {
+    // 10px apart, labels need 30px + 10px spacing -> every 5th tick
+    let ticks: Vec<f64> = (0..=20).map(|i| i as f64).collect();
+    let px: Vec<f32> = (0..=20).map(|i| i as f32 * 10.0).collect();
+    let extents = vec![30.0; ticks.len()];
+    let visible = visible_tick_labels(&ticks, &px, &extents, 10.0);
+
+    let shown: Vec<f64> = ticks
+        .iter()
+        .zip(&visible)
+        .filter(|(_, v)| **v)
+        .map(|(t, _)| *t)
+        .collect();
+    assert_eq!(shown, vec![0.0, 5.0, 10.0, 15.0, 20.0]);
+}
+
+#[test]
+fn test_tick_label_selection_stable_while_panning() {
+    let extents = vec![30.0; 10];
+    let px: Vec<f32> = (0..10).map(|i| i as f32 * 10.0).collect();
+
+    let a: Vec<f64> = (3..13).map(|i| i as f64).collect();
+    let b: Vec<f64> = (4..14).map(|i| i as f64).collect();
+    let shown = |ticks: &[f64]| -> Vec<f64> {
+        ticks
+            .iter()
+            .zip(visible_tick_labels(ticks, &px, &extents, 10.0))
+            .filter(|(_, v)| *v)
+            .map(|(t, _)| *t)
+            .collect()
+    };
+    // The same values keep their labels after the tick window shifts
+    assert_eq!(shown(&a), vec![5.0, 10.0]);
+    assert_eq!(shown(&b), vec![5.0, 10.0]);
+}
+
+#[test]
+fn test_band_scale_layout() {
+    // 4 bands over 440px with 10% padding: step = 440 / 4.1
+    let scale = ChartScale::new_band(4, (0.0, 440.0), 0.1);
+    let step = 440.0 / 4.1;
+    let bw = scale.bandwidth().unwrap();
+    assert!((bw - step * 0.9).abs() < 1e-3);
+
+    // Bands are centered with equal outer margins
+    let first = scale.map(0.0);
+    let last = scale.map(3.0);
+    assert!((first - (440.0 - last)).abs() < 1e-3);
+    assert!((scale.map(1.0) - first - step).abs() < 1e-3);
+
+    // Pixel -> index for hit-testing
+    assert_eq!(scale.band_index(first), Some(0));
+    assert_eq!(scale.band_index(last + bw / 2.0), Some(3));
+    assert_eq!(scale.band_index(-50.0), None);
+    assert!((scale.invert(scale.map(2.0)) - 2.0).abs() < 1e-6);
+
+    assert_eq!(scale.domain(), (-0.5, 3.5));
+    assert!(ChartScale::new_linear((0.0, 1.0), (0.0, 1.0)).bandwidth().is_none());
+}
+
+#[test]
+fn test_percent_of_range_labels() {
+    let ticks = percent_of_range_ticks((50.0, 250.0), 5);
+    assert_eq!(ticks, vec![50.0, 90.0, 130.0, 170.0, 210.0, 250.0]);
+    assert!(percent_of_range_ticks((10.0, 10.0), 5).is_empty());
+
+    let scale = ChartScale::new_linear((50.0, 250.0), (100.0, 0.0));
+    let labels: Vec<String> = ticks
+        .iter()
+        .map(|t| scale.format_tick(*t, &AxisFormat::PercentOfRange))
+        .collect();
+    assert_eq!(labels, ["0%", "20%", "40%", "60%", "80%", "100%"]);
+    assert_eq!(scale.format_tick(55.0, &AxisFormat::PercentOfRange), "2.5%");
+}
+
+#[test]
+fn test_set_limits_moves_view_inside() {
+    let mut range = AxisRange::new(-50.0, 50.0);
+    range.set_limits(Some(0.0), None);
+    assert_eq!((range.min, range.max), (0.0, 100.0));
+    // Reversed limits are put back in order
+    range.set_limits(Some(80.0), Some(10.0));
+    assert_eq!((range.min_limit, range.max_limit), (Some(10.0), Some(80.0)));
+    range.set_limits(None, None);
+    range.pan(-500.0);
+    assert!(range.min < -400.0);
+}
+
+#[gpui::test]
+fn test_chart_axis_limits(cx: &mut gpui::TestAppContext) {
+    use gpui::AppContext;
+    use gpui_chart::data_types::SharedPlotState;
+    use gpui_chart::view_controller::ViewController;
+    use gpui_chart::Chart;
+
+    let (chart, shared_x, own_x) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(-10.0, 90.0));
+        let own_x = cx.new(|_| AxisRange::new(500.0, 700.0));
+        let state = cx.new(|_| SharedPlotState::default());
+        let chart = cx.new(|cx| Chart::new(shared_x.clone(), state, cx));
+        (chart, shared_x, own_x)
+    });
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.add_pane_at(1, 1.0, cx);
+        c.set_pane_x_axis(1, Some(own_x.clone()), cx);
+        c.set_x_limits(Some(0.0), Some(600.0), cx);
+        c.set_y_limits(0, 0, Some(0.0), None, cx);
+        c.set_y_limits(0, 7, Some(0.0), None, cx);
+    });
+    shared_x.read_with(cx, |x, _| assert_eq!((x.min, x.max), (0.0, 100.0)));
+    own_x.read_with(cx, |x, _| assert_eq!((x.min, x.max), (400.0, 600.0)));
+    chart.read_with(cx, |c, cx| {
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert_eq!(y.min_limit, Some(0.0));
+        assert!(y.min >= 0.0);
+    });
+
+    // Pans stop at the limits until they are cleared
+    shared_x.update(cx, |x, _| ViewController::pan_axis(x, 500.0, 100.0, false, None));
+    shared_x.read_with(cx, |x, _| assert_eq!(x.min, 0.0));
+    chart.update(cx, |c, cx| c.set_x_limits(None, None, cx));
+    shared_x.update(cx, |x, _| ViewController::pan_axis(x, 500.0, 100.0, false, None));
+    shared_x.read_with(cx, |x, _| {
+        assert_eq!((x.min_limit, x.max_limit), (None, None));
+        assert!(x.min < 0.0, "{}", x.min);
+    });
+}
@@ -0,0 +1,93 @@
+use gpui_chart::data_types::{
+    ColorOp, PlotData, PlotDataSource, PlotPoint, StreamingDataSource, VecDataSource,
+};
+use gpui_chart::decimation::{decimate_m4_slice, decimate_min_max_slice};
+
+fn pt(x: f64, y: f64, color_op: ColorOp) -> PlotData {
+    PlotData::Point(PlotPoint { x, y, color_op })
+}
+
+/// Sine-like data with a single colored point that is never an extremum of its bucket.
+fn data_with_op(op: ColorOp, at: usize) -> Vec<PlotData> {
+    (0..10_000)
+        .map(|i| {
+            let y = ((i % 100) as f64 - 50.0).abs();
+            pt(i as f64, y, if i == at { op } else { ColorOp::None })
+        })
+        .collect()
+}
+
+fn ops(data: &[PlotData]) -> Vec<ColorOp> {
+    data.iter()
+        .map(|p| p.color_op())
+        .filter(|op| *op != ColorOp::None)
+        .collect()
+}
+
+#[test]
+fn test_apply_semantics() {
+    let red = gpui::red();
+    let green = gpui::green();
+    let mut current = None;
+
+    assert_eq!(ColorOp::None.apply(&mut current), None);
+    assert_eq!(ColorOp::Persistent(red).apply(&mut current), Some(red));
+    assert_eq!(ColorOp::None.apply(&mut current), Some(red));
+    // One-shot colors a single segment and keeps the persistent color
+    assert_eq!(ColorOp::OneShot(green).apply(&mut current), Some(green));
+    assert_eq!(ColorOp::None.apply(&mut current), Some(red));
+    assert_eq!(ColorOp::Reset.apply(&mut current), None);
+    assert_eq!(ColorOp::None.apply(&mut current), None);
+}
+
+#[test]
+fn test_decimation_keeps_color_op() {
+    let red = gpui::red();
+    for op in [ColorOp::Persistent(red), ColorOp::OneShot(red), ColorOp::Reset] {
+        let data = data_with_op(op, 4_321);
+        assert_eq!(ops(&decimate_min_max_slice(&data, 200, None, None)), vec![op]);
+        assert_eq!(ops(&decimate_m4_slice(&data, 200, None, None)), vec![op]);
+    }
+}
+
+#[test]
+fn test_persistent_change_wins_in_bucket() {
+    let (red, green) = (gpui::red(), gpui::green());
+    let mut data = data_with_op(ColorOp::OneShot(green), 4_320);
+    data[4_321] = pt(4_321.0, 1.0, ColorOp::Persistent(red));
+    let out = decimate_min_max_slice(&data, 200, None, None);
+    assert_eq!(ops(&out), vec![ColorOp::Persistent(red)]);
+}
+
+#[test]
+fn test_lod_pyramid_keeps_color_op() {
+    let red = gpui::red();
+    let source = VecDataSource::new(data_with_op(ColorOp::Persistent(red), 4_321));
+    let mut out = Vec::new();
+    source.get_aggregated_data(0.0, 10_000.0, 100, &mut out, None);
+    assert!(out.len() <= 200);
+    assert_eq!(ops(&out), vec![ColorOp::Persistent(red)]);
+}
+
+#[test]
+fn test_persistent_color_before() {
+    let red = gpui::red();
+    let mut data = data_with_op(ColorOp::Persistent(red), 100);
+    data[500] = pt(500.0, 0.0, ColorOp::Reset);
+    let source = VecDataSource::new(data.clone());
+    assert_eq!(source.persistent_color_before(50.0), None);
+    assert_eq!(source.persistent_color_before(101.0), Some(red));
+    assert_eq!(source.persistent_color_before(400.0), Some(red));
+    assert_eq!(source.persistent_color_before(600.0), None);
+
+    let mut streaming = StreamingDataSource::new(20_000);
+    for p in data {
+        streaming.add_data(p);
+    }
+    assert_eq!(streaming.persistent_color_before(400.0), Some(red));
+    assert_eq!(streaming.persistent_color_before(600.0), None);
+
+    // Sources without color ops skip the scan
+    let plain = VecDataSource::new(vec![pt(0.0, 0.0, ColorOp::None), pt(1.0, 1.0, ColorOp::None)]);
+    assert_eq!(plain.persistent_color_before(10.0), None);
+}
@@ -0,0 +1,62 @@
+use gpui::{Hsla, Rgba};
+use gpui_chart::data_types::{ColorMap, ColorOp, LinePlotConfig, PlotPoint};
+use gpui_chart::LinePlot;
+
+fn rgb(c: Hsla) -> (f32, f32, f32) {
+    let c = Rgba::from(c);
+    (c.r, c.g, c.b)
+}
+
+fn assert_close(a: (f32, f32, f32), b: (f32, f32, f32)) {
+    let d = (a.0 - b.0).abs() + (a.1 - b.1).abs() + (a.2 - b.2).abs();
+    assert!(d < 1e-3, "{a:?} != {b:?}");
+}
+
+#[test]
+fn test_colormap_ends_and_midpoint() {
+    let map = ColorMap::blue_red();
+    assert_close(rgb(map.color_at(0.0)), rgb(gpui::blue()));
+    assert_close(rgb(map.color_at(1.0)), rgb(gpui::red()));
+
+    // Out-of-range positions clamp to the end stops
+    assert_close(rgb(map.color_at(-3.0)), rgb(gpui::blue()));
+    assert_close(rgb(map.color_at(7.0)), rgb(gpui::red()));
+
+    // Interpolated in RGB: halfway between blue and red, with no green
+    let (b, r) = (Rgba::from(gpui::blue()), Rgba::from(gpui::red()));
+    let mid = rgb(map.color_at(0.5));
+    assert_close(mid, ((b.r + r.r) / 2.0, (b.g + r.g) / 2.0, (b.b + r.b) / 2.0));
+}
+
+#[test]
+fn test_colormap_unsorted_stops() {
+    let map = ColorMap::new(vec![(1.0, gpui::red()), (0.0, gpui::blue()), (0.5, gpui::green())]);
+    assert_eq!(map.stops()[0].0, 0.0);
+    assert_close(rgb(map.color_at(0.5)), rgb(gpui::green()));
+    let (g, r) = (Rgba::from(gpui::green()), Rgba::from(gpui::red()));
+    let q = rgb(map.color_at(0.75));
+    assert_close(q, ((g.r + r.r) / 2.0, (g.g + r.g) / 2.0, (g.b + r.b) / 2.0));
+}
+
+#[test]
+fn test_colormap_position() {
+    assert_eq!(LinePlotConfig::colormap_position(5.0, (0.0, 10.0)), 0.5);
+    assert_eq!(LinePlotConfig::colormap_position(-5.0, (0.0, 10.0)), 0.0);
+    assert_eq!(LinePlotConfig::colormap_position(50.0, (0.0, 10.0)), 1.0);
+    // Inverted ranges (e.g. screen space) map the same way
+    assert_eq!(LinePlotConfig::colormap_position(2.5, (10.0, 0.0)), 0.75);
+    // Degenerate range
+    assert_eq!(LinePlotConfig::colormap_position(3.0, (3.0, 3.0)), 0.5);
+}
+
+#[test]
+fn test_line_with_colormap() {
+    let line = LinePlot::new(vec![PlotPoint {
+        x: 0.0,
+        y: 1.0,
+        color_op: ColorOp::None,
+    }])
+    .with_colormap(ColorMap::blue_red(), Some((0.0, 2.0)));
+    assert!(line.config.colormap.is_some());
+    assert_eq!(line.config.colormap_range, Some((0.0, 2.0)));
+}
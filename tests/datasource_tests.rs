@@ -58,3 +58,258 @@ fn test_streaming_cache_rebuild() {
     assert_eq!(bounds.0, 0.0);
     assert_eq!(bounds.1, 599.0);
 }
+
+#[test]
+fn test_step_x() {
+    let data = (0..10)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64 * 2.0,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect::<Vec<_>>();
+    let vec_source = VecDataSource::new(data.clone());
+    let mut streaming = StreamingDataSource::new(100);
+    streaming.set_data(data);
+
+    let sources: [&dyn PlotDataSource; 2] = [&vec_source, &streaming];
+    for source in sources {
+        assert_eq!(source.step_x(4.0, 1), Some(6.0));
+        assert_eq!(source.step_x(5.0, 1), Some(6.0));
+        assert_eq!(source.step_x(4.0, -1), Some(2.0));
+        assert_eq!(source.step_x(4.0, 3), Some(10.0));
+        assert_eq!(source.step_x(4.0, -3), Some(0.0), "stops at the first point");
+        assert_eq!(source.step_x(16.0, 5), Some(18.0), "stops at the last point");
+        assert_eq!(source.step_x(18.0, 1), None);
+        assert_eq!(source.step_x(0.0, -1), None);
+        assert_eq!(source.step_x(4.0, 0), None);
+    }
+}
+
+#[test]
+fn test_nearest_point() {
+    let data: Vec<PlotData> = (0..10)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64 * 2.0,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+    let vec_source = VecDataSource::new(data.clone());
+    // `iter_range` of a streaming source has no padding points around the range
+    let mut streaming = StreamingDataSource::new(100);
+    streaming.set_data(data);
+
+    let sources: [&dyn PlotDataSource; 2] = [&vec_source, &streaming];
+    for source in sources {
+        let nearest_x = |x: f64| source.nearest_point(x).map(|p| p.x());
+        assert_eq!(nearest_x(4.0), Some(4.0));
+        assert_eq!(nearest_x(4.9), Some(4.0));
+        assert_eq!(nearest_x(5.0), Some(4.0), "the earlier point on a tie");
+        assert_eq!(nearest_x(5.1), Some(6.0));
+        assert_eq!(nearest_x(-7.0), Some(0.0));
+        assert_eq!(nearest_x(100.0), Some(18.0));
+        assert_eq!(source.nearest_point(7.0).map(|p| p.y()), Some(3.0));
+    }
+    assert!(StreamingDataSource::new(10).nearest_point(1.0).is_none());
+}
+
+/// Deterministic pseudo-random sequence (LCG) for the streaming property tests.
+fn lcg(seed: &mut u64) -> f64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ((*seed >> 11) as f64 / (1u64 << 53) as f64) * 200.0 - 100.0
+}
+
+fn brute_force_y_range(points: &[(f64, f64)], x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+    points
+        .iter()
+        .filter(|(x, _)| *x >= x_min && *x <= x_max)
+        .fold(None, |acc, &(_, y)| match acc {
+            None => Some((y, y)),
+            Some((lo, hi)) => Some((f64::min(lo, y), f64::max(hi, y))),
+        })
+}
+
+#[test]
+fn test_streaming_y_range_matches_brute_force_after_eviction() {
+    for (capacity, total) in [(1, 10), (100, 1_000), (700, 3_000), (1_500, 5_000), (2_048, 2_100)] {
+        let mut seed = capacity as u64;
+        let mut source = StreamingDataSource::new(capacity);
+        let mut points = std::collections::VecDeque::new();
+
+        for i in 0..total {
+            let y = lcg(&mut seed);
+            source.add_data(PlotData::Point(PlotPoint {
+                x: i as f64,
+                y,
+                color_op: ColorOp::None,
+            }));
+            points.push_back((i as f64, y));
+            if points.len() > capacity {
+                points.pop_front();
+            }
+
+            // Check a few windows regularly, including partial chunks at both ends
+            if i % 97 == 0 || i == total - 1 {
+                let live: Vec<(f64, f64)> = points.iter().copied().collect();
+                let first = live[0].0;
+                let last = live[live.len() - 1].0;
+                let windows = [
+                    (first, last),
+                    (first + 3.0, last - 3.0),
+                    (first - 10.0, first + 10.0),
+                    (last - 600.0, last + 1.0),
+                    ((first + last) / 2.0, (first + last) / 2.0 + 1.0),
+                ];
+                for (lo, hi) in windows {
+                    assert_eq!(
+                        source.get_y_range(lo, hi),
+                        brute_force_y_range(&live, lo, hi),
+                        "capacity {capacity}, after {i} points, window {lo}..{hi}"
+                    );
+                }
+                let (b_x_min, b_x_max, b_y_min, b_y_max) = source.get_bounds().unwrap();
+                let (y_min, y_max) = brute_force_y_range(&live, first, last).unwrap();
+                assert_eq!((b_x_min, b_x_max, b_y_min, b_y_max), (first, last, y_min, y_max));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_vec_bounds_cache_tracks_set_and_add() {
+    let point = |x: f64, y: f64| {
+        PlotData::Point(PlotPoint {
+            x,
+            y,
+            color_op: ColorOp::None,
+        })
+    };
+
+    let mut source = VecDataSource::new(vec![]);
+    assert_eq!(source.get_bounds(), None);
+
+    source.set_data((0..10).map(|i| point(i as f64, i as f64)).collect());
+    assert_eq!(source.get_bounds(), Some((0.0, 9.0, 0.0, 9.0)));
+
+    // Appends inside the current chunk update the cached bounds immediately
+    source.add_data(point(10.0, -5.0));
+    source.add_data(point(11.0, 42.0));
+    assert_eq!(source.get_bounds(), Some((0.0, 11.0, -5.0, 42.0)));
+
+    source.set_data(vec![point(3.0, 1.0)]);
+    assert_eq!(source.get_bounds(), Some((3.0, 3.0, 1.0, 1.0)));
+}
+
+fn points(xs: &[f64]) -> Vec<PlotData> {
+    xs.iter()
+        .map(|&x| {
+            PlotData::Point(PlotPoint {
+                x,
+                y: x * 10.0,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_descending_data_is_reversed() {
+    let newest_first = points(&[4.0, 3.0, 2.0, 1.0, 0.0]);
+
+    let source = VecDataSource::new(newest_first.clone());
+    let xs: Vec<f64> = source.iter_range(1.0, 3.0).map(|p| p.x()).collect();
+    assert_eq!(xs, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(source.count_in_range(1.0, 3.0), 3);
+
+    let mut streaming = StreamingDataSource::new(10);
+    streaming.set_data(newest_first);
+    assert_eq!(streaming.count_in_range(1.0, 3.0), 3);
+    assert_eq!(streaming.get_bounds().map(|b| (b.0, b.1)), Some((0.0, 4.0)));
+}
+
+#[test]
+fn test_sort_on_set() {
+    let shuffled = points(&[2.0, 0.0, 4.0, 1.0, 3.0]);
+
+    let mut source = VecDataSource::new_sorted(shuffled.clone());
+    assert_eq!(source.count_in_range(1.0, 3.0), 3);
+    source.set_data(points(&[9.0, 5.0, 7.0]));
+    let xs: Vec<f64> = source.iter_range(0.0, 10.0).map(|p| p.x()).collect();
+    assert_eq!(xs, vec![5.0, 7.0, 9.0]);
+
+    let mut streaming = StreamingDataSource::new(10).with_sort_on_set(true);
+    streaming.set_data(shuffled);
+    assert_eq!(streaming.count_in_range(1.0, 3.0), 3);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not sorted by X")]
+fn test_unsorted_data_fails_loudly() {
+    VecDataSource::new(points(&[2.0, 0.0, 4.0]));
+}
+
+#[test]
+fn test_clear_empties_data_and_caches() {
+    let data: Vec<PlotData> = (0..5_000)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: (i % 7) as f64,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+    let mut vec_source = VecDataSource::new(data.clone());
+    let mut streaming = StreamingDataSource::new(10_000);
+    streaming.set_data(data);
+
+    let sources: [&mut dyn PlotDataSource; 2] = [&mut vec_source, &mut streaming];
+    for source in sources {
+        source.clear();
+        assert!(source.is_empty());
+        assert_eq!(source.get_bounds(), None);
+        assert_eq!(source.get_y_range(0.0, 5_000.0), None);
+        let mut out = Vec::new();
+        source.get_aggregated_data(0.0, 5_000.0, 100, &mut out, None);
+        assert!(out.is_empty(), "stale LOD data: {}", out.len());
+
+        // Usable again afterwards
+        source.add_data(PlotData::Point(PlotPoint {
+            x: 1.0,
+            y: 2.0,
+            color_op: ColorOp::None,
+        }));
+        assert_eq!(source.get_bounds(), Some((1.0, 1.0, 2.0, 2.0)));
+    }
+}
+
+#[test]
+fn test_version_changes_with_data() {
+    let point = |x: f64| {
+        PlotData::Point(PlotPoint {
+            x,
+            y: 0.0,
+            color_op: ColorOp::None,
+        })
+    };
+    let mut vec_source = VecDataSource::new(vec![point(0.0)]);
+    // A full ring buffer keeps its length while its data changes
+    let mut streaming = StreamingDataSource::new(1);
+    streaming.add_data(point(0.0));
+
+    let sources: [&mut dyn PlotDataSource; 2] = [&mut vec_source, &mut streaming];
+    for source in sources {
+        let version = source.version();
+        source.add_data(point(1.0));
+        assert!(source.version() > version);
+        let version = source.version();
+        source.set_data(vec![point(2.0)]);
+        assert!(source.version() > version);
+    }
+}
@@ -0,0 +1,280 @@
+use gpui::{point, px, size, AppContext, Bounds, TestAppContext};
+use gpui_chart::chart_view::input::ChartInputHandler;
+use gpui_chart::data_types::{AxisRange, ColorOp, EmphasisConfig, PlotPoint, SharedPlotState};
+use gpui_chart::{Chart, ChartEvent, LinePlot, PaneState, Series};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn flat_line(y: f64) -> LinePlot {
+    LinePlot::new(
+        (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y,
+                color_op: ColorOp::None,
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_emphasis_disabled_by_default() {
+    let mut state = SharedPlotState::default();
+    state.hovered_series = Some("a".into());
+    let e = state.series_emphasis("b");
+    assert_eq!(e.opacity, 1.0);
+    assert_eq!(e.width_scale, 1.0);
+}
+
+#[test]
+fn test_emphasis_hovered_and_dimmed() {
+    let mut state = SharedPlotState::default();
+    state.emphasis = EmphasisConfig {
+        enabled: true,
+        ..Default::default()
+    };
+    state.hovered_series = Some("a".into());
+
+    let hovered = state.series_emphasis("a");
+    assert_eq!(hovered.width_scale, state.emphasis.width_scale);
+    assert_eq!(hovered.opacity, 1.0);
+
+    let other = state.series_emphasis("b");
+    assert_eq!(other.width_scale, 1.0);
+    assert_eq!(other.opacity, state.emphasis.dim_opacity);
+
+    state.hovered_series = None;
+    assert_eq!(state.series_emphasis("b").opacity, 1.0);
+}
+
+#[test]
+fn test_series_under_cursor_picks_nearest() {
+    let mut pane = PaneState::new("p".into(), 1.0);
+    pane.series.push(Series::new("low", flat_line(20.0)));
+    pane.series.push(Series::new("high", flat_line(80.0)));
+
+    // 100x100 px pane, Y domain 0..100 -> y=80 is at 20px from the top.
+    let bounds = Bounds::new(point(px(0.0), px(0.0)), size(px(100.0), px(100.0)));
+    let hit = ChartInputHandler::series_under_cursor(
+        &pane,
+        &[(0.0, 100.0)],
+        &[(0.0, 100.0)],
+        bounds,
+        point(px(50.0), px(22.0)),
+        6.0,
+        None,
+    );
+    assert_eq!(hit.as_deref(), Some("high"));
+
+    let miss = ChartInputHandler::series_under_cursor(
+        &pane,
+        &[(0.0, 100.0)],
+        &[(0.0, 100.0)],
+        bounds,
+        point(px(50.0), px(50.0)),
+        6.0,
+        None,
+    );
+    assert!(miss.is_none());
+
+    pane.hidden_series.insert("high".into());
+    let hidden = ChartInputHandler::series_under_cursor(
+        &pane,
+        &[(0.0, 100.0)],
+        &[(0.0, 100.0)],
+        bounds,
+        point(px(50.0), px(22.0)),
+        6.0,
+        None,
+    );
+    assert!(hidden.is_none());
+}
+
+#[test]
+fn test_series_under_cursor_uses_series_x_axis() {
+    use gpui_chart::data_types::AxisId;
+
+    // "late" lives on a second X axis showing 100..200
+    let late = LinePlot::new(
+        (100..=200)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: 80.0,
+                color_op: ColorOp::None,
+            })
+            .collect(),
+    );
+    let mut pane = PaneState::new("p".into(), 1.0);
+    let mut series = Series::new("late", late);
+    series.x_axis_id = AxisId(1);
+    pane.series.push(series);
+
+    let bounds = Bounds::new(point(px(0.0), px(0.0)), size(px(100.0), px(100.0)));
+    let hit = |x_domains: &[(f64, f64)]| {
+        ChartInputHandler::series_under_cursor(
+            &pane,
+            x_domains,
+            &[(0.0, 100.0)],
+            bounds,
+            point(px(50.0), px(22.0)),
+            6.0,
+            None,
+        )
+    };
+    assert_eq!(hit(&[(0.0, 100.0), (100.0, 200.0)]).as_deref(), Some("late"));
+    // Its data is outside the first axis, and an unknown axis is never hit
+    assert!(hit(&[(0.0, 100.0), (0.0, 100.0)]).is_none());
+    assert!(hit(&[(100.0, 200.0)]).is_none());
+}
+
+#[test]
+fn test_selected_series_stays_emphasized() {
+    let mut state = SharedPlotState::default();
+    state.emphasis.enabled = true;
+    state.selected_series = Some("a".into());
+
+    assert_eq!(state.series_emphasis("a").width_scale, state.emphasis.width_scale);
+    assert_eq!(state.series_emphasis("b").opacity, state.emphasis.dim_opacity);
+
+    // Hovering another series emphasizes it alongside the selection
+    state.hovered_series = Some("b".into());
+    assert_eq!(state.series_emphasis("a").opacity, 1.0);
+    assert_eq!(state.series_emphasis("b").opacity, 1.0);
+    assert_eq!(state.series_emphasis("c").opacity, state.emphasis.dim_opacity);
+}
+
+#[test]
+fn test_series_opacity_combines_with_dimming() {
+    let mut state = SharedPlotState::default();
+    state.series_opacity.insert("bg".into(), 0.5);
+
+    // Without emphasis only the series' own opacity applies
+    assert_eq!(state.series_emphasis("bg").opacity, 0.5);
+    assert_eq!(state.series_emphasis("fg").opacity, 1.0);
+
+    state.emphasis.enabled = true;
+    state.hovered_series = Some("fg".into());
+    assert_eq!(
+        state.series_emphasis("bg").opacity,
+        0.5 * state.emphasis.dim_opacity
+    );
+
+    state.hovered_series = Some("bg".into());
+    let hovered = state.series_emphasis("bg");
+    assert_eq!(hovered.opacity, 0.5);
+    assert_eq!(hovered.width_scale, state.emphasis.width_scale);
+}
+
+#[test]
+fn test_series_with_opacity_is_clamped() {
+    assert_eq!(Series::new("a", flat_line(0.0)).opacity(), 1.0);
+    assert_eq!(Series::new("a", flat_line(0.0)).with_opacity(0.25).opacity(), 0.25);
+    assert_eq!(Series::new("a", flat_line(0.0)).with_opacity(3.0).opacity(), 1.0);
+}
+
+#[gpui::test]
+fn test_selection_change_emits_event(cx: &mut TestAppContext) {
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events_clone = events.clone();
+    let _sub = cx.update(|cx| {
+        cx.subscribe(&chart, move |_, event: &ChartEvent, _| {
+            events_clone.borrow_mut().push(event.clone());
+        })
+    });
+
+    cx.update(|cx| {
+        chart.update(cx, |c, cx| {
+            c.set_selected_series(Some("a".into()), cx);
+            // Selecting the same series again is a no-op
+            c.set_selected_series(Some("a".into()), cx);
+            c.set_selected_series(None, cx);
+        });
+    });
+
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            ChartEvent::SelectionChanged(Some("a".into())),
+            ChartEvent::SelectionChanged(None),
+        ]
+    );
+    chart.read_with(cx, |c, _| assert!(c.selected_series.is_none()));
+
+    // The selection stays in its chart, charts sharing the state do not see it
+    let other = cx.update(|cx| {
+        let (shared_x, shared_state) = chart.read_with(cx, |c, _| {
+            (c.shared_x_axis.clone(), c.shared_state.clone())
+        });
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.update(cx, |c, cx| c.set_selected_series(Some("a".into()), cx));
+    other.read_with(cx, |c, cx| {
+        assert!(c.selected_series.is_none());
+        assert!(c.shared_state.read(cx).selected_series.is_none());
+    });
+}
+
+#[gpui::test]
+fn test_hover_is_tracked_per_chart(cx: &mut TestAppContext) {
+    use gpui::Point;
+    use gpui_chart::ChartView;
+
+    let (chart, other) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        let chart = cx.new(|cx| Chart::new(shared_x.clone(), shared_state.clone(), cx));
+        let other = cx.new(|cx| Chart::new(shared_x, shared_state, cx));
+        (chart, other)
+    });
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.panes[0].series.push(Series::new("a", flat_line(50.0)));
+        c.panes[0].y_axes[0].entity.update(cx, |y, _| {
+            y.min = 0.0;
+            y.max = 100.0;
+        });
+        c.set_emphasis_config(
+            EmphasisConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            cx,
+        );
+    });
+    let window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    cx.run_until_parked();
+
+    // Middle of the pane, on the flat line at y = 50
+    let center = window
+        .update(cx, |view, window, cx| {
+            let size = window.viewport_size();
+            let y = size.height / 2.0;
+            let columns: Vec<f32> = (0..f32::from(size.width) as usize)
+                .map(|x| x as f32 + 0.5)
+                .filter(|&x| view.probe(Point::new(px(x), y), cx).is_some())
+                .collect();
+            let rows: Vec<f32> = (0..f32::from(size.height) as usize)
+                .map(|y| y as f32 + 0.5)
+                .filter(|&y| view.probe(Point::new(px(columns[0]), px(y)), cx).is_some())
+                .collect();
+            Point::new(
+                px((columns[0] + columns[columns.len() - 1]) / 2.0),
+                px((rows[0] + rows[rows.len() - 1]) / 2.0),
+            )
+        })
+        .unwrap();
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    vcx.simulate_mouse_move(center, None, Default::default());
+
+    chart.read_with(&vcx, |c, _| assert_eq!(c.hovered_series.as_deref(), Some("a")));
+    other.read_with(&vcx, |c, cx| {
+        assert!(c.hovered_series.is_none());
+        assert!(c.shared_state.read(cx).hovered_series.is_none());
+    });
+}
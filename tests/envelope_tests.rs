@@ -0,0 +1,73 @@
+use gpui_chart::data_types::{ColorOp, PlotData, PlotPoint};
+use gpui_chart::decimation::min_max_envelope_slice_into;
+use gpui_chart::EnvelopePlot;
+
+fn pt(x: f64, y: f64) -> PlotData {
+    PlotData::Point(PlotPoint {
+        x,
+        y,
+        color_op: ColorOp::None,
+    })
+}
+
+/// Flat noise in [-1, 1] with a single spike at x = 5_555.
+fn spiky_data() -> Vec<PlotData> {
+    (0..10_000)
+        .map(|i| {
+            let y = if i == 5_555 { 100.0 } else { ((i % 7) as f64 - 3.0) / 3.0 };
+            pt(i as f64, y)
+        })
+        .collect()
+}
+
+#[test]
+fn test_envelope_keeps_spikes() {
+    let data = spiky_data();
+    let mut bands = Vec::new();
+    min_max_envelope_slice_into(&data, 100, &mut bands, None, None);
+    assert!(!bands.is_empty() && bands.len() <= 100, "{}", bands.len());
+
+    let max = bands.iter().map(|b| b.2).fold(f64::MIN, f64::max);
+    let min = bands.iter().map(|b| b.1).fold(f64::MAX, f64::min);
+    assert_eq!((min, max), (-1.0, 100.0));
+    // Every bucket covers the whole noise
+    let spike = bands.iter().filter(|b| b.2 == 100.0).count();
+    assert_eq!(spike, 1);
+    assert!(bands.iter().all(|b| b.1 == -1.0 && b.2 >= 1.0));
+    assert!(bands.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn test_envelope_refines_with_zoom() {
+    let plot = EnvelopePlot::new(spiky_data());
+    let wide = plot.envelope(0.0, 10_000.0, 50, None);
+    assert!(wide.len() <= 50);
+
+    // Zoomed in below the budget: one band per point, collapsed on the line
+    let narrow = plot.envelope(5_550.0, 5_560.0, 50, None);
+    assert!(narrow.len() >= 11 && narrow.len() < 50, "{}", narrow.len());
+    assert!(narrow.iter().all(|b| b.1 == b.2));
+    assert!(narrow.contains(&(5_555.0, 100.0, 100.0)));
+}
+
+#[test]
+fn test_envelope_skips_nan_and_uses_ohlcv_extent() {
+    let mut data = spiky_data();
+    data[10] = pt(10.0, f64::NAN);
+    let mut bands = Vec::new();
+    min_max_envelope_slice_into(&data[..20], 100, &mut bands, None, None);
+    assert_eq!(bands.len(), 19);
+
+    let bar = PlotData::Ohlcv(gpui_chart::data_types::Ohlcv {
+        time: 0.0,
+        span: 1.0,
+        open: 2.0,
+        high: 5.0,
+        low: 1.0,
+        close: 3.0,
+        volume: 0.0,
+    });
+    bands.clear();
+    min_max_envelope_slice_into(&[bar], 10, &mut bands, None, None);
+    assert_eq!(bands, vec![(0.0, 1.0, 5.0)]);
+}
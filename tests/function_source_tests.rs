@@ -0,0 +1,91 @@
+use gpui_chart::data_types::{PlotData, PlotDataSource};
+use gpui_chart::function_source::FunctionDataSource;
+use gpui_chart::gaps::{GapIndex, GapSegment};
+
+fn xs(data: &[PlotData]) -> Vec<f64> {
+    data.iter().map(|p| p.x()).collect()
+}
+
+#[test]
+fn test_samples_window_at_render_resolution() {
+    let source = FunctionDataSource::new(|x| 2.0 * x, 0.0, 100.0);
+    let mut out = Vec::new();
+    source.get_aggregated_data(10.0, 20.0, 11, &mut out, None);
+    assert_eq!(xs(&out), (10..=20).map(|x| x as f64).collect::<Vec<_>>());
+    assert!(out.iter().all(|p| p.y() == 2.0 * p.x()));
+
+    // Clipped to the function's X bounds
+    source.get_aggregated_data(90.0, 200.0, 3, &mut out, None);
+    assert_eq!(xs(&out), vec![90.0, 95.0, 100.0]);
+    source.get_aggregated_data(150.0, 200.0, 3, &mut out, None);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_bounds_and_y_range_are_sampled() {
+    let source = FunctionDataSource::new(f64::sin, 0.0, std::f64::consts::TAU).with_samples(1001);
+    let (x_min, x_max, y_min, y_max) = source.get_bounds().unwrap();
+    assert_eq!((x_min, x_max), (0.0, std::f64::consts::TAU));
+    assert!((y_min + 1.0).abs() < 1e-4 && (y_max - 1.0).abs() < 1e-4);
+
+    let (lo, hi) = source.get_y_range(0.0, 1.0).unwrap();
+    assert!(lo.abs() < 1e-9 && (hi - 1.0f64.sin()).abs() < 1e-9);
+    assert_eq!(source.iter_range(0.0, 1.0).count(), 1001);
+}
+
+#[test]
+fn test_single_x_window_and_repeated_y_range() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let source = FunctionDataSource::new(
+        move |x| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            x * x
+        },
+        0.0,
+        10.0,
+    );
+
+    // One point for a single X, not `samples` copies of it
+    let points: Vec<PlotData> = source.iter_range(3.0, 3.0).collect();
+    assert_eq!(xs(&points), vec![3.0]);
+    let mut out = Vec::new();
+    source.get_aggregated_data(3.0, 3.0, 500, &mut out, None);
+    assert_eq!(out.len(), 1);
+
+    // The same window is sampled once
+    let y_range = source.get_y_range(1.0, 2.0);
+    let before = calls.load(Ordering::Relaxed);
+    assert_eq!(source.get_y_range(1.0, 2.0), y_range);
+    assert_eq!(calls.load(Ordering::Relaxed), before);
+    assert_ne!(source.get_y_range(1.0, 3.0), y_range);
+    assert!(calls.load(Ordering::Relaxed) > before);
+}
+
+#[test]
+fn test_non_finite_values_are_skipped() {
+    let source = FunctionDataSource::new(|x| 1.0 / x, -1.0, 1.0).with_samples(3);
+    assert_eq!(xs(&source.iter_range(-1.0, 1.0).collect::<Vec<_>>()), vec![-1.0, 1.0]);
+    assert_eq!(source.get_bounds(), Some((-1.0, 1.0, -1.0, 1.0)));
+
+    let log = FunctionDataSource::new(f64::ln, -2.0, -1.0);
+    assert_eq!(log.get_bounds(), None);
+}
+
+#[test]
+fn test_samples_skip_gaps() {
+    // A 1000ms gap in the middle of [0, 2000]
+    let gaps = GapIndex::new(vec![GapSegment {
+        start_real: 500,
+        end_real: 1500,
+        cumulative_before: 0,
+    }]);
+    let source = FunctionDataSource::new(|x| x, 0.0, 2000.0);
+    let mut out = Vec::new();
+    source.get_aggregated_data(0.0, 2000.0, 11, &mut out, Some(&gaps));
+    assert_eq!(out.len(), 11);
+    assert!(out.iter().all(|p| !gaps.is_inside(p.x() as i64)), "{:?}", xs(&out));
+}
@@ -123,3 +123,115 @@ fn test_m4_kernel_respects_gaps() {
         }
     }
 }
+
+#[test]
+fn test_split_point_budget_is_proportional_to_logical_span() {
+    let gaps = GapIndex::new(vec![GapSegment {
+        start_real: 1000,
+        end_real: 2000,
+        cumulative_before: 0,
+    }]);
+    // 100 logical units before the gap, 300 after
+    assert_eq!(
+        gaps.split_point_budget(900, 2300, 100),
+        Some(vec![(900, 1000, 25), (2000, 2300, 75)])
+    );
+    // No gap inside the range
+    assert_eq!(gaps.split_point_budget(2000, 2300, 100), None);
+}
+
+#[test]
+fn test_streaming_aggregation_splits_at_gaps() {
+    use gpui_chart::data_types::{ColorOp, PlotDataSource, PlotPoint, StreamingDataSource};
+
+    let gaps = GapIndex::new(vec![GapSegment {
+        start_real: 1000,
+        end_real: 2000,
+        cumulative_before: 0,
+    }]);
+    let mut source = StreamingDataSource::new(10_000);
+    for x in (0..1000).chain(2000..3000) {
+        source.add_data(PlotData::Point(PlotPoint {
+            x: x as f64,
+            y: (x % 7) as f64,
+            color_op: ColorOp::None,
+        }));
+    }
+
+    let mut output = Vec::new();
+    source.get_aggregated_data(0.0, 3000.0, 200, &mut output, Some(&gaps));
+    assert!(!output.is_empty() && output.len() <= 200);
+    let before = output.iter().filter(|p| p.x() < 1000.0).count();
+    let after = output.iter().filter(|p| p.x() >= 2000.0).count();
+    assert_eq!(before + after, output.len(), "no point inside the gap");
+    assert_eq!(before, after, "equal sessions get equal budgets");
+    assert!(output.windows(2).all(|w| w[0].x() <= w[1].x()));
+}
+
+#[test]
+fn test_empty_gap_index_behaves_like_none() {
+    use gpui_chart::data_types::{AxisRange, ColorOp, PlotPoint};
+    use gpui_chart::decimation::{
+        decimate_lttb_slice, decimate_m4_arrays_par_into, decimate_min_max_slice_into,
+        decimate_ohlcv_arrays_par_into,
+    };
+    use gpui_chart::scales::ChartScale;
+    use std::sync::Arc;
+
+    let empty = GapIndex::new(vec![]);
+    assert!(empty.is_empty());
+    let mut state = gpui_chart::data_types::SharedPlotState::default();
+    state.set_gap_index(Some(Arc::new(empty.clone())));
+    assert!(state.gap_index.is_none());
+    assert!(!GapIndex::new(vec![GapSegment {
+        start_real: 10,
+        end_real: 20,
+        cumulative_before: 0,
+    }])
+    .is_empty());
+
+    let x: Vec<f64> = (0..5000).map(|i| i as f64 * 7.0).collect();
+    let y: Vec<f64> = x.iter().map(|v| (v * 0.01).sin() * 100.0).collect();
+    let points: Vec<PlotData> = x
+        .iter()
+        .zip(&y)
+        .map(|(&x, &y)| {
+            PlotData::Point(PlotPoint {
+                x,
+                y,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+
+    let xy = |data: &[PlotData]| data.iter().map(|p| (p.x(), p.y())).collect::<Vec<_>>();
+    let (mut with_empty, mut without) = (Vec::new(), Vec::new());
+    decimate_m4_arrays_par_into(&x, &y, 200, &mut with_empty, Some(&empty), None);
+    decimate_m4_arrays_par_into(&x, &y, 200, &mut without, None, None);
+    assert_eq!(xy(&with_empty), xy(&without));
+
+    with_empty.clear();
+    without.clear();
+    decimate_min_max_slice_into(&points, 200, &mut with_empty, Some(&empty), None);
+    decimate_min_max_slice_into(&points, 200, &mut without, None, None);
+    assert_eq!(xy(&with_empty), xy(&without));
+
+    assert_eq!(
+        xy(&decimate_lttb_slice(&points, 200, Some(&empty), None)),
+        xy(&decimate_lttb_slice(&points, 200, None, None))
+    );
+
+    with_empty.clear();
+    without.clear();
+    decimate_ohlcv_arrays_par_into(&x, &y, &y, &y, &y, 100, &mut with_empty, Some(&empty), None);
+    decimate_ohlcv_arrays_par_into(&x, &y, &y, &y, &y, 100, &mut without, None, None);
+    assert_eq!(xy(&with_empty), xy(&without));
+
+    // Ticks and scales take the plain linear path
+    let mut range = AxisRange::new(0.0, 1234.0);
+    let ticks = range.ticks(8, Some(&empty)).to_vec();
+    assert_eq!(ticks, AxisRange::new(0.0, 1234.0).ticks(8, None));
+    let scale = ChartScale::new_linear((0.0, 100.0), (0.0, 500.0))
+        .with_gaps(Some(Arc::new(empty.clone())));
+    assert!(scale.gap_index().is_none());
+}
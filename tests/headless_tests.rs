@@ -0,0 +1,53 @@
+//! Pane and view logic exercised on plain structs, without a GPUI app.
+
+use gpui_chart::data_types::{AxisId, ColorOp, PlotPoint};
+use gpui_chart::{LinePlot, PaneState, Series};
+
+fn line(points: &[(f64, f64)]) -> LinePlot {
+    LinePlot::new(
+        points
+            .iter()
+            .map(|&(x, y)| PlotPoint {
+                x,
+                y,
+                color_op: ColorOp::None,
+            })
+            .collect(),
+    )
+}
+
+fn pane() -> PaneState {
+    let mut pane = PaneState::new("p".into(), 1.0);
+    pane.series
+        .push(Series::new("a", line(&[(0.0, 1.0), (5.0, 4.0), (10.0, 2.0)])));
+    pane.series
+        .push(Series::new("b", line(&[(0.0, -3.0), (5.0, 0.0), (20.0, 8.0)])));
+    let mut isolated = Series::new("c", line(&[(0.0, 100.0), (10.0, 200.0)]));
+    isolated.y_axis_id = AxisId(1);
+    pane.series.push(isolated);
+    pane
+}
+
+#[test]
+fn test_y_range_on_axis() {
+    let mut pane = pane();
+    assert_eq!(pane.y_range_on_axis(0, 0.0, 20.0, false), Some((-3.0, 8.0)));
+    assert_eq!(pane.y_range_on_axis(1, 0.0, 20.0, false), Some((100.0, 200.0)));
+    assert_eq!(pane.y_range_on_axis(2, 0.0, 20.0, false), None);
+
+    pane.hidden_series.insert("b".into());
+    assert_eq!(pane.y_range_on_axis(0, 0.0, 20.0, false), Some((1.0, 4.0)));
+    assert_eq!(pane.y_range_on_axis(0, 0.0, 20.0, true), Some((-3.0, 8.0)));
+}
+
+#[test]
+fn test_data_bounds() {
+    let mut pane = pane();
+    assert_eq!(pane.data_bounds(None, false), Some((0.0, 20.0, -3.0, 200.0)));
+    assert_eq!(pane.data_bounds(Some(0), false), Some((0.0, 20.0, -3.0, 8.0)));
+
+    pane.hidden_series.insert("b".into());
+    assert_eq!(pane.data_bounds(Some(0), false), Some((0.0, 10.0, 1.0, 4.0)));
+    assert_eq!(pane.data_bounds(Some(0), true), Some((0.0, 20.0, -3.0, 8.0)));
+    assert_eq!(PaneState::new("empty".into(), 1.0).data_bounds(None, true), None);
+}
@@ -0,0 +1,81 @@
+use gpui_chart::data_types::{ColorMap, ColorScale, HeatmapCell};
+use gpui_chart::HeatmapPlot;
+
+fn cell(x: f64, value: f64) -> HeatmapCell {
+    HeatmapCell {
+        x,
+        y: 0.0,
+        width: 1.0,
+        height: 1.0,
+        color: gpui::green(),
+        text: None,
+        value: Some(value),
+    }
+}
+
+#[test]
+fn test_log_scale_spreads_decades() {
+    let range = (1.0, 1000.0);
+    assert_eq!(ColorScale::Log.normalize(1.0, range), 0.0);
+    assert!((ColorScale::Log.normalize(10.0, range) - 1.0 / 3.0).abs() < 1e-6);
+    assert!((ColorScale::Log.normalize(100.0, range) - 2.0 / 3.0).abs() < 1e-6);
+    // Linear squeezes the low decades at the bottom of the map
+    assert!(ColorScale::Linear.normalize(10.0, range) < 0.01);
+
+    // Zero and negative values clamp to the low end
+    assert_eq!(ColorScale::Log.normalize(0.0, range), 0.0);
+    assert_eq!(ColorScale::Log.normalize(-5.0, range), 0.0);
+    // A non-positive lower bound falls back to three decades below the top
+    assert_eq!(ColorScale::Log.effective_range((0.0, 100.0)), (0.1, 100.0));
+    assert!((ColorScale::Log.normalize(10.0, (0.0, 100.0)) - 2.0 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_symlog_is_symmetric() {
+    let range = (-100.0, 100.0);
+    assert_eq!(ColorScale::SymLog.normalize(0.0, range), 0.5);
+    let up = ColorScale::SymLog.normalize(10.0, range);
+    let down = ColorScale::SymLog.normalize(-10.0, range);
+    assert!((up - 0.5 - (0.5 - down)).abs() < 1e-6);
+    assert!(up > ColorScale::Linear.normalize(10.0, range));
+}
+
+#[test]
+fn test_colorbar_ticks() {
+    assert_eq!(
+        ColorScale::Log.ticks((1.0, 1000.0), 5),
+        vec![1.0, 10.0, 100.0, 1000.0]
+    );
+    assert_eq!(
+        ColorScale::Log.ticks((2.0, 30.0), 5),
+        vec![2.0, 5.0, 10.0, 20.0]
+    );
+    assert_eq!(
+        ColorScale::SymLog.ticks((-100.0, 10.0), 5),
+        vec![-100.0, -10.0, -1.0, 0.0, 1.0, 10.0]
+    );
+
+    let plot = HeatmapPlot::new(vec![cell(0.0, 0.0), cell(1.0, 1.0), cell(2.0, 100.0)])
+        .with_colormap(ColorMap::blue_red(), ColorScale::Log, None);
+    // Zero is left out of the automatic log range
+    assert_eq!(plot.color_range(), Some((1.0, 100.0)));
+    let ticks = plot.colorbar_ticks(5);
+    assert_eq!(ticks, vec![(1.0, 0.0), (10.0, 0.5), (100.0, 1.0)]);
+}
+
+#[test]
+fn test_cell_color_follows_value() {
+    let cells = vec![cell(0.0, 1.0), cell(1.0, 100.0)];
+    let plain = HeatmapPlot::new(cells.clone());
+    assert_eq!(plain.cell_color(&cells[0]), gpui::green());
+
+    let plot =
+        HeatmapPlot::new(cells.clone()).with_colormap(ColorMap::blue_red(), ColorScale::Log, None);
+    let map = ColorMap::blue_red();
+    assert_eq!(plot.cell_color(&cells[0]), map.color_at(0.0));
+    assert_eq!(plot.cell_color(&cells[1]), map.color_at(1.0));
+
+    let mut no_value = cells[0].clone();
+    no_value.value = None;
+    assert_eq!(plot.cell_color(&no_value), gpui::green());
+}
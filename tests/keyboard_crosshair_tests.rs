@@ -0,0 +1,219 @@
+use gpui::{AppContext, TestAppContext};
+use gpui_chart::data_types::{AxisRange, ColorOp, PlotPoint, SharedPlotState};
+use gpui_chart::{Chart, LinePlot, PaneState, Series};
+
+fn line(xs: &[f64]) -> LinePlot {
+    LinePlot::new(
+        xs.iter()
+            .map(|&x| PlotPoint {
+                x,
+                y: x,
+                color_op: ColorOp::None,
+            })
+            .collect(),
+    )
+}
+
+#[gpui::test]
+fn test_step_crosshair_visits_points_of_all_series(cx: &mut TestAppContext) {
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| {
+            let mut c = Chart::new(shared_x, shared_state, cx);
+            let mut pane = PaneState::new("p".into(), 1.0);
+            pane.series.push(Series::new("even", line(&[0.0, 2.0, 4.0, 6.0, 8.0, 30.0])));
+            pane.series.push(Series::new("odd", line(&[1.0, 3.0, 5.0])));
+            c.panes.push(pane);
+            c
+        })
+    });
+
+    let hover_x = |cx: &mut TestAppContext| {
+        chart.read_with(cx, |c, cx| {
+            let s = c.shared_state.read(cx);
+            assert!(s.crosshair_pinned);
+            s.hover_x
+        })
+    };
+
+    chart.update(cx, |c, cx| {
+        c.shared_state.update(cx, |s, _| s.hover_x = Some(2.0));
+        c.step_crosshair(1, cx);
+    });
+    assert_eq!(hover_x(cx), Some(3.0));
+
+    chart.update(cx, |c, cx| c.step_crosshair(-2, cx));
+    assert_eq!(hover_x(cx), Some(1.0));
+
+    // Jumping past the visible range scrolls the X axis to keep the point in view
+    chart.update(cx, |c, cx| c.step_crosshair(10, cx));
+    assert_eq!(hover_x(cx), Some(30.0));
+    chart.read_with(cx, |c, cx| {
+        let x = c.shared_x_axis.read(cx);
+        assert!(x.min <= 30.0 && x.max >= 30.0);
+    });
+}
+
+#[gpui::test]
+fn test_step_crosshair_requires_crosshair(cx: &mut TestAppContext) {
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    chart.update(cx, |c, cx| {
+        c.set_crosshair_enabled(false, cx);
+        c.step_crosshair(1, cx);
+    });
+    chart.read_with(cx, |c, cx| {
+        assert!(c.shared_state.read(cx).hover_x.is_none());
+    });
+}
+
+#[gpui::test]
+fn test_crosshair_and_tooltip_visibility_api(cx: &mut TestAppContext) {
+    use gpui::Focusable;
+    use gpui_chart::chart_view::ToggleCrosshair;
+    use gpui_chart::ChartView;
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.read_with(cx, |c, cx| {
+        assert!(c.crosshair_visible(cx));
+        assert!(c.tooltip_visible(cx));
+    });
+
+    chart.update(cx, |c, cx| {
+        c.shared_state.update(cx, |s, _| {
+            s.hover_x = Some(2.0);
+            s.crosshair_pinned = true;
+        });
+        c.set_crosshair_visible(false, cx);
+        c.set_tooltip_visible(false, cx);
+    });
+    chart.read_with(cx, |c, cx| {
+        assert!(!c.crosshair_visible(cx));
+        assert!(!c.tooltip_visible(cx));
+        let s = c.shared_state.read(cx);
+        assert!(s.hover_x.is_none() && !s.crosshair_pinned);
+    });
+
+    // The keyboard action flips the same switch
+    let window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    window
+        .update(&mut vcx, |view, window, cx| window.focus(&view.focus_handle(cx)))
+        .unwrap();
+    vcx.dispatch_action(ToggleCrosshair);
+    chart.read_with(&vcx, |c, cx| assert!(c.crosshair_visible(cx)));
+    vcx.dispatch_action(ToggleCrosshair);
+    chart.read_with(&vcx, |c, cx| assert!(!c.crosshair_visible(cx)));
+    // The tooltip setting is independent
+    chart.read_with(&vcx, |c, cx| assert!(!c.tooltip_visible(cx)));
+}
+
+#[gpui::test]
+fn test_hover_readout_modes(cx: &mut TestAppContext) {
+    use gpui::{px, Point};
+    use gpui_chart::data_types::HoverReadout;
+    use gpui_chart::data_types::AxisEdge;
+    use gpui_chart::{AxisState, ChartView};
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.update(cx, |c, cx| {
+        for id in ["price", "rsi"] {
+            let mut pane = PaneState::new(id.into(), 1.0);
+            let y = cx.new(|_| AxisRange::new(0.0, 10.0));
+            pane.y_axes.push(AxisState::new(y, AxisEdge::Right, px(60.0), "Y".into()));
+            pane.series.push(Series::new(id, line(&[0.0, 5.0, 10.0])));
+            c.panes.push(pane);
+        }
+    });
+
+    // (mouse over a pane of the chart, mouse over this pane)
+    let visible = |c: &Chart, cx: &gpui::App| {
+        let s = c.shared_state.read(cx);
+        [(false, false), (true, false), (true, true)]
+            .map(|(chart, pane)| s.readout_visible(chart, pane))
+    };
+    chart.read_with(cx, |c, cx| {
+        assert_eq!(c.hover_readout(cx), HoverReadout::Off);
+        assert_eq!(visible(c, cx), [false, false, false]);
+    });
+    chart.update(cx, |c, cx| c.set_hover_readout(HoverReadout::Pane, cx));
+    chart.read_with(cx, |c, cx| assert_eq!(visible(c, cx), [false, false, true]));
+    chart.update(cx, |c, cx| c.set_hover_readout(HoverReadout::AllPanes, cx));
+    chart.read_with(cx, |c, cx| assert_eq!(visible(c, cx), [false, true, true]));
+
+    // The pinned crosshair shows it everywhere, unless the tooltip is off
+    chart.update(cx, |c, cx| {
+        c.set_hover_readout(HoverReadout::Off, cx);
+        c.shared_state.update(cx, |s, _| s.crosshair_pinned = true);
+    });
+    chart.read_with(cx, |c, cx| assert_eq!(visible(c, cx), [true, true, true]));
+    chart.update(cx, |c, cx| c.set_tooltip_visible(false, cx));
+    chart.read_with(cx, |c, cx| assert_eq!(visible(c, cx), [false, false, false]));
+
+    // Hovering a pane renders the readouts of both
+    chart.update(cx, |c, cx| {
+        c.set_tooltip_visible(true, cx);
+        c.set_hover_readout(HoverReadout::AllPanes, cx);
+    });
+    let window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    vcx.run_until_parked();
+    vcx.simulate_mouse_move(Point::new(px(100.0), px(50.0)), None, gpui::Modifiers::none());
+    vcx.run_until_parked();
+    chart.read_with(&vcx, |c, cx| assert!(c.shared_state.read(cx).hover_x.is_some()));
+}
+
+#[gpui::test]
+fn test_crosshair_scope_follows_active_chart(cx: &mut TestAppContext) {
+    use gpui::Entity;
+    use gpui_chart::data_types::CrosshairScope;
+
+    let (a, b) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        let a = cx.new(|cx| Chart::new(shared_x.clone(), shared_state.clone(), cx));
+        let b = cx.new(|cx| Chart::new(shared_x, shared_state, cx));
+        (a, b)
+    });
+    for chart in [&a, &b] {
+        chart.update(cx, |c, _| {
+            let mut pane = PaneState::new("p".into(), 1.0);
+            pane.series.push(Series::new("s", line(&[0.0, 5.0, 10.0])));
+            c.panes.push(pane);
+        });
+    }
+    let shown = |chart: &Entity<Chart>, cx: &mut TestAppContext| {
+        let id = chart.entity_id();
+        chart.read_with(cx, |c, cx| c.shared_state.read(cx).crosshair_visible_on(id))
+    };
+
+    // Every chart draws it by default
+    assert_eq!(a.read_with(cx, |c, cx| c.crosshair_scope(cx)), CrosshairScope::AllCharts);
+    assert!(shown(&a, cx) && shown(&b, cx));
+
+    // Only the chart stepped with the keyboard once restricted
+    a.update(cx, |c, cx| c.set_crosshair_scope(CrosshairScope::ActiveChart, cx));
+    assert!(!shown(&a, cx) && !shown(&b, cx));
+    b.update(cx, |c, cx| c.step_crosshair(1, cx));
+    assert_eq!(a.read_with(cx, |c, cx| c.active_chart_id(cx)), Some(b.entity_id()));
+    assert!(!shown(&a, cx) && shown(&b, cx));
+    a.update(cx, |c, cx| c.step_crosshair(1, cx));
+    assert!(shown(&a, cx) && !shown(&b, cx));
+
+    // Nothing is drawn with the crosshair off
+    a.update(cx, |c, cx| c.set_crosshair_visible(false, cx));
+    assert!(!shown(&a, cx) && !shown(&b, cx));
+}
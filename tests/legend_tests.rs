@@ -33,3 +33,65 @@ fn test_legend_visibility_toggle(cx: &mut gpui::TestAppContext) {
         assert!(c.panes[0].hidden_series.contains("series1"));
     });
 }
+
+#[gpui::test]
+fn test_legend_actions_emit_events(cx: &mut gpui::TestAppContext) {
+    use gpui_chart::ChartEvent;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events_clone = events.clone();
+    let _sub = cx.update(|cx| {
+        cx.subscribe(&chart, move |_, event: &ChartEvent, _| {
+            events_clone.borrow_mut().push(event.clone());
+        })
+    });
+
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.add_pane_at(1, 1.0, cx);
+        c.panes[0].series.push(Series::new("a", LinePlot::new(vec![])));
+        c.panes[0].series.push(Series::new("b", LinePlot::new(vec![])));
+
+        c.toggle_series_isolation(0, "a", cx);
+        c.toggle_series_isolation(0, "a", cx);
+        c.move_series(0, 1, "b", cx);
+        c.remove_series_by_id("b".into(), cx);
+        // Unknown series: nothing changes, nothing is emitted
+        c.move_series(0, 1, "zzz", cx);
+        c.toggle_series_isolation(0, "zzz", cx);
+        c.remove_series_by_id("zzz".into(), cx);
+    });
+
+    let series_id = |id: &str| id.to_string();
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            ChartEvent::SeriesIsolationToggled {
+                series_id: series_id("a"),
+                pane_idx: 0,
+                isolated: true,
+            },
+            ChartEvent::SeriesIsolationToggled {
+                series_id: series_id("a"),
+                pane_idx: 0,
+                isolated: false,
+            },
+            ChartEvent::SeriesMoved {
+                series_id: series_id("b"),
+                from_pane: 0,
+                to_pane: 1,
+            },
+            ChartEvent::SeriesRemoved {
+                series_id: series_id("b"),
+                pane_idx: 1,
+            },
+        ]
+    );
+}
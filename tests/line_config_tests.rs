@@ -0,0 +1,66 @@
+use gpui_chart::data_types::{ColorOp, LinePlotConfig, PlotPoint};
+use gpui_chart::LinePlot;
+
+#[test]
+fn test_line_join_and_cap() {
+    use gpui_chart::data_types::{LineCap, LineJoin};
+
+    // Defaults keep the previous output: sharp joins, flat ends
+    let config = LinePlotConfig::default();
+    assert_eq!(config.line_join, LineJoin::Miter);
+    assert_eq!(config.line_cap, LineCap::Butt);
+
+    let line = LinePlot::new(vec![]).with_round_joins();
+    assert_eq!(line.config.line_join, LineJoin::Round);
+    assert_eq!(line.config.line_cap, LineCap::Round);
+}
+
+#[test]
+fn test_area_baseline() {
+    use gpui_chart::data_types::{AreaBaseline, AreaPlotConfig};
+    use gpui_chart::plot_types::PlotRenderer;
+    use gpui_chart::AreaPlot;
+
+    let points = || {
+        [(0.0, 5.0), (1.0, 8.0), (2.0, 3.0)]
+            .into_iter()
+            .map(|(x, y)| PlotPoint {
+                x,
+                y,
+                color_op: ColorOp::None,
+            })
+            .collect()
+    };
+
+    // The default zero fills to 0 but leaves the auto-fit alone
+    let area = AreaPlot::new(points());
+    assert_eq!(area.baseline(), 0.0);
+    assert_eq!(area.config.baseline(), AreaBaseline::Zero);
+    assert_eq!(area.get_y_range(0.0, 2.0), Some((3.0, 8.0)));
+    assert_eq!(area.get_min_max(), Some((0.0, 2.0, 3.0, 8.0)));
+
+    // An explicit zero is pulled into the range
+    let area = AreaPlot::new(points())
+        .with_config(AreaPlotConfig::default().with_baseline(AreaBaseline::Zero));
+    assert_eq!(area.get_y_range(0.0, 2.0), Some((0.0, 8.0)));
+    assert_eq!(area.get_min_max(), Some((0.0, 2.0, 0.0, 8.0)));
+
+    let area = AreaPlot::new(points()).with_baseline(10.0);
+    assert_eq!(area.baseline, 10.0);
+    assert_eq!(area.get_y_range(0.0, 1.0), Some((5.0, 10.0)));
+
+    // The public field still works
+    let mut area = AreaPlot::new(points());
+    area.baseline = -2.0;
+    assert_eq!(area.baseline(), -2.0);
+    assert_eq!(area.get_y_range(0.0, 1.0), Some((-2.0, 8.0)));
+
+    // The series minimum adds nothing to the range
+    let area = AreaPlot::new(points()).with_config(
+        AreaPlotConfig::default()
+            .with_baseline(AreaBaseline::SeriesMin)
+            .with_negative_fill_color(gpui::red()),
+    );
+    assert_eq!(area.baseline(), 3.0);
+    assert_eq!(area.get_y_range(0.0, 1.0), Some((3.0, 8.0)));
+}
@@ -1,4 +1,7 @@
-use gpui_chart::data_types::{ColorOp, Ohlcv, PlotData, PlotDataSource, PlotPoint, VecDataSource};
+use gpui_chart::data_types::{
+    AggregationMode, ColorOp, Ohlcv, PlotData, PlotDataSource, PlotPoint, StreamingDataSource,
+    VecDataSource, ZoomAggregation,
+};
 
 #[test]
 fn test_vec_datasource_aggregation_simple() {
@@ -130,3 +133,310 @@ fn test_vec_datasource_ohlcv_aggregation() {
     // Total volume should be preserved (100 * 100 = 10000)
     assert_eq!(total_volume, 10000.0);
 }
+
+#[test]
+fn test_vec_datasource_pyramid_preserves_spikes() {
+    // Flat signal with a single one-sample spike
+    let count = 8000;
+    let spike_at = 4321;
+    let data: Vec<PlotData> = (0..count)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: if i == spike_at { 100.0 } else { 0.0 },
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+
+    let max_y = |points: &[PlotData]| {
+        points
+            .iter()
+            .map(|p| p.y())
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+
+    // Selecting M4 keeps extremes at every pyramid level
+    let source = VecDataSource::new(data.clone()).with_aggregation_mode(AggregationMode::M4);
+    let aggregated: Vec<PlotData> = source
+        .iter_aggregated(0.0, count as f64, 100, None)
+        .collect();
+    assert!(aggregated.len() < 200, "got {}", aggregated.len());
+    assert_eq!(max_y(&aggregated), 100.0);
+    assert!(aggregated.windows(2).all(|w| w[0].x() <= w[1].x()));
+
+    // By default, as with LTTB, levels average pairs: the spike is smoothed away
+    for source in [
+        VecDataSource::new(data.clone()),
+        VecDataSource::new(data).with_aggregation_mode(AggregationMode::LTTB),
+    ] {
+        let aggregated: Vec<PlotData> = source
+            .iter_aggregated(0.0, count as f64, 100, None)
+            .collect();
+        assert!(max_y(&aggregated) < 100.0);
+    }
+}
+
+#[test]
+fn test_vec_datasource_without_lod_pyramid() {
+    let count = 8000;
+    let data: Vec<PlotData> = (0..count)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: if i == 4321 { 100.0 } else { 0.0 },
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+
+    // The raw points are decimated, keeping the exact extremes that averaged
+    // pyramid levels would smooth away
+    let source = VecDataSource::new(data)
+        .with_aggregation_mode(AggregationMode::LTTB)
+        .with_lod_pyramid(false);
+    let aggregated: Vec<PlotData> = source
+        .iter_aggregated(0.0, count as f64, 100, None)
+        .collect();
+    assert!(aggregated.len() <= 102, "got {}", aggregated.len());
+    assert!(aggregated.iter().any(|p| p.y() == 100.0));
+}
+
+#[test]
+fn test_vec_datasource_custom_aggregation() {
+    use gpui_chart::gaps::{GapIndex, GapSegment};
+    use gpui_chart::smallvec::{smallvec, SmallVec};
+    use std::sync::{Arc, Mutex};
+
+    // Two sessions separated by a gap, with a negative spike in the second one
+    let data: Vec<PlotData> = (0..1000)
+        .chain(2000..3000)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: if i == 2500 { -50.0 } else { (i % 7) as f64 },
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+    let gaps = GapIndex::new(vec![GapSegment {
+        start_real: 1000,
+        end_real: 2000,
+        cumulative_before: 0,
+    }]);
+
+    // Keep the point with the largest absolute deviation per bucket
+    let buckets = Arc::new(Mutex::new(Vec::new()));
+    let seen = buckets.clone();
+    let source = VecDataSource::new(data).with_custom_aggregation(move |chunk| {
+        seen.lock()
+            .unwrap()
+            .push((chunk[0].x(), chunk[chunk.len() - 1].x()));
+        let extreme = chunk
+            .iter()
+            .max_by(|a, b| a.y().abs().total_cmp(&b.y().abs()))
+            .unwrap();
+        let out: SmallVec<[PlotData; 4]> = smallvec![extreme.clone()];
+        out
+    });
+    assert_eq!(source.aggregation_mode(), AggregationMode::Custom);
+
+    let aggregated: Vec<PlotData> = source
+        .iter_aggregated(0.0, 3000.0, 200, Some(&gaps))
+        .collect();
+    assert!(!aggregated.is_empty() && aggregated.len() <= 200, "got {}", aggregated.len());
+    assert!(aggregated.iter().any(|p| p.y() == -50.0));
+
+    // The reducer never sees a bucket straddling the gap
+    let buckets = buckets.lock().unwrap();
+    assert!(!buckets.is_empty());
+    assert!(buckets.iter().all(|&(lo, hi)| hi < 1000.0 || lo >= 2000.0));
+}
+
+#[test]
+fn test_decimation_threshold() {
+    let data: Vec<PlotData> = (0..150)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: (i % 7) as f64,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+
+    // Default: 150 points in view exceed a budget of 100
+    let source = VecDataSource::new(data.clone());
+    assert_eq!(source.decimation_threshold(), 1.0);
+    let mut out = Vec::new();
+    source.get_aggregated_data(0.0, 149.0, 100, &mut out, None);
+    assert!(out.len() <= 100);
+
+    // 150 <= 100 * 2.0: raw points are kept
+    let source = VecDataSource::new(data.clone()).with_decimation_threshold(2.0);
+    source.get_aggregated_data(0.0, 149.0, 100, &mut out, None);
+    assert_eq!(out.len(), 150);
+
+    // Below 1.0 is clamped: the budget is never shrunk
+    let source = VecDataSource::new(data.clone()).with_decimation_threshold(0.5);
+    assert_eq!(source.decimation_threshold(), 1.0);
+    source.get_aggregated_data(0.0, 149.0, 200, &mut out, None);
+    assert_eq!(out.len(), 150);
+    let mut default_out = Vec::new();
+    VecDataSource::new(data.clone()).get_aggregated_data(0.0, 149.0, 100, &mut default_out, None);
+    source.get_aggregated_data(0.0, 149.0, 100, &mut out, None);
+    assert_eq!(out.len(), default_out.len());
+
+    let mut streaming = StreamingDataSource::new(1000).with_decimation_threshold(2.0);
+    streaming.set_data(data);
+    streaming.get_aggregated_data(0.0, 149.0, 100, &mut out, None);
+    assert_eq!(out.len(), 150);
+}
+
+#[test]
+fn test_series_decimation_policy() {
+    use gpui_chart::data_types::DecimationPolicy;
+    use gpui_chart::{LinePlot, Series};
+
+    let data: Vec<PlotData> = (0..150)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: (i % 7) as f64,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+    let aggregated_len = |series: &Series, max_points: usize| {
+        let plot = series.plot.read();
+        let mut out = Vec::new();
+        plot.data_source()
+            .unwrap()
+            .get_aggregated_data(10.0, 139.0, max_points, &mut out, None);
+        out.len()
+    };
+
+    // Never: raw points of the window (plus one on each side), whatever the budget
+    let source = VecDataSource::new(data.clone());
+    let series = Series::new("s", LinePlot::with_source(Box::new(source)))
+        .with_decimation(DecimationPolicy::Never);
+    assert_eq!(aggregated_len(&series, 10), 132);
+
+    // Always: the threshold no longer keeps 150 points raw for a budget of 100
+    let source = VecDataSource::new(data.clone()).with_decimation_threshold(2.0);
+    let series = Series::new("s", LinePlot::with_source(Box::new(source)));
+    assert_eq!(aggregated_len(&series, 100), 132);
+    let series = series.with_decimation(DecimationPolicy::Always);
+    assert!(aggregated_len(&series, 100) <= 100);
+    // Nothing to aggregate within the budget
+    assert_eq!(aggregated_len(&series, 200), 132);
+
+    let mut streaming = StreamingDataSource::new(1000);
+    streaming.set_data(data);
+    streaming.set_decimation_policy(DecimationPolicy::Never);
+    assert_eq!(streaming.decimation_policy(), DecimationPolicy::Never);
+    let mut out = Vec::new();
+    streaming.get_aggregated_data(0.0, 149.0, 10, &mut out, None);
+    assert_eq!(out.len(), 150);
+}
+
+#[test]
+fn test_count_in_range() {
+    let data: Vec<PlotData> = (0..1000)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: 0.0,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+    let source = VecDataSource::new(data.clone());
+    let mut streaming = StreamingDataSource::new(2000);
+    streaming.set_data(data);
+
+    for s in [&source as &dyn PlotDataSource, &streaming] {
+        assert_eq!(s.count_in_range(100.0, 199.0), 100);
+        assert_eq!(s.count_in_range(-50.0, 2000.0), 1000);
+        assert_eq!(s.count_in_range(2000.0, 3000.0), 0);
+    }
+}
+
+#[test]
+fn test_zoom_aggregation_steps_with_hysteresis() {
+    let zoom = ZoomAggregation::new(vec![
+        (50.0, AggregationMode::Custom),
+        (10.0, AggregationMode::MinMax),
+    ]);
+    assert_eq!(zoom.steps[0].0, 10.0);
+    assert_eq!(zoom.step_for(5.0, None), None);
+    assert_eq!(zoom.step_for(100.0, None), Some(1));
+    // Within the band around 10 the current step is kept either way
+    assert_eq!(zoom.step_for(10.5, None), None);
+    assert_eq!(zoom.step_for(9.5, Some(0)), Some(0));
+    assert_eq!(zoom.step_for(11.5, None), Some(0));
+    assert_eq!(zoom.step_for(8.5, Some(0)), None);
+    assert_eq!(zoom.step_for(8.5, Some(1)), None);
+    assert_eq!(zoom.with_hysteresis(0.0).step_for(10.0, None), Some(0));
+}
+
+#[test]
+fn test_zoom_aggregation_switches_to_bars_when_zoomed_out() {
+    use gpui_chart::smallvec::smallvec;
+
+    let data: Vec<PlotData> = (0..10_000)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: (i % 13) as f64,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect();
+    // One bar per bucket beyond 20 points per budget slot, M4 lines below
+    let source = VecDataSource::new(data)
+        .with_custom_aggregation(|chunk| {
+            let ys = chunk.iter().map(|p| p.y());
+            smallvec![PlotData::Ohlcv(Ohlcv {
+                time: chunk[0].x(),
+                span: chunk[chunk.len() - 1].x() - chunk[0].x(),
+                open: chunk[0].y(),
+                high: ys.clone().fold(f64::NEG_INFINITY, f64::max),
+                low: ys.fold(f64::INFINITY, f64::min),
+                close: chunk[chunk.len() - 1].y(),
+                volume: 0.0,
+            })]
+        })
+        .with_aggregation_mode(AggregationMode::M4)
+        .with_zoom_aggregation(ZoomAggregation::new(vec![(20.0, AggregationMode::Custom)]));
+    let is_bars = |out: &[PlotData]| out.iter().all(|p| matches!(p, PlotData::Ohlcv(_)));
+    let is_points = |out: &[PlotData]| out.iter().all(|p| matches!(p, PlotData::Point(_)));
+    let mut out = Vec::new();
+    // Step kept by the view between frames
+    let mut step = None;
+
+    source.get_aggregated_data_at(0.0, 9_999.0, 100, &mut out, None, &mut step);
+    assert_eq!(source.aggregation_mode_for(10_000, 100, &mut None), AggregationMode::Custom);
+    assert!(!out.is_empty() && out.len() <= 100 && is_bars(&out), "{}", out.len());
+
+    // Panning at a ratio just under the threshold keeps the bars
+    for start in [0.0, 37.0, 120.0] {
+        source.get_aggregated_data_at(start, start + 1_899.0, 100, &mut out, None, &mut step);
+        assert!(is_bars(&out));
+    }
+    // A view arriving at that zoom level has no step to keep: lines
+    source.get_aggregated_data(0.0, 1_899.0, 100, &mut out, None);
+    assert!(is_points(&out));
+    let mut other_step = None;
+    source.get_aggregated_data_at(0.0, 1_899.0, 100, &mut out, None, &mut other_step);
+    assert!(is_points(&out));
+    // ...and does not change the first view's step
+    source.get_aggregated_data_at(0.0, 1_899.0, 100, &mut out, None, &mut step);
+    assert!(is_bars(&out));
+
+    // Zooming in past the band switches to lines, then raw points
+    source.get_aggregated_data_at(0.0, 999.0, 100, &mut out, None, &mut step);
+    assert!(out.len() <= 102 && is_points(&out));
+    source.get_aggregated_data_at(0.0, 49.0, 100, &mut out, None, &mut step);
+    assert_eq!(out.len(), 51);
+}
@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use gpui_chart::data_types::{AxisRange, PlotDataSource, VecDataSource};
+    use gpui::TestAppContext;
+    use gpui_chart::data_types::{
+        AxisRange, ColorOp, PlotData, PlotDataSource, PlotPoint, StreamingDataSource,
+        VecDataSource,
+    };
     use gpui_chart::scales::ChartScale;
 
     #[test]
@@ -60,4 +64,85 @@ mod tests {
         );
         // If min==max, linear scale might fail to generate ticks.
     }
+
+    fn bad_samples() -> Vec<PlotData> {
+        [
+            (0.0, 1.0),
+            (1.0, f64::NAN),
+            (2.0, f64::INFINITY),
+            (3.0, 4.0),
+            (4.0, f64::NEG_INFINITY),
+            (5.0, 2.0),
+        ]
+        .into_iter()
+        .map(|(x, y)| {
+            PlotData::Point(PlotPoint {
+                x,
+                y,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_bounds_skip_non_finite_values() {
+        let vec_source = VecDataSource::new(bad_samples());
+        let mut streaming = StreamingDataSource::new(100);
+        for p in bad_samples() {
+            streaming.add_data(p);
+        }
+        let mut streaming_set = StreamingDataSource::new(100);
+        streaming_set.set_data(bad_samples());
+
+        let sources: [&dyn PlotDataSource; 3] = [&vec_source, &streaming, &streaming_set];
+        for source in sources {
+            assert_eq!(source.get_bounds(), Some((0.0, 5.0, 1.0, 4.0)));
+            assert_eq!(source.get_y_range(0.0, 5.0), Some((1.0, 4.0)));
+            assert_eq!(source.get_y_range(0.5, 2.5), None);
+        }
+
+        // Points added one by one
+        let mut vec_source = VecDataSource::new(vec![]);
+        for p in bad_samples() {
+            vec_source.add_data(p);
+        }
+        assert_eq!(vec_source.get_bounds(), Some((0.0, 5.0, 1.0, 4.0)));
+    }
+
+    #[gpui::test]
+    fn test_auto_fit_ignores_non_finite_values(cx: &mut TestAppContext) {
+        use gpui::{px, AppContext};
+        use gpui_chart::data_types::{AxisEdge, SharedPlotState};
+        use gpui_chart::{AxisState, Chart, LinePlot, PaneState, Series};
+
+        let chart = cx.update(|cx| {
+            let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+            let shared_state = cx.new(|_| SharedPlotState::default());
+            cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+        });
+        chart.update(cx, |c, cx| {
+            let mut pane = PaneState::new("p".into(), 1.0);
+            pane.y_axes.push(AxisState::new(
+                cx.new(|_| AxisRange::new(0.0, 1.0)),
+                AxisEdge::Right,
+                px(60.0),
+                "Y".into(),
+            ));
+            let plot = LinePlot::with_source(Box::new(VecDataSource::new(bad_samples())));
+            pane.series.push(Series::new("s", plot));
+            c.panes.push(pane);
+            c.fit_to_data(cx);
+        });
+        chart.read_with(cx, |c, cx| {
+            let x = c.shared_x_axis.read(cx);
+            assert!(x.min.is_finite() && x.max.is_finite());
+            assert!(x.min <= 0.0 && x.max >= 5.0, "{:?}", (x.min, x.max));
+            let y = c.panes[0].y_axes[0].entity.read(cx);
+            assert!(y.min.is_finite() && y.max.is_finite());
+            // The valid data with a 5% margin
+            assert!(y.min < 1.0 && y.min > 0.5, "{}", y.min);
+            assert!(y.max > 4.0 && y.max < 4.5, "{}", y.max);
+        });
+    }
 }
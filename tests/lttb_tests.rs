@@ -1,5 +1,7 @@
-use gpui_chart::decimation::decimate_lttb_slice;
-use gpui_chart::data_types::{ColorOp, PlotData, PlotPoint};
+use gpui_chart::decimation::{decimate_lttb_slice, decimate_lttb_stable_slice_into};
+use gpui_chart::data_types::{
+    AggregationMode, ColorOp, PlotData, PlotDataSource, PlotPoint, VecDataSource,
+};
 
 #[test]
 fn test_lttb_decimation_basic() {
@@ -120,3 +122,65 @@ fn test_lttb_with_nans() {
         }
     }
 }
+
+/// Noisy signal: the sine alone would give every bucket an obvious pick.
+fn noisy(n: usize) -> Vec<PlotData> {
+    (0..n)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: (i as f64 * 0.01).sin() * 10.0 + ((i * 7919) % 101) as f64 / 20.0,
+                color_op: ColorOp::None,
+            })
+        })
+        .collect()
+}
+
+/// Points kept in `[lo, hi]`, away from the window edges.
+fn kept_between(points: &[PlotData], lo: f64, hi: f64) -> Vec<(f64, f64)> {
+    points
+        .iter()
+        .map(|p| (p.x(), p.y()))
+        .filter(|&(x, _)| x >= lo && x <= hi)
+        .collect()
+}
+
+#[test]
+fn test_stable_lttb_ignores_pan_offset() {
+    let data = noisy(20_000);
+    let width = 10_000.0;
+    let mut reference = None;
+    // Sub-bucket pans: buckets are 20 wide for this view
+    for offset in [0.0, 3.0, 7.5, 11.0, 19.0] {
+        let window: Vec<PlotData> = data
+            .iter()
+            .filter(|p| p.x() >= offset && p.x() <= offset + width)
+            .cloned()
+            .collect();
+        let mut out = Vec::new();
+        decimate_lttb_stable_slice_into(&window, 500, &mut out, None, Some(width));
+        assert!(out.len() <= 502, "{}", out.len());
+        let kept = kept_between(&out, 2_000.0, 8_000.0);
+        match &reference {
+            None => reference = Some(kept),
+            Some(expected) => assert_eq!(&kept, expected, "offset {offset}"),
+        }
+    }
+}
+
+#[test]
+fn test_stable_lttb_source_mode() {
+    let source =
+        VecDataSource::new(noisy(50_000)).with_aggregation_mode(AggregationMode::StableLTTB);
+    let mut first = Vec::new();
+    let mut panned = Vec::new();
+    source.get_aggregated_data(10_000.0, 30_000.0, 400, &mut first, None);
+    source.get_aggregated_data(10_013.0, 30_013.0, 400, &mut panned, None);
+    assert!(first.len() <= 402, "{}", first.len());
+    assert_eq!(
+        kept_between(&first, 12_000.0, 28_000.0),
+        kept_between(&panned, 12_000.0, 28_000.0)
+    );
+    // The peak-preserving choice of LTTB is kept
+    assert!(first.iter().any(|p| p.y() > 14.0));
+}
@@ -0,0 +1,105 @@
+use gpui::{canvas, point, px, size, Bounds, Styled, TestAppContext};
+use gpui_chart::data_types::{ColorOp, PlotData, PlotPoint, SharedPlotState, VecDataSource};
+use gpui_chart::{ChartScale, LineChannel, LinePlot, MultiLinePlot, PlotRenderer, PlotTransform};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+const CHANNELS: usize = 50;
+const POINTS: usize = 10_000;
+
+fn points(k: usize) -> Vec<PlotData> {
+    (0..POINTS)
+        .map(|i| {
+            PlotData::Point(PlotPoint {
+                x: i as f64,
+                y: k as f64 + (i as f64 * 0.01 + k as f64).sin(),
+                color_op: ColorOp::None,
+            })
+        })
+        .collect()
+}
+
+fn channel(k: usize, color: gpui::Hsla) -> LineChannel {
+    LineChannel::new(Box::new(VecDataSource::new(points(k))), color)
+}
+
+fn transform() -> PlotTransform {
+    PlotTransform::new(
+        ChartScale::new_linear((0.0, POINTS as f64), (0.0, 1000.0)),
+        ChartScale::new_linear((-1.0, CHANNELS as f64 + 1.0), (600.0, 0.0)),
+        Bounds::new(point(px(0.0), px(0.0)), size(px(1000.0), px(600.0))),
+    )
+}
+
+#[test]
+fn test_channels_grouped_by_color_and_width() {
+    let plot = MultiLinePlot::new(vec![
+        channel(0, gpui::red()),
+        channel(1, gpui::blue()),
+        channel(2, gpui::red()),
+        channel(3, gpui::red()).with_line_width(2.0),
+    ]);
+    let paths = plot.build_paths(&transform(), None, 1.0);
+    let colors: Vec<_> = paths.iter().map(|(c, _)| *c).collect();
+    assert_eq!(colors, vec![gpui::red(), gpui::blue(), gpui::red()]);
+    assert!(plot.drawn_points().unwrap() <= 4 * 2_000);
+
+    let (_, _, y_min, y_max) = plot.get_min_max().unwrap();
+    assert!(y_min >= -1.0 && y_max <= 4.0);
+    assert!(plot.get_y_range(0.0, 100.0).is_some());
+}
+
+#[gpui::test]
+fn bench_multi_line_batched_vs_per_series(cx: &mut TestAppContext) {
+    let colors = [gpui::red(), gpui::green(), gpui::blue()];
+    let batched = || {
+        MultiLinePlot::new((0..CHANNELS).map(|k| channel(k, colors[k % 3])).collect())
+    };
+
+    // 50 separate LinePlot series, each with its own path and paint call
+    let separate: Vec<LinePlot> = (0..CHANNELS)
+        .map(|k| {
+            let mut plot = LinePlot::with_source(Box::new(VecDataSource::new(points(k))));
+            plot.config.color = colors[k % 3];
+            plot
+        })
+        .collect();
+    let paths = batched().build_paths(&transform(), None, 1.0).len();
+    let batched = batched();
+
+    let durations = Rc::new(Cell::new((Duration::ZERO, Duration::ZERO)));
+    let out = durations.clone();
+    let vcx = cx.add_empty_window();
+    vcx.draw(point(px(0.0), px(0.0)), size(px(1000.0), px(600.0)), |_, _| {
+        canvas(
+            |_, _, _| {},
+            move |_, (), window, cx| {
+                let transform = transform();
+                let state = SharedPlotState::default();
+                // Warmup
+                for plot in &separate {
+                    plot.render(window, &transform, "s", cx, &state);
+                }
+                batched.render(window, &transform, "s", cx, &state);
+
+                let start = Instant::now();
+                for plot in &separate {
+                    plot.render(window, &transform, "s", cx, &state);
+                }
+                let dur_separate = start.elapsed();
+
+                let start = Instant::now();
+                batched.render(window, &transform, "s", cx, &state);
+                out.set((dur_separate, start.elapsed()));
+            },
+        )
+        .size_full()
+    });
+
+    let (dur_separate, dur_batched) = durations.get();
+    println!("LinePlot per series ({CHANNELS}x{POINTS}): {:?}", dur_separate);
+    println!("MultiLinePlot batched ({CHANNELS}x{POINTS}): {:?}", dur_batched);
+    assert!(dur_separate > Duration::ZERO && dur_batched > Duration::ZERO);
+    assert_eq!(paths, 3);
+}
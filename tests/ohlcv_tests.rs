@@ -71,3 +71,176 @@ fn test_ohlcv_decimation_nan_handling() {
         assert_eq!(c.low, 2.0); // Min
     }
 }
+
+#[test]
+fn test_candle_body_min_height() {
+    use gpui_chart::data_types::{CandleStyle, CandlestickConfig};
+    use gpui_chart::CandlestickPlot;
+
+    let config = CandlestickConfig {
+        min_body_px: 3.0,
+        ..Default::default()
+    };
+    // Regular bodies are untouched, whatever the direction
+    assert_eq!(config.body_span_px(10.0, 50.0), (10.0, 50.0));
+    assert_eq!(config.body_span_px(50.0, 10.0), (10.0, 50.0));
+    // Doji: grown around the open/close level
+    assert_eq!(config.body_span_px(20.0, 20.0), (18.5, 21.5));
+
+    let plot = CandlestickPlot::new(vec![]).with_style(CandleStyle::OhlcBar);
+    assert_eq!(plot.config.style, CandleStyle::OhlcBar);
+    assert_eq!(CandlestickConfig::default().style, CandleStyle::Candle);
+}
+
+#[test]
+fn test_provisional_last_candle_opacity() {
+    use gpui_chart::data_types::{CandlestickConfig, Ohlcv};
+    use gpui_chart::CandlestickPlot;
+
+    let candle = |time: f64, span: f64| Ohlcv {
+        time,
+        span,
+        open: 1.0,
+        high: 2.0,
+        low: 0.5,
+        close: 1.5,
+        volume: 0.0,
+    };
+    let mut config = CandlestickConfig::default();
+    // Opt-in: static data is unaffected by default
+    assert_eq!(config.candle_opacity(&candle(60.0, 60.0), 60.0), 1.0);
+
+    config.provisional_last = true;
+    let faded = config.provisional_opacity;
+    assert_eq!(config.candle_opacity(&candle(60.0, 60.0), 60.0), faded);
+    assert_eq!(config.candle_opacity(&candle(0.0, 60.0), 60.0), 1.0);
+    // An aggregated candle containing the newest one is provisional too
+    assert_eq!(config.candle_opacity(&candle(0.0, 120.0), 60.0), faded);
+    assert_eq!(config.candle_opacity(&candle(60.0, 0.0), 60.0), faded);
+
+    let plot = CandlestickPlot::new(vec![]).with_provisional_last(true);
+    assert!(plot.config.provisional_last);
+}
+
+#[test]
+fn test_resample_to_fixed_intervals() {
+    use gpui_chart::data_types::Ohlcv;
+    use gpui_chart::decimation::{calculate_interval_buckets, resample_slice};
+
+    let minute = 60_000.0;
+    let hour = 60.0 * minute;
+    // Irregular sampling: dense in the first hour, sparse in the third, none in the second
+    let times = [0.0, 1.0, 2.0, 3.0, 4.0, 59.0, 125.0, 170.0].map(|m| 1_000.0 * hour + m * minute);
+    let data: Vec<PlotData> = times
+        .iter()
+        .enumerate()
+        .map(|(i, &time)| {
+            PlotData::Ohlcv(Ohlcv {
+                time,
+                span: minute,
+                open: i as f64,
+                high: 10.0 + i as f64,
+                low: -(i as f64),
+                close: i as f64 + 0.5,
+                volume: 1.0,
+            })
+        })
+        .collect();
+
+    let buckets = calculate_interval_buckets(&times, hour);
+    assert_eq!(buckets, vec![0..6, 6..8]);
+
+    let candles: Vec<Ohlcv> = resample_slice(&data, hour)
+        .into_iter()
+        .map(|p| match p {
+            PlotData::Ohlcv(o) => o,
+            _ => panic!("Expected Ohlcv data"),
+        })
+        .collect();
+    assert_eq!(candles.len(), 2);
+    assert_eq!((candles[0].time, candles[0].span), (1_000.0 * hour, hour));
+    assert_eq!((candles[0].open, candles[0].close), (0.0, 5.5));
+    assert_eq!((candles[0].high, candles[0].low, candles[0].volume), (15.0, -5.0, 6.0));
+    assert_eq!(candles[1].time, 1_002.0 * hour);
+    assert_eq!((candles[1].open, candles[1].close), (6.0, 7.5));
+}
+
+#[test]
+fn test_candlestick_from_arrays() {
+    use gpui_chart::ohlcv_source::OhlcvArraySource;
+    use gpui_chart::plot_types::PlotRenderer;
+    use gpui_chart::CandlestickPlot;
+
+    let time = vec![0.0, 60.0, 120.0, 180.0];
+    let open = vec![10.0, 11.0, f64::NAN, 12.0];
+    let high = vec![12.0, 13.0, 99.0, 14.0];
+    let low = vec![9.0, 10.0, f64::NAN, 11.0];
+    let close = vec![11.0, 12.0, 12.0, 13.0];
+
+    // Columns must line up
+    let short = vec![1.0; 3];
+    assert!(CandlestickPlot::from_arrays(
+        time.clone(), open.clone(), high.clone(), low.clone(), short.clone(), None
+    )
+    .is_none());
+    assert!(OhlcvArraySource::new(
+        time.clone(), open.clone(), high.clone(), low.clone(), close.clone(), Some(short)
+    )
+    .is_none());
+
+    let volume = Some(vec![100.0, f64::NAN, 0.0, 50.0]);
+    let plot = CandlestickPlot::from_arrays(time, open, high, low, close, volume).unwrap();
+    // Span from the time step; NaN lows are skipped, not their row's high
+    assert_eq!(plot.get_min_max(), Some((0.0, 240.0, 9.0, 99.0)));
+    assert_eq!(plot.get_y_range(60.0, 60.0), Some((10.0, 13.0)));
+
+    // The incomplete row is not drawn, the others keep their fields
+    let source = plot.data_source().unwrap();
+    let candles: Vec<_> = source
+        .iter_range(0.0, 180.0)
+        .filter_map(|p| if let PlotData::Ohlcv(o) = p { Some(o) } else { None })
+        .collect();
+    assert_eq!(candles.iter().map(|c| c.time).collect::<Vec<_>>(), vec![0.0, 60.0, 180.0]);
+    assert_eq!((candles[1].span, candles[1].close, candles[1].volume), (60.0, 12.0, 0.0));
+
+    // Aggregation goes through the OHLCV decimation
+    let mut out = Vec::new();
+    source.get_aggregated_data(0.0, 180.0, 2, &mut out, None);
+    assert!(!out.is_empty() && out.len() <= 3, "{out:?}");
+    assert!(out.iter().all(|p| matches!(p, PlotData::Ohlcv(_))));
+}
+
+#[test]
+fn test_ohlcv_array_source_sorts_and_appends() {
+    use gpui_chart::data_types::{Ohlcv, PlotDataSource};
+    use gpui_chart::ohlcv_source::OhlcvArraySource;
+
+    let mut source = OhlcvArraySource::new(
+        vec![120.0, 0.0, 60.0],
+        vec![3.0, 1.0, 2.0],
+        vec![3.5, 1.5, 2.5],
+        vec![2.5, 0.5, 1.5],
+        vec![3.0, 1.0, 2.0],
+        None,
+    )
+    .unwrap();
+    let opens: Vec<_> = (0..source.len()).map(|i| source.candle(i).unwrap().open).collect();
+    assert_eq!(opens, vec![1.0, 2.0, 3.0]);
+
+    source.add_data(PlotData::Ohlcv(Ohlcv {
+        time: 150.0,
+        span: 0.0,
+        open: 4.0,
+        high: 8.0,
+        low: 4.0,
+        close: 5.0,
+        volume: 1.0,
+    }));
+    assert_eq!(source.suggested_x_spacing(), 30.0);
+    assert_eq!(source.get_bounds(), Some((0.0, 180.0, 0.5, 8.0)));
+    assert_eq!(source.count_in_range(60.0, 150.0), 3);
+
+    source.clear();
+    assert!(source.is_empty());
+    assert_eq!(source.get_bounds(), None);
+}
@@ -30,6 +30,21 @@ fn test_polars_datasource_basic() {
     assert_eq!(y_range.1, 25.0);
 }
 
+#[test]
+#[cfg(feature = "polars")]
+fn test_polars_bounds_skip_non_finite_values() {
+    let df = df!(
+        "x" => &[0.0, 1.0, 2.0, 3.0, 4.0],
+        "y" => &[1.0, 4.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+    )
+    .unwrap();
+
+    let source = PolarsDataSource::new(df, "x", "y");
+    assert_eq!(source.get_bounds(), Some((0.0, 4.0, 1.0, 4.0)));
+    assert_eq!(source.get_y_range(0.0, 4.0), Some((1.0, 4.0)));
+    assert_eq!(source.get_y_range(2.0, 4.0), None);
+}
+
 #[test]
 #[cfg(feature = "polars")]
 fn test_polars_datasource_aggregation() {
@@ -87,3 +102,29 @@ fn test_polars_performance_1m_rows() {
         "Decimation should be extremely fast (<20ms)"
     );
 }
+
+#[test]
+#[cfg(feature = "polars")]
+fn test_polars_custom_aggregation() {
+    use gpui_chart::data_types::AggregationMode;
+    use gpui_chart::smallvec::smallvec;
+
+    let n = 10_000;
+    let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let y: Vec<f64> = (0..n).map(|i| if i == 1234 { 99.0 } else { 0.0 }).collect();
+    let df = df!("x" => x, "y" => y).unwrap();
+
+    // Keep only the last point of each bucket, unless it holds a spike
+    let source = PolarsDataSource::new(df, "x", "y").with_custom_aggregation(|chunk| {
+        match chunk.iter().find(|p| p.y() > 0.0) {
+            Some(spike) => smallvec![spike.clone()],
+            None => smallvec![chunk[chunk.len() - 1].clone()],
+        }
+    });
+    assert_eq!(source.aggregation_mode(), AggregationMode::Custom);
+
+    let aggregated: Vec<PlotData> = source.iter_aggregated(0.0, n as f64, 400, None).collect();
+    assert!(!aggregated.is_empty() && aggregated.len() <= 400, "got {}", aggregated.len());
+    assert!(aggregated.iter().any(|p| p.y() == 99.0));
+    assert!(aggregated.windows(2).all(|w| w[0].x() < w[1].x()));
+}
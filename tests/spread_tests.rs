@@ -0,0 +1,129 @@
+use gpui::{AppContext, TestAppContext};
+use gpui_chart::data_types::{
+    AxisRange, ColorOp, PlotData, PlotDataSource, PlotPoint, SharedPlotState, StreamingDataSource,
+};
+use gpui_chart::spread_source::{SharedPlot, SpreadDataSource, SpreadOp};
+use gpui_chart::{Chart, LinePlot, Series};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+fn pt(x: f64, y: f64) -> PlotData {
+    PlotData::Point(PlotPoint {
+        x,
+        y,
+        color_op: ColorOp::None,
+    })
+}
+
+fn line(points: impl IntoIterator<Item = (f64, f64)>) -> SharedPlot {
+    let source = StreamingDataSource::new(10_000);
+    let mut plot = LinePlot::with_source(Box::new(source));
+    for (x, y) in points {
+        plot.source.add_data(pt(x, y));
+    }
+    Arc::new(RwLock::new(plot))
+}
+
+fn ys(source: &SpreadDataSource, x_min: f64, x_max: f64) -> Vec<(f64, f64)> {
+    source.iter_range(x_min, x_max).map(|p| (p.x(), p.y())).collect()
+}
+
+#[test]
+fn test_difference_interpolates_other_series() {
+    // a sampled every 1, b every 2 with y = x
+    let a = line((0..=10).map(|i| (i as f64, 10.0)));
+    let b = line((0..=5).map(|i| (2.0 * i as f64, 2.0 * i as f64)));
+    let spread = SpreadDataSource::new(a, b, SpreadOp::Difference);
+
+    let out = ys(&spread, 0.0, 10.0);
+    assert_eq!(out.len(), 11);
+    for (x, y) in out {
+        assert!((y - (10.0 - x)).abs() < 1e-9, "{x}: {y}");
+    }
+    assert_eq!(spread.get_y_range(2.0, 4.0), Some((6.0, 8.0)));
+    assert_eq!(spread.get_bounds(), Some((0.0, 10.0, 0.0, 10.0)));
+}
+
+#[test]
+fn test_samples_outside_reference_are_skipped() {
+    let a = line((0..=10).map(|i| (i as f64, 1.0)));
+    let b = line([(3.0, 1.0), (6.0, 1.0)]);
+    let spread = SpreadDataSource::new(a, b, SpreadOp::Ratio);
+    let xs: Vec<f64> = ys(&spread, 0.0, 10.0).into_iter().map(|p| p.0).collect();
+    assert_eq!(xs, vec![3.0, 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn test_ratio_ops_skip_non_finite() {
+    assert_eq!(SpreadOp::Ratio.apply(6.0, 3.0), Some(2.0));
+    assert_eq!(SpreadOp::Ratio.apply(1.0, 0.0), None);
+    assert_eq!(SpreadOp::LogRatio.apply(2.0, 2.0), Some(0.0));
+    assert_eq!(SpreadOp::LogRatio.apply(-1.0, 2.0), None);
+    assert_eq!(SpreadOp::LogRatio.label("a", "b"), "ln(a / b)");
+}
+
+#[test]
+fn test_spread_follows_streaming_inputs() {
+    let a = line([(0.0, 5.0), (1.0, 5.0)]);
+    let b = line([(0.0, 1.0), (1.0, 1.0)]);
+    let spread = SpreadDataSource::new(a.clone(), b.clone(), SpreadOp::Difference);
+    assert_eq!(ys(&spread, 0.0, 10.0).len(), 2);
+
+    a.write().data_source_mut().unwrap().add_data(pt(2.0, 7.0));
+    // b does not reach x = 2 yet
+    assert_eq!(ys(&spread, 0.0, 10.0).len(), 2);
+    b.write().data_source_mut().unwrap().add_data(pt(2.0, 3.0));
+    assert_eq!(ys(&spread, 0.0, 10.0).last(), Some(&(2.0, 4.0)));
+}
+
+#[test]
+fn test_spread_of_a_series_with_itself() {
+    let a = line((0..=4).map(|i| (i as f64, 3.0 + i as f64)));
+    let spread = SpreadDataSource::new(a.clone(), a.clone(), SpreadOp::Difference);
+    assert_eq!(spread.get_bounds(), Some((0.0, 4.0, 0.0, 0.0)));
+
+    // The cached series is rebuilt when the input grows
+    a.write().data_source_mut().unwrap().add_data(pt(5.0, 1.0));
+    assert_eq!(spread.len(), 6);
+    assert_eq!(spread.get_y_range(4.5, 6.0), Some((0.0, 0.0)));
+}
+
+#[gpui::test]
+fn test_add_spread_pane(cx: &mut TestAppContext) {
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let series = |y: f64| {
+            LinePlot::new(
+                (0..=10)
+                    .map(|i| PlotPoint {
+                        x: i as f64,
+                        y: y + i as f64,
+                        color_op: ColorOp::None,
+                    })
+                    .collect(),
+            )
+        };
+        c.panes[0].series.push(Series::new("a", series(100.0)));
+        c.panes[0].series.push(Series::new("b", series(40.0)));
+
+        assert!(c.add_spread_pane("a", "missing", SpreadOp::Difference, cx).is_none());
+        let id = c.add_spread_pane("a", "b", SpreadOp::Difference, cx).unwrap();
+        assert_eq!(c.panes.len(), 2);
+        assert_eq!(c.panes[1].id, id);
+        assert_eq!(c.panes[1].series[0].id, "a - b");
+        // The same spread is not added twice
+        assert!(c.add_spread_pane("a", "b", SpreadOp::Difference, cx).is_none());
+        assert_eq!(c.panes.len(), 2);
+    });
+    chart.read_with(cx, |c, cx| {
+        // Constant spread of 60, fitted on the new pane's axis
+        let y = c.panes[1].y_axes[0].entity.read(cx);
+        assert!(y.min <= 60.0 && y.max >= 60.0, "{:?}", (y.min, y.max));
+        assert_eq!(c.panes[1].series[0].plot.read().get_y_range(0.0, 10.0), Some((60.0, 60.0)));
+    });
+}
@@ -1,6 +1,6 @@
 use gpui::{px, Bounds, Point, Size};
 use gpui_chart::scales::ChartScale;
-use gpui_chart::transform::PlotTransform;
+use gpui_chart::transform::{plot_clip_bounds, snap_line_to_pixel, PlotTransform};
 
 #[test]
 fn test_chart_scale_linear() {
@@ -42,3 +42,37 @@ fn test_plot_transform() {
     assert!((p_restored.x - 50.0).abs() < 0.001);
     assert!((p_restored.y - 50.0).abs() < 0.001);
 }
+
+#[test]
+fn test_plot_clip_bounds_snaps_inward() {
+    // Fractional pane bounds at 2x zoom: the clip must not cover any device
+    // pixel that is only partially inside the pane.
+    let bounds = Bounds::new(
+        Point::new(px(10.3), px(20.7)),
+        Size::new(px(100.6), px(50.2)),
+    );
+    let clip = plot_clip_bounds(bounds, 2.0);
+    assert_eq!(clip.origin, Point::new(px(10.5), px(21.0)));
+    assert_eq!(clip.origin.x + clip.size.width, px(110.5));
+    assert_eq!(clip.origin.y + clip.size.height, px(70.5));
+
+    // Already aligned bounds are unchanged
+    let aligned = Bounds::new(Point::new(px(0.0), px(0.0)), Size::new(px(100.0), px(50.0)));
+    assert_eq!(plot_clip_bounds(aligned, 1.0), aligned);
+}
+
+#[test]
+fn test_snap_line_to_pixel() {
+    // A 1px line at 1x is centered on a pixel center
+    assert_eq!(snap_line_to_pixel(10.2, 1.0, 1.0), 10.5);
+    assert_eq!(snap_line_to_pixel(10.9, 1.0, 1.0), 10.5);
+    assert_eq!(snap_line_to_pixel(11.1, 1.0, 1.0), 11.5);
+    // At 2x it covers two device pixels, so it sits on a device pixel edge
+    assert_eq!(snap_line_to_pixel(10.2, 1.0, 2.0), 10.0);
+    assert_eq!(snap_line_to_pixel(10.3, 1.0, 2.0), 10.5);
+    // At 1.5x a 1px line is two device pixels wide too
+    assert_eq!(snap_line_to_pixel(10.1, 1.0, 1.5), 10.0);
+    // Already crisp positions are unchanged
+    assert_eq!(snap_line_to_pixel(4.5, 1.0, 1.0), 4.5);
+    assert_eq!(snap_line_to_pixel(4.5, 1.0, 2.0), 4.5);
+}
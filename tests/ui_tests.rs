@@ -164,3 +164,2015 @@ fn test_ticks_skip_gaps() {
     assert!(has_before, "Should have ticks before the gap");
     assert!(has_after, "Should have ticks after the gap");
 }
+
+#[gpui::test]
+fn test_snap_zoom_to_data_extent(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, PaneState, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.y_axes.push(gpui_chart::AxisState::new(
+            cx.new(|_| AxisRange::new(0.0, 1.0)),
+            gpui_chart::data_types::AxisEdge::Right,
+            px(60.0),
+            "Y".into(),
+        ));
+        let points = (0..=10)
+            .map(|i| PlotPoint {
+                x: i as f64 * 10.0,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        pane.series.push(Series::new("s", LinePlot::new(points)));
+        c.panes.push(pane);
+        c.set_snap_zoom_to_data(true, cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        assert_eq!(c.shared_x_axis.read(cx).data_extent, Some((-5.0, 105.0)));
+        assert_eq!(c.panes[0].y_axes[0].entity.read(cx).data_extent, Some((-0.5, 10.5)));
+    });
+
+    chart_entity.update(cx, |c, cx| c.set_snap_zoom_to_data(false, cx));
+    chart_entity.read_with(cx, |c, cx| {
+        assert_eq!(c.shared_x_axis.read(cx).data_extent, None);
+    });
+}
+
+#[test]
+fn test_crosshair_config_default_matches_theme_line() {
+    use gpui_chart::data_types::{CrosshairConfig, CrosshairLineStyle};
+
+    let config = CrosshairConfig::default();
+    for style in [&config.vertical, &config.horizontal] {
+        assert_eq!(style, &CrosshairLineStyle::default());
+        assert_eq!(style.color, None, "defaults to theme.crosshair_line");
+        assert_eq!(style.width, 1.0);
+        assert!(style.dash.is_empty());
+    }
+    assert_eq!(config.point_marker_radius, None);
+}
+
+#[gpui::test]
+fn test_box_zoom_fits_every_y_axis(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::AxisEdge;
+    use gpui_chart::AxisState;
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    cx.update(|cx| {
+        chart_entity.update(cx, |c, cx| {
+            c.add_pane_at(0, 1.0, cx);
+            let secondary = cx.new(|_| AxisRange::new(0.0, 1000.0));
+            c.panes[0].y_axes.push(AxisState::new(
+                secondary,
+                AxisEdge::Right,
+                px(60.0),
+                "Secondary".into(),
+            ));
+        });
+    });
+
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    let spans = |c: &Chart, cx: &gpui::App| -> Vec<f64> {
+        c.panes[0]
+            .y_axes
+            .iter()
+            .map(|a| a.entity.read(cx).span())
+            .collect()
+    };
+    let before = chart_entity.read_with(cx, |c, cx| spans(c, cx));
+
+    let mut visual_cx = gpui::VisualTestContext::from_window(window.into(), cx);
+    let start = Point::new(px(300.0), px(200.0));
+    let end = start + Point::new(px(100.0), px(100.0));
+    visual_cx.simulate_mouse_down(start, MouseButton::Right, Default::default());
+    visual_cx.simulate_mouse_move(end, Some(MouseButton::Right), Default::default());
+    visual_cx.simulate_mouse_up(end, MouseButton::Right, Default::default());
+
+    let after = chart_entity.read_with(&visual_cx, |c, cx| spans(c, cx));
+    assert_eq!(after.len(), 2);
+    for (b, a) in before.iter().zip(&after) {
+        assert!(a < b, "every Y axis should be zoomed by the box: {before:?} -> {after:?}");
+    }
+    // Both axes frame the same pixel rows, so they shrink by the same ratio
+    assert!((after[0] / before[0] - after[1] / before[1]).abs() < 1e-6);
+}
+
+#[gpui::test]
+fn test_crosshair_y_values_cover_hovered_pane_axes(cx: &mut TestAppContext) {
+    use gpui::{point, size, Bounds};
+    use gpui_chart::chart_view::renderer::ChartRenderer;
+    use gpui_chart::data_types::AxisEdge;
+    use gpui_chart::{AxisState, PaneState};
+    use std::collections::HashMap;
+
+    let panes = cx.update(|cx| {
+        let axis = |cx: &mut gpui::App, min: f64, max: f64, edge| {
+            AxisState::new(cx.new(|_| AxisRange::new(min, max)), edge, px(60.0), "".into())
+        };
+        let mut top = PaneState::new("top".into(), 1.0);
+        top.y_axes.push(axis(cx, 0.0, 10.0, AxisEdge::Right));
+        let mut bottom = PaneState::new("bottom".into(), 1.0);
+        bottom.y_axes.push(axis(cx, 0.0, 100.0, AxisEdge::Right));
+        bottom.y_axes.push(axis(cx, 0.0, 1000.0, AxisEdge::Left));
+        vec![top, bottom]
+    });
+
+    let mut pane_bounds = HashMap::new();
+    pane_bounds.insert(
+        "top".to_string(),
+        Bounds::new(point(px(0.0), px(0.0)), size(px(400.0), px(100.0))),
+    );
+    pane_bounds.insert(
+        "bottom".to_string(),
+        Bounds::new(point(px(0.0), px(100.0)), size(px(400.0), px(100.0))),
+    );
+
+    cx.update(|cx| {
+        // Middle of the bottom pane: both of its axes, nothing from the top pane
+        let values = ChartRenderer::crosshair_y_values(&panes, &pane_bounds, px(150.0), cx);
+        assert_eq!(values, vec![(1, 0, 50.0), (1, 1, 500.0)]);
+
+        let values = ChartRenderer::crosshair_y_values(&panes, &pane_bounds, px(25.0), cx);
+        assert_eq!(values, vec![(0, 0, 7.5)]);
+
+        assert!(ChartRenderer::crosshair_y_values(&panes, &pane_bounds, px(250.0), cx).is_empty());
+    });
+}
+
+#[gpui::test]
+fn test_nearest_series_y_snaps_to_closest_line(cx: &mut TestAppContext) {
+    use gpui::{point, size, Bounds};
+    use gpui_chart::chart_view::renderer::ChartRenderer;
+    use gpui_chart::data_types::{AxisEdge, ColorOp, PlotPoint};
+    use gpui_chart::{AxisState, LinePlot, PaneState, Series};
+    use std::collections::HashMap;
+
+    let flat = |y: f64| {
+        LinePlot::new(
+            [0.0, 10.0]
+                .map(|x| PlotPoint {
+                    x,
+                    y,
+                    color_op: ColorOp::None,
+                })
+                .to_vec(),
+        )
+    };
+    let mut panes = cx.update(|cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        for max in [10.0, 100.0] {
+            let axis = cx.new(|_| AxisRange::new(0.0, max));
+            pane.y_axes.push(AxisState::new(axis, AxisEdge::Right, px(60.0), "".into()));
+        }
+        // On screen: y = 80 px, 40 px (second axis) and 0 px (out of view)
+        pane.series.push(Series::new("low", flat(2.0)));
+        pane.series.push(Series::new("high", flat(60.0)).on_axis(1));
+        pane.series.push(Series::new("off", flat(50.0)));
+        vec![pane]
+    });
+    let mut pane_bounds = HashMap::new();
+    pane_bounds.insert(
+        "p".to_string(),
+        Bounds::new(point(px(0.0), px(0.0)), size(px(400.0), px(100.0))),
+    );
+
+    cx.update(|cx| {
+        let nearest = |panes: &[PaneState], y: f32| {
+            ChartRenderer::nearest_series_y(panes, &pane_bounds, 5.0, px(y), cx)
+        };
+        assert_eq!(nearest(&panes, 70.0), Some((0, 0, 2.0, px(80.0))));
+        assert_eq!(nearest(&panes, 45.0), Some((0, 1, 60.0, px(40.0))));
+        // The off-screen series is never picked
+        assert_eq!(nearest(&panes, 1.0), Some((0, 1, 60.0, px(40.0))));
+        assert_eq!(nearest(&panes, 150.0), None);
+        panes[0].hidden_series.insert("high".into());
+        assert_eq!(nearest(&panes, 45.0), Some((0, 0, 2.0, px(80.0))));
+    });
+}
+
+#[gpui::test]
+fn test_fit_y_to_visible_x_keeps_x(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, ColorOp, PlotPoint};
+    use gpui_chart::{AxisState, LinePlot, PaneState, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(20.0, 40.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.y_axes.push(AxisState::new(
+            cx.new(|_| AxisRange::new(0.0, 1.0)),
+            AxisEdge::Right,
+            px(60.0),
+            "Y".into(),
+        ));
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        pane.series.push(Series::new("s", LinePlot::new(points)));
+        c.panes.push(pane);
+        c.fit_y_to_visible_x(cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        let x = c.shared_x_axis.read(cx);
+        assert_eq!((x.min, x.max), (20.0, 40.0));
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        // The visible points (plus one neighbor on each side) with a 5% margin
+        assert!(y.min < 20.0 && y.min > 15.0, "{}", y.min);
+        assert!(y.max > 40.0 && y.max < 45.0, "{}", y.max);
+    });
+}
+
+#[gpui::test]
+fn test_autofit_padding(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, ColorOp, PlotPoint};
+    use gpui_chart::{AxisState, LinePlot, PaneState, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.y_axes.push(AxisState::new(
+            cx.new(|_| AxisRange {
+                autofit_padding: Some(0.1),
+                ..AxisRange::new(0.0, 1.0)
+            }),
+            AxisEdge::Right,
+            px(60.0),
+            "Y".into(),
+        ));
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: 2.0 * i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        pane.series.push(Series::new("s", LinePlot::new(points)));
+        c.panes.push(pane);
+        c.set_autofit_padding(0.0, cx);
+        c.fit_to_data(cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        // Chart-wide padding on X, the axis' own on Y
+        let x = c.shared_x_axis.read(cx);
+        assert_eq!((x.min, x.max), (0.0, 100.0));
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert_eq!((y.min, y.max), (-20.0, 220.0));
+    });
+
+    chart_entity.update(cx, |c, cx| {
+        c.set_autofit_padding(0.1, cx);
+        c.fit_x_to_data(cx);
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let x = c.shared_x_axis.read(cx);
+        assert_eq!((x.min, x.max), (-10.0, 110.0));
+    });
+}
+
+#[gpui::test]
+fn test_fit_x_action_keeps_y(cx: &mut TestAppContext) {
+    use gpui::Focusable;
+    use gpui_chart::chart_view::FitX;
+    use gpui_chart::data_types::{AxisEdge, ColorOp, PlotPoint};
+    use gpui_chart::{AxisState, ChartView, LinePlot, PaneState, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(20.0, 40.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.y_axes.push(AxisState::new(
+            cx.new(|_| AxisRange::new(-5.0, 5.0)),
+            AxisEdge::Right,
+            px(60.0),
+            "Y".into(),
+        ));
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        pane.series.push(Series::new("s", LinePlot::new(points)));
+        c.panes.push(pane);
+        c.user_interacted = true;
+    });
+    // A pane on its own X axis, far from the shared one
+    let own_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("own".into(), 1.0);
+        let points = (5000..=5100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: 1.0,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        pane.series.push(Series::new("o", LinePlot::new(points)));
+        c.panes.push(pane);
+        c.set_pane_x_axis(1, Some(own_x.clone()), cx);
+        c.user_interacted = true;
+    });
+
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    window
+        .update(&mut vcx, |view, window, cx| window.focus(&view.focus_handle(cx)))
+        .unwrap();
+    vcx.dispatch_action(FitX);
+
+    chart_entity.read_with(&vcx, |c, cx| {
+        let x = c.shared_x_axis.read(cx);
+        // Full extent with a 5% margin
+        assert!(x.min < 0.0 && x.min > -6.0, "{}", x.min);
+        assert!(x.max > 100.0 && x.max < 106.0, "{}", x.max);
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert_eq!((y.min, y.max), (-5.0, 5.0));
+        assert!(!c.user_interacted);
+        // The own X axis is fitted to its pane alone
+        let own = own_x.read(cx);
+        assert!(own.min < 5000.0 && own.min > 4994.0, "{}", own.min);
+        assert!(own.max > 5100.0 && own.max < 5106.0, "{}", own.max);
+    });
+}
+
+#[gpui::test]
+fn test_chart_with_shared_state_joins_group(cx: &mut TestAppContext) {
+    use gpui_chart::ChartTheme;
+
+    let (first, shared_x, shared_state) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        let first = cx.new(|cx| Chart::new(shared_x.clone(), shared_state.clone(), cx));
+        (first, shared_x, shared_state)
+    });
+    first.update(cx, |c, cx| {
+        c.set_theme(ChartTheme::light(), cx);
+        c.set_crosshair_enabled(false, cx);
+    });
+
+    let second = cx.update(|cx| {
+        cx.new(|cx| Chart::with_shared_state(shared_x.clone(), shared_state.clone(), cx))
+    });
+
+    // Joining does not reset the group's settings and adopts its theme
+    second.read_with(cx, |c, cx| {
+        assert!(!c.shared_state.read(cx).crosshair_enabled);
+        assert_eq!(c.theme.background, ChartTheme::light().background);
+    });
+
+    // Hover and X range are seen by both charts
+    shared_state.update(cx, |s, _| s.hover_x = Some(42.0));
+    shared_x.update(cx, |x, _| x.pan(10.0));
+    second.read_with(cx, |c, cx| {
+        assert_eq!(c.shared_state.read(cx).hover_x, Some(42.0));
+        assert_eq!(c.shared_x_axis.read(cx).min, 10.0);
+    });
+}
+
+#[gpui::test]
+fn test_axis_format_setters_validate_index(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, AxisFormat, TimeUnit};
+    use gpui_chart::AxisState;
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+
+    let time = AxisFormat::Time(TimeUnit::Milliseconds, None);
+    chart_entity.update(cx, |c, cx| {
+        c.x_axes.push(AxisState::new(
+            c.shared_x_axis.clone(),
+            AxisEdge::Bottom,
+            px(25.0),
+            "X".into(),
+        ));
+        c.shared_x_axis.update(cx, |r, _| r.update_ticks_if_needed(5, None));
+        c.add_pane_at(0, 1.0, cx);
+
+        assert!(c.set_x_axis_format(0, time, cx));
+        assert!(!c.set_x_axis_format(1, time, cx));
+        assert!(c.set_y_axis_format(0, 0, time, cx));
+        assert!(!c.set_y_axis_format(0, 1, time, cx));
+        assert!(!c.set_y_axis_format(1, 0, time, cx));
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        assert_eq!(c.x_axes[0].format, time);
+        assert_eq!(c.panes[0].y_axes[0].format, time);
+        // Ticks are recomputed on the next paint
+        assert!(c.shared_x_axis.read(cx).cached_ticks.is_empty());
+    });
+}
+
+#[gpui::test]
+fn test_placeholder_state(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, PaneState, Series};
+
+    let mut pane = PaneState::new("p".into(), 1.0);
+    assert!(!pane.has_data());
+    pane.series.push(Series::new("empty", LinePlot::new(vec![])));
+    assert!(!pane.has_data());
+    pane.series.push(Series::new(
+        "s",
+        LinePlot::new(vec![PlotPoint {
+            x: 0.0,
+            y: 1.0,
+            color_op: ColorOp::None,
+        }]),
+    ));
+    assert!(pane.has_data());
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        assert!(!c.loading);
+        c.panes.push(pane);
+        c.set_loading(true, cx);
+    });
+    // Renders the placeholder pane without panicking
+    cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+    chart_entity.read_with(cx, |c, _| assert!(c.loading));
+}
+
+#[gpui::test]
+fn test_follow_latest_tracks_appends_until_user_moves(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{
+        ColorOp, FollowWindow, PlotData, PlotPoint, StreamingDataSource,
+    };
+    use gpui_chart::{LinePlot, PaneState, Series};
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    let point = |x: f64| {
+        PlotData::Point(PlotPoint {
+            x,
+            y: x,
+            color_op: ColorOp::None,
+        })
+    };
+    let plot = Arc::new(RwLock::new(LinePlot::with_source(Box::new(
+        StreamingDataSource::new(1000),
+    ))));
+    for i in 0..100 {
+        plot.write().source.add_data(point(i as f64));
+    }
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let x_range = |cx: &mut TestAppContext| {
+        chart_entity.read_with(cx, |c, cx| {
+            let r = c.shared_x_axis.read(cx);
+            (r.min, r.max)
+        })
+    };
+
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.series.push(Series::from_shared("live", plot.clone()));
+        c.panes.push(pane);
+        c.set_follow_latest(Some(FollowWindow::Points(10)), cx);
+    });
+    assert_eq!(x_range(cx), (90.0, 99.0));
+
+    plot.write().source.add_data(point(100.0));
+    chart_entity.update(cx, |c, cx| c.data_appended(cx));
+    assert_eq!(x_range(cx), (91.0, 100.0));
+
+    // The user pans away (as the input handlers do): following pauses
+    chart_entity.update(cx, |c, cx| {
+        c.shared_x_axis.update(cx, |r, _| r.pan(-30.0));
+        c.user_interacted = true;
+    });
+    plot.write().source.add_data(point(101.0));
+    chart_entity.update(cx, |c, cx| c.data_appended(cx));
+    assert_eq!(x_range(cx), (61.0, 70.0));
+    chart_entity.read_with(cx, |c, _| assert!(!c.following));
+
+    // Going live again jumps to the newest data, here with a time window
+    chart_entity.update(cx, |c, cx| {
+        c.set_follow_latest(Some(FollowWindow::Span(50.0)), cx)
+    });
+    assert_eq!(x_range(cx), (51.0, 101.0));
+    chart_entity.read_with(cx, |c, _| assert!(c.following));
+}
+
+#[gpui::test]
+fn test_follow_latest_keeps_own_x_axes_apart(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, FollowWindow, PlotPoint};
+    use gpui_chart::{LinePlot, PaneState, Series};
+
+    // One pane on the shared X axis, one on its own clock far ahead of it
+    let line = |start: usize| {
+        LinePlot::new(
+            (start..start + 100)
+                .map(|i| PlotPoint {
+                    x: i as f64,
+                    y: 1.0,
+                    color_op: ColorOp::None,
+                })
+                .collect(),
+        )
+    };
+    let chart_entity = cx.update(|cx| Chart::standalone(0.0, 100.0, cx));
+    let own_x = cx.new(|_| AxisRange::new(5000.0, 5100.0));
+    chart_entity.update(cx, |c, cx| {
+        for (id, start) in [("shared", 0), ("own", 5000)] {
+            let mut pane = PaneState::new(id.into(), 1.0);
+            pane.series.push(Series::new(id, line(start)));
+            c.panes.push(pane);
+        }
+        c.set_pane_x_axis(1, Some(own_x.clone()), cx);
+        c.set_follow_latest(Some(FollowWindow::Span(10.0)), cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        let shared = c.shared_x_axis.read(cx);
+        assert_eq!((shared.min, shared.max), (89.0, 99.0));
+        let own = own_x.read(cx);
+        assert_eq!((own.min, own.max), (5089.0, 5099.0));
+    });
+}
+
+#[gpui::test]
+fn test_request_render_coalesces_within_a_frame(cx: &mut TestAppContext) {
+    use gpui_chart::chart::RENDER_COALESCE_INTERVAL;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let notifications = Rc::new(Cell::new(0));
+    let _sub = cx.update(|cx| {
+        let notifications = notifications.clone();
+        cx.observe(&chart_entity, move |_, _| notifications.set(notifications.get() + 1))
+    });
+
+    chart_entity.update(cx, |c, cx| {
+        for _ in 0..100 {
+            c.request_render(cx);
+        }
+    });
+    cx.run_until_parked();
+    assert_eq!(notifications.get(), 0);
+
+    cx.executor().advance_clock(RENDER_COALESCE_INTERVAL);
+    cx.run_until_parked();
+    assert_eq!(notifications.get(), 1);
+
+    // A new burst schedules a new update
+    chart_entity.update(cx, |c, cx| c.request_render(cx));
+    cx.executor().advance_clock(RENDER_COALESCE_INTERVAL);
+    cx.run_until_parked();
+    assert_eq!(notifications.get(), 2);
+}
+
+#[gpui::test]
+fn test_render_stats_recorded_without_debug_mode(cx: &mut TestAppContext) {
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.read_with(cx, |c, cx| assert_eq!(c.render_stats(cx).frames, 0));
+
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    chart_entity.read_with(cx, |c, cx| {
+        let stats = c.render_stats(cx);
+        assert!(stats.frames >= 1);
+        assert!(stats.avg_render <= stats.last_render * stats.frames as u32);
+        assert_eq!(stats.total_paint, stats.pane_paint.values().sum());
+    });
+}
+
+#[test]
+fn test_render_history_keeps_a_rolling_window() {
+    use gpui_chart::data_types::{RenderHistory, RENDER_STATS_WINDOW};
+    use std::time::Duration;
+
+    let mut history = RenderHistory::default();
+    assert_eq!(history.avg_render(), Duration::ZERO);
+    for ms in 0..(RENDER_STATS_WINDOW as u64 + 10) {
+        history.record(Duration::from_millis(ms), Duration::from_millis(2));
+    }
+    assert_eq!(history.renders.len(), RENDER_STATS_WINDOW);
+    assert_eq!(history.last_render, Duration::from_millis(RENDER_STATS_WINDOW as u64 + 9));
+    // Oldest 10 frames evicted: average of 10..130
+    assert_eq!(
+        history.avg_render(),
+        Duration::from_micros((10 + RENDER_STATS_WINDOW as u64 + 9) * 1000 / 2)
+    );
+    assert_eq!(history.avg_paint(), Duration::from_millis(2));
+}
+
+#[gpui::test]
+fn test_derived_axis_mirrors_source(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::AxisEdge;
+    use gpui_chart::{DerivedAxis, PaneState};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let (celsius, fahrenheit) = chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("temp".into(), 1.0);
+        let celsius = cx.new(|_| AxisRange::new(0.0, 100.0));
+        pane.y_axes.push(gpui_chart::AxisState::new(
+            celsius.clone(),
+            AxisEdge::Left,
+            px(60.0),
+            "°C".into(),
+        ));
+        c.panes.push(pane);
+        assert!(c
+            .add_derived_y_axis(0, DerivedAxis::affine(5, 1.8, 32.0), AxisEdge::Right, "°F", cx)
+            .is_none());
+        let idx = c
+            .add_derived_y_axis(0, DerivedAxis::affine(0, 1.8, 32.0), AxisEdge::Right, "°F", cx)
+            .unwrap();
+        (celsius, c.panes[0].y_axes[idx].entity.clone())
+    });
+    let range = |e: &gpui::Entity<AxisRange>, cx: &mut TestAppContext| {
+        e.read_with(cx, |r, _| (r.min, r.max))
+    };
+    assert_eq!(range(&fahrenheit, cx), (32.0, 212.0));
+
+    // Pan/zoom of the source is mirrored once the chart is notified (as the
+    // input handlers do), without waiting for a render
+    let notify = |cx: &mut TestAppContext| chart_entity.update(cx, |_, cx| cx.notify());
+    celsius.update(cx, |r, _| {
+        r.min = 10.0;
+        r.max = 20.0;
+    });
+    notify(cx);
+    assert_eq!(range(&fahrenheit, cx), (50.0, 68.0));
+
+    // Moving the derived axis drives the source through the inverse
+    fahrenheit.update(cx, |r, _| r.max = 86.0);
+    notify(cx);
+    let (c_min, c_max) = range(&celsius, cx);
+    assert!((c_min - 10.0).abs() < 1e-9 && (c_max - 30.0).abs() < 1e-9);
+    assert_eq!(range(&fahrenheit, cx), (50.0, 86.0));
+
+    // A decreasing mapping keeps min < max
+    let negated = chart_entity.update(cx, |c, cx| {
+        let idx = c
+            .add_derived_y_axis(0, DerivedAxis::affine(0, -1.0, 0.0), AxisEdge::Right, "-°C", cx)
+            .unwrap();
+        c.panes[0].y_axes[idx].entity.clone()
+    });
+    let (n_min, n_max) = range(&negated, cx);
+    assert!((n_min + 30.0).abs() < 1e-9 && (n_max + 10.0).abs() < 1e-9);
+    negated.update(cx, |r, _| r.min = -40.0);
+    notify(cx);
+    let (c_min, c_max) = range(&celsius, cx);
+    assert!((c_min - 10.0).abs() < 1e-9 && (c_max - 40.0).abs() < 1e-9);
+
+    // Renders without panicking
+    cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+}
+
+#[gpui::test]
+fn test_locked_down_controls(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::LegendConfig;
+
+    assert!(LegendConfig::default().show_buttons);
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        assert!(c.show_pane_controls);
+        c.add_pane_at(0, 1.0, cx);
+        c.set_show_pane_controls(false, cx);
+    });
+    // Renders without the pane and legend buttons
+    cx.add_window(|_window, cx| {
+        let mut view = ChartView::new(chart_entity.clone(), cx);
+        view.set_legend_config(LegendConfig {
+            show_buttons: false,
+            ..Default::default()
+        });
+        view
+    });
+    cx.run_until_parked();
+    chart_entity.read_with(cx, |c, _| assert!(!c.show_pane_controls));
+}
+
+#[gpui::test]
+fn test_cycle_aggregation_mode(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AggregationMode, ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, PaneState, Series};
+
+    let line = |n: usize| {
+        LinePlot::new(
+            (0..n)
+                .map(|i| PlotPoint {
+                    x: i as f64,
+                    y: (i as f64).sin(),
+                    color_op: ColorOp::None,
+                })
+                .collect(),
+        )
+    };
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let mode_of = |c: &Chart, i: usize| {
+        c.panes[0].series[i]
+            .plot
+            .read()
+            .data_source()
+            .unwrap()
+            .aggregation_mode()
+    };
+
+    chart_entity.update(cx, |c, cx| {
+        assert_eq!(c.cycle_aggregation_mode(cx), None);
+
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.series.push(Series::new("a", line(5000)));
+        pane.series.push(Series::new("b", line(10)));
+        c.panes.push(pane);
+
+        assert_eq!(c.cycle_aggregation_mode(cx), Some(AggregationMode::MinMax));
+        assert_eq!(c.cycle_aggregation_mode(cx), Some(AggregationMode::LTTB));
+        assert_eq!(mode_of(c, 1), AggregationMode::LTTB);
+
+        // Only the selected series when there is one
+        c.set_selected_series(Some("a".into()), cx);
+        assert_eq!(c.cycle_aggregation_mode(cx), Some(AggregationMode::StableLTTB));
+        assert_eq!(c.cycle_aggregation_mode(cx), Some(AggregationMode::M4));
+        assert_eq!(mode_of(c, 0), AggregationMode::M4);
+        assert_eq!(mode_of(c, 1), AggregationMode::LTTB);
+
+        // A source with a fixed mode reports the one it kept
+        let streaming = gpui_chart::data_types::StreamingDataSource::new(10);
+        c.panes[0]
+            .series
+            .push(Series::new("s", LinePlot::with_source(Box::new(streaming))));
+        c.set_selected_series(Some("s".into()), cx);
+        assert_eq!(c.cycle_aggregation_mode(cx), Some(AggregationMode::M4));
+    });
+}
+
+#[gpui::test]
+fn test_pane_baseline(cx: &mut TestAppContext) {
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        assert_eq!(c.panes[0].baseline, None);
+        c.set_pane_baseline(0, Some(0.0), cx);
+        c.set_pane_baseline(3, Some(1.0), cx);
+        assert_eq!(c.panes[0].baseline, Some(0.0));
+    });
+    cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+}
+
+#[gpui::test]
+fn test_pane_with_own_x_axis(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let own_x = cx.update(|cx| {
+        let own_x = cx.new(|_| AxisRange::new(50.0, 60.0));
+        chart_entity.update(cx, |c, cx| {
+            c.add_pane_at(0, 1.0, cx);
+            let points = (0..=100)
+                .map(|i| PlotPoint {
+                    x: i as f64,
+                    y: i as f64,
+                    color_op: ColorOp::None,
+                })
+                .collect();
+            c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+            c.set_pane_x_axis(0, Some(own_x.clone()), cx);
+            assert_eq!(c.pane_x_axis(0).map(|a| a.entity_id()), Some(own_x.entity_id()));
+            assert!(c.pane_x_axis(1).is_none());
+            c.fit_pane_y(0, cx);
+        });
+        own_x
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        // Y is fitted over the pane's own X window, not the shared 0..100
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert!(y.min > 40.0 && y.max < 70.0, "{:?}", (y.min, y.max));
+    });
+
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+    let mut visual_cx = gpui::VisualTestContext::from_window(window.into(), cx);
+    let center = Point::new(px(400.0), px(300.0));
+    visual_cx.simulate_mouse_down(center, MouseButton::Middle, Default::default());
+    visual_cx.simulate_mouse_move(
+        center + Point::new(px(100.0), px(-100.0)),
+        Some(MouseButton::Middle),
+        Default::default(),
+    );
+
+    chart_entity.read_with(&visual_cx, |c, cx| {
+        let shared = c.shared_x_axis.read(cx);
+        assert_eq!((shared.min, shared.max), (0.0, 100.0));
+        assert_ne!(own_x.read(cx).span(), 10.0);
+    });
+}
+
+#[gpui::test]
+fn test_probe_maps_point_without_touching_state(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: 2.0 * i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+    });
+
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    window
+        .update(cx, |view, _window, cx| {
+            let probe = view.probe(Point::new(px(400.0), px(300.0)), cx).unwrap();
+            assert_eq!(probe.pane_idx, 0);
+            assert!(probe.data_x > 0.0 && probe.data_x < 100.0);
+            assert_eq!(probe.per_series.len(), 1);
+            assert_eq!(probe.per_series[0].0, "s");
+            let value = probe.per_series[0].1.unwrap();
+            assert!((value - 2.0 * probe.data_x).abs() < 1e-6);
+            assert_eq!(probe.data_y_by_axis.len(), 1);
+
+            assert!(view.probe(Point::new(px(-10.0), px(-10.0)), cx).is_none());
+        })
+        .unwrap();
+
+    chart_entity.read_with(cx, |c, cx| {
+        assert!(c.shared_state.read(cx).hover_x.is_none());
+    });
+}
+
+#[gpui::test]
+fn test_debug_overlay_without_continuous_repaint(cx: &mut TestAppContext) {
+    let mut state = SharedPlotState::default();
+    assert!(!state.repaints_continuously());
+    state.debug_mode = true;
+    assert!(state.repaints_continuously());
+    state.continuous_repaint = Some(false);
+    assert!(!state.repaints_continuously());
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState {
+            debug_mode: true,
+            ..Default::default()
+        });
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.set_continuous_repaint(Some(false), cx);
+    });
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    chart_entity.read_with(cx, |c, cx| {
+        let state = c.shared_state.read(cx);
+        assert!(state.debug_mode && !state.repaints_continuously());
+        assert!(c.render_stats(cx).frames >= 1);
+    });
+}
+
+#[gpui::test]
+fn test_plot_padding_insets_panes(cx: &mut TestAppContext) {
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| c.add_pane_at(0, 1.0, cx));
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    let near_top = Point::new(px(400.0), px(5.0));
+    let below_padding = Point::new(px(400.0), px(45.0));
+    window
+        .update(cx, |view, _window, cx| {
+            assert!(view.probe(near_top, cx).is_some());
+        })
+        .unwrap();
+
+    chart_entity.update(cx, |c, cx| c.set_plot_padding(gpui::Edges::all(px(20.0)), cx));
+    cx.run_until_parked();
+    window
+        .update(cx, |view, _window, cx| {
+            // Border-to-axis plus axis-to-pane padding: 40px above the pane
+            assert!(view.probe(near_top, cx).is_none());
+            assert!(view.probe(below_padding, cx).is_some());
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+fn test_sparkline_fills_bounds_and_fits(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let (chart_entity, shared_x) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        let chart = cx.new(|cx| Chart::sparkline(shared_x.clone(), shared_state, cx));
+        (chart, shared_x)
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let points = (0..=50)
+            .map(|i| PlotPoint {
+                x: 1000.0 + i as f64,
+                y: -(i as f64),
+                color_op: ColorOp::None,
+            })
+            .collect();
+        c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+        c.data_appended(cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        assert!(!c.show_pane_controls);
+        // The crosshair is hidden by the renderer, not for the whole group
+        assert!(c.shared_state.read(cx).crosshair_enabled);
+        let x = shared_x.read(cx);
+        assert!(x.min <= 1000.0 && x.max >= 1050.0, "{:?}", (x.min, x.max));
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert!(y.min <= -50.0 && y.max >= 0.0 && y.max < 10.0, "{:?}", (y.min, y.max));
+    });
+
+    // Only data changes refit the view, not other updates
+    chart_entity.update(cx, |c, cx| {
+        c.shared_x_axis.update(cx, |x, _| {
+            x.min = 1010.0;
+            x.max = 1020.0;
+        });
+        c.set_show_pane_controls(false, cx);
+    });
+    shared_x.read_with(cx, |x, _| assert_eq!((x.min, x.max), (1010.0, 1020.0)));
+    chart_entity.update(cx, |c, cx| c.data_appended(cx));
+    shared_x.read_with(cx, |x, _| assert!(x.min <= 1000.0 && x.max >= 1050.0));
+
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+    window
+        .update(cx, |view, window, cx| {
+            // No axis gutters: the pane reaches every edge of the view
+            let size = window.viewport_size();
+            for corner in [
+                Point::new(px(2.0), px(2.0)),
+                Point::new(size.width - px(2.0), size.height - px(2.0)),
+            ] {
+                assert!(view.probe(corner, cx).is_some(), "{:?}", corner);
+            }
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+fn test_auto_fit_on_first_data_fits_once(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotData, PlotPoint, StreamingDataSource};
+    use gpui_chart::{LinePlot, Series};
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    let point = |x: f64| {
+        PlotData::Point(PlotPoint {
+            x,
+            y: x * 2.0,
+            color_op: ColorOp::None,
+        })
+    };
+    let plot = Arc::new(RwLock::new(LinePlot::with_source(Box::new(
+        StreamingDataSource::new(1000),
+    ))));
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 10.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let x_range = |cx: &mut TestAppContext| {
+        chart_entity.read_with(cx, |c, cx| {
+            let r = c.shared_x_axis.read(cx);
+            (r.min, r.max)
+        })
+    };
+
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let mut series = Series::new("live", LinePlot::new(vec![]));
+        series.plot = plot.clone();
+        c.panes[0].series.push(series);
+        c.set_auto_fit_on_first_data(true, cx);
+        // No data yet: the initial window stays
+        c.data_appended(cx);
+    });
+    assert_eq!(x_range(cx), (0.0, 10.0));
+
+    for i in 500..=600 {
+        plot.write().source.add_data(point(i as f64));
+    }
+    chart_entity.update(cx, |c, cx| c.data_appended(cx));
+    let (x_min, x_max) = x_range(cx);
+    assert!(x_min <= 500.0 && x_max >= 600.0 && x_min > 400.0, "{:?}", (x_min, x_max));
+    chart_entity.read_with(cx, |c, cx| {
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert!(y.min <= 1000.0 && y.max >= 1200.0, "{:?}", (y.min, y.max));
+    });
+
+    // Later data leaves the user's window alone
+    chart_entity.update(cx, |c, cx| {
+        c.shared_x_axis.update(cx, |r, _| {
+            r.min = 550.0;
+            r.max = 560.0;
+        });
+    });
+    for i in 601..=700 {
+        plot.write().source.add_data(point(i as f64));
+    }
+    chart_entity.update(cx, |c, cx| c.data_appended(cx));
+    assert_eq!(x_range(cx), (550.0, 560.0));
+}
+
+#[gpui::test]
+fn test_visible_decimated_matches_painted_points(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(10_000.0, 60_000.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let points = (0..100_000)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: (i as f64 * 0.01).sin(),
+                color_op: ColorOp::None,
+            })
+            .collect();
+        c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        // Not painted yet: no point budget to reuse
+        assert!(c.visible_decimated("s", cx).is_none());
+    });
+
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+    chart_entity.read_with(cx, |c, cx| {
+        let exported = c.visible_decimated("s", cx).unwrap();
+        let drawn = c.panes[0].series[0].plot.read().drawn_points().unwrap();
+        assert!(!exported.is_empty() && exported.len() < 50_000);
+        assert_eq!(exported.len(), drawn);
+        // In data units, over the visible window (give or take an LOD bucket)
+        assert!(exported.iter().all(|p| p.x() >= 9_900.0 && p.x() <= 60_100.0));
+        assert!(c.visible_decimated("missing", cx).is_none());
+    });
+}
+
+#[gpui::test]
+fn test_render_into_target_bounds(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::sparkline(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let points = (0..=10)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+        c.data_appended(cx);
+    });
+
+    let target = gpui::Bounds::new(
+        Point::new(px(100.0), px(50.0)),
+        gpui::size(px(300.0), px(200.0)),
+    );
+    // Embedded in a larger layout, next to a sibling taking the full width
+    struct Host {
+        chart_view: gpui::Entity<ChartView>,
+    }
+    impl gpui::Render for Host {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            _cx: &mut gpui::Context<Self>,
+        ) -> impl gpui::IntoElement {
+            use gpui::{div, ParentElement, Styled};
+            div()
+                .size_full()
+                .relative()
+                .flex()
+                .flex_col()
+                .child(div().h(px(40.0)).w_full())
+                .child(self.chart_view.clone())
+        }
+    }
+    let window = cx.add_window(|_window, cx| {
+        let chart_view = cx.new(|cx| {
+            let mut view = ChartView::new(chart_entity.clone(), cx);
+            view.set_target_bounds(Some(target));
+            view
+        });
+        Host { chart_view }
+    });
+    cx.run_until_parked();
+    window
+        .update(cx, |host, _window, cx| {
+            let view = host.chart_view.read(cx);
+            // Sparkline panes have no gutters: they cover exactly the target rect
+            let inside = [
+                Point::new(px(102.0), px(52.0)),
+                Point::new(px(398.0), px(248.0)),
+            ];
+            let outside = [
+                Point::new(px(98.0), px(100.0)),
+                Point::new(px(200.0), px(48.0)),
+                Point::new(px(402.0), px(100.0)),
+                Point::new(px(200.0), px(252.0)),
+            ];
+            for p in inside {
+                assert!(view.probe(p, cx).is_some(), "{:?}", p);
+            }
+            for p in outside {
+                assert!(view.probe(p, cx).is_none(), "{:?}", p);
+            }
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+fn test_data_overflow_beyond_visible_range_and_limits(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, DataOverflow, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let (chart_entity, shared_x) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        let chart = cx.new(|cx| Chart::new(shared_x.clone(), shared_state, cx));
+        (chart, shared_x)
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+        c.fit_to_data(cx);
+        c.set_show_overflow_indicators(true, cx);
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        // Fitted: nothing beyond the edges
+        assert_eq!(c.data_overflow(0, cx), Some(DataOverflow::default()));
+        assert_eq!(c.data_overflow(1, cx), None);
+    });
+
+    shared_x.update(cx, |x, _| {
+        x.min = 20.0;
+        x.max = 60.0;
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let o = c.data_overflow(0, cx).unwrap();
+        assert!(o.left && o.right && !o.top && !o.bottom, "{o:?}");
+    });
+
+    // Above and below only count the data within the visible X range (20..60)
+    chart_entity.update(cx, |c, cx| {
+        c.panes[0].y_axes[0].entity.update(cx, |y, _| {
+            y.min = 10.0;
+            y.max = 70.0;
+        });
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let o = c.data_overflow(0, cx).unwrap();
+        assert!(!o.top && !o.bottom, "{o:?}");
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.panes[0].y_axes[0].entity.update(cx, |y, _| {
+            y.min = 30.0;
+            y.max = 50.0;
+        });
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let o = c.data_overflow(0, cx).unwrap();
+        assert!(o.top && o.bottom, "{o:?}");
+    });
+
+    // Data past an axis limit counts even when the view is at the limit
+    shared_x.update(cx, |x, _| {
+        x.min = 0.0;
+        x.max = 100.0;
+        x.max_limit = Some(80.0);
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let o = c.data_overflow(0, cx).unwrap();
+        assert!(!o.left && o.right, "{o:?}");
+    });
+
+    // Hidden series are ignored
+    chart_entity.update(cx, |c, _| {
+        c.panes[0].hidden_series.insert("s".to_string());
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        assert!(!c.data_overflow(0, cx).unwrap().any());
+    });
+
+    // Painting the indicators does not break rendering
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+}
+
+#[gpui::test]
+fn test_fit_to_series_ignores_siblings(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let (chart_entity, shared_x) = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        let chart = cx.new(|cx| Chart::new(shared_x.clone(), shared_state, cx));
+        (chart, shared_x)
+    });
+    let line = |n: usize, scale: f64| {
+        LinePlot::new(
+            (0..=n)
+                .map(|i| PlotPoint {
+                    x: 100.0 + i as f64,
+                    y: scale * i as f64 / n as f64,
+                    color_op: ColorOp::None,
+                })
+                .collect(),
+        )
+    };
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.panes[0].series.push(Series::new("small", line(10, 1.0)));
+        c.panes[0].series.push(Series::new("big", line(100, 1_000.0)));
+        assert!(!c.fit_to_series("missing", cx));
+        assert!(c.fit_to_series("small", cx));
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let x = shared_x.read(cx);
+        assert!(x.min <= 100.0 && x.max >= 110.0 && x.max < 112.0, "{:?}", (x.min, x.max));
+        // "big" reaches 100 over the same X range but is ignored
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert!(y.min <= 0.0 && y.max >= 1.0 && y.max < 2.0, "{:?}", (y.min, y.max));
+    });
+}
+
+#[gpui::test]
+fn test_user_interacted_set_by_input_cleared_by_fit(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        c.panes[0].series.push(Series::new("s", LinePlot::new(points)));
+    });
+
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+    assert!(!chart_entity.read_with(cx, |c, _| c.user_interacted));
+
+    let mut visual_cx = gpui::VisualTestContext::from_window(window.into(), cx);
+    let start = Point::new(px(300.0), px(200.0));
+    let end = start + Point::new(px(100.0), px(100.0));
+    visual_cx.simulate_mouse_down(start, MouseButton::Right, Default::default());
+    visual_cx.simulate_mouse_move(end, Some(MouseButton::Right), Default::default());
+    visual_cx.simulate_mouse_up(end, MouseButton::Right, Default::default());
+    assert!(chart_entity.read_with(&visual_cx, |c, _| c.user_interacted));
+
+    chart_entity.update(&mut visual_cx, |c, cx| c.fit_to_data(cx));
+    assert!(!chart_entity.read_with(&visual_cx, |c, _| c.user_interacted));
+
+    // Programmatic axis changes are not user interaction
+    chart_entity.update(&mut visual_cx, |c, cx| {
+        c.shared_x_axis.update(cx, |x, _| x.pan(10.0));
+    });
+    assert!(!chart_entity.read_with(&visual_cx, |c, _| c.user_interacted));
+
+    visual_cx.simulate_mouse_down(start, MouseButton::Left, Default::default());
+    visual_cx.simulate_mouse_move(end, Some(MouseButton::Left), Default::default());
+    visual_cx.simulate_mouse_up(end, MouseButton::Left, Default::default());
+    assert!(chart_entity.read_with(&visual_cx, |c, _| c.user_interacted));
+
+    chart_entity.update(&mut visual_cx, |c, cx| c.fit_y_to_visible_x(cx));
+    assert!(!chart_entity.read_with(&visual_cx, |c, _| c.user_interacted));
+}
+
+#[gpui::test]
+fn test_named_axis_follows_reordering(cx: &mut TestAppContext) {
+    use gpui_chart::chart::AxisState;
+    use gpui_chart::data_types::AxisEdge;
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let axis = |label: &str, cx: &mut gpui::Context<Chart>| {
+        let entity = cx.new(|_| AxisRange::new(0.0, 1.0));
+        AxisState::new(entity, AxisEdge::Right, px(60.0), label.to_string())
+    };
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let rsi = axis("RSI", cx);
+        c.panes[0].y_axes.push(rsi);
+        c.panes[0]
+            .series
+            .push(Series::new("rsi", LinePlot::new(vec![])).on_named_axis("rsi"));
+        c.panes[0]
+            .series
+            .push(Series::new("fixed", LinePlot::new(vec![])).on_axis(1));
+        assert!(!c.set_y_axis_name(0, 5, "rsi", cx));
+        assert!(c.set_y_axis_name(0, 1, "rsi", cx));
+        assert_eq!(c.panes[0].y_axis_by_name("rsi"), Some(1));
+        assert_eq!(c.panes[0].series[0].y_axis_id.0, 1);
+
+        // Inserting an axis before it shifts the index-based series only
+        let volume = axis("Volume", cx);
+        c.panes[0].y_axes.insert(1, volume);
+        cx.notify();
+    });
+    chart_entity.read_with(cx, |c, _| {
+        assert_eq!(c.panes[0].series[0].y_axis_id.0, 2);
+        assert_eq!(c.panes[0].series[1].y_axis_id.0, 1);
+    });
+}
+
+#[gpui::test]
+fn test_series_painted_by_z_order(cx: &mut TestAppContext) {
+    use gpui::{App, Window};
+    use gpui_chart::plot_types::PlotRenderer;
+    use gpui_chart::{PlotTransform, Series};
+    use std::sync::{Arc, Mutex};
+
+    /// Records the order of its background and foreground passes.
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+    impl PlotRenderer for Recorder {
+        fn render_background(
+            &self,
+            _: &mut Window,
+            _: &PlotTransform,
+            id: &str,
+            _: &mut App,
+            _: &SharedPlotState,
+        ) {
+            self.0.lock().unwrap().push(format!("bg:{id}"));
+        }
+        fn render(
+            &self,
+            _: &mut Window,
+            _: &PlotTransform,
+            id: &str,
+            _: &mut App,
+            _: &SharedPlotState,
+        ) {
+            self.0.lock().unwrap().push(id.to_string());
+        }
+        fn get_min_max(&self) -> Option<(f64, f64, f64, f64)> {
+            Some((0.0, 1.0, 0.0, 1.0))
+        }
+        fn get_y_range(&self, _: f64, _: f64) -> Option<(f64, f64)> {
+            Some((0.0, 1.0))
+        }
+    }
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let chart = cx.update(|cx| Chart::standalone(0.0, 1.0, cx));
+    chart.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        for (id, z) in [("top", 1), ("a", 0), ("back", -1), ("b", 0)] {
+            c.panes[0].series.push(Series::new(id, Recorder(log.clone())).with_z_order(z));
+        }
+    });
+    let _window = cx.add_window(|_window, cx| ChartView::new(chart.clone(), cx));
+    cx.run_until_parked();
+
+    let log = log.lock().unwrap();
+    let frame = &log[..8];
+    assert_eq!(frame, ["bg:back", "bg:a", "bg:b", "bg:top", "back", "a", "b", "top"]);
+}
+
+#[gpui::test]
+fn test_orphan_axis_cleanup_keeps_named_axes(cx: &mut TestAppContext) {
+    use gpui_chart::chart::AxisState;
+    use gpui_chart::data_types::AxisEdge;
+    use gpui_chart::{LinePlot, Series};
+
+    let chart_entity = cx.update(|cx| Chart::standalone(0.0, 1.0, cx));
+    let axis = |label: &str, cx: &mut gpui::Context<Chart>| {
+        let entity = cx.new(|_| AxisRange::new(0.0, 1.0));
+        AxisState::new(entity, AxisEdge::Right, px(60.0), label.to_string())
+    };
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        let rsi = axis("RSI", cx);
+        c.panes[0].y_axes.push(rsi);
+        c.panes[0]
+            .series
+            .push(Series::new("rsi", LinePlot::new(vec![])).on_named_axis("rsi"));
+        c.panes[0].series.push(Series::new("price", LinePlot::new(vec![])));
+        c.set_y_axis_name(0, 1, "rsi", cx);
+        c.toggle_series_isolation(0, "price", cx);
+        assert_eq!(c.panes[0].y_axes.len(), 3);
+
+        // An axis inserted in the same update leaves the named series' index
+        // stale while the isolated series goes back to axis 0
+        let volume = axis("Volume", cx);
+        c.panes[0].y_axes.insert(1, volume);
+        c.toggle_series_isolation(0, "price", cx);
+
+        let rsi_axis = c.panes[0].y_axis_by_name("rsi").expect("named axis removed");
+        assert_eq!(c.panes[0].series[0].y_axis_id.0, rsi_axis);
+        assert_eq!(c.panes[0].series[1].y_axis_id.0, 0);
+    });
+}
+
+#[gpui::test]
+fn test_fixed_height_pane_keeps_its_pixels(cx: &mut TestAppContext) {
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.add_pane_at(1, 1.0, cx);
+        c.add_pane_at(2, 1.0, cx);
+        // Panes added within the same millisecond share their generated ID
+        for (i, pane) in c.panes.iter_mut().enumerate() {
+            pane.id = format!("p{i}");
+        }
+        assert!(!c.set_pane_fixed_height(5, Some(px(120.0)), cx));
+        assert!(c.set_pane_fixed_height(2, Some(px(120.0)), cx));
+    });
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    // Rows covered by each pane, found by probing down the middle column
+    let heights = |cx: &mut TestAppContext| {
+        window
+            .update(cx, |view, window, cx| {
+                let size = window.viewport_size();
+                let mut rows = [0usize; 3];
+                for y in 0..f32::from(size.height) as usize {
+                    let point = Point::new(size.width / 2.0, px(y as f32 + 0.5));
+                    if let Some(hit) = view.probe(point, cx) {
+                        rows[hit.pane_idx] += 1;
+                    }
+                }
+                rows
+            })
+            .unwrap()
+    };
+    let rows = heights(cx);
+    assert!((119..=121).contains(&rows[2]), "{rows:?}");
+    assert!(rows[0].abs_diff(rows[1]) <= 1, "{rows:?}");
+
+    // Weighted panes give way to a taller fixed pane
+    chart_entity.update(cx, |c, cx| {
+        c.set_pane_fixed_height(2, Some(px(200.0)), cx);
+    });
+    cx.run_until_parked();
+    let rows = heights(cx);
+    assert!((199..=201).contains(&rows[2]), "{rows:?}");
+    assert!(rows[0].abs_diff(rows[1]) <= 1, "{rows:?}");
+}
+
+#[gpui::test]
+fn test_splitter_drag_reports_pane_sizes(cx: &mut TestAppContext) {
+    use gpui_chart::ChartEvent;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events_clone = events.clone();
+    let _sub = cx.update(|cx| {
+        cx.subscribe(&chart_entity, move |_, event: &ChartEvent, _| {
+            events_clone.borrow_mut().push(event.clone());
+        })
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.add_pane_at(0, 1.0, cx);
+        c.add_pane_at(1, 1.0, cx);
+        c.panes[1].id = "second".into();
+    });
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    // The splitter sits right below the last row of the first pane
+    let (x, splitter_y) = window
+        .update(cx, |view, window, cx| {
+            let size = window.viewport_size();
+            let x = size.width / 2.0;
+            let last_row = (0..f32::from(size.height) as usize)
+                .map(|y| px(y as f32 + 0.5))
+                .filter(|&y| {
+                    view.probe(Point::new(x, y), cx)
+                        .is_some_and(|p| p.pane_idx == 0)
+                })
+                .last()
+                .unwrap();
+            (x, last_row + px(3.0))
+        })
+        .unwrap();
+
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    // A click without moving does not report anything
+    vcx.simulate_mouse_down(Point::new(x, splitter_y), MouseButton::Left, Default::default());
+    vcx.simulate_mouse_up(Point::new(x, splitter_y), MouseButton::Left, Default::default());
+    assert!(events.borrow().is_empty());
+
+    vcx.simulate_mouse_down(Point::new(x, splitter_y), MouseButton::Left, Default::default());
+    for dy in [20.0, 40.0, 60.0] {
+        vcx.simulate_mouse_move(
+            Point::new(x, splitter_y + px(dy)),
+            Some(MouseButton::Left),
+            Default::default(),
+        );
+    }
+    // Nothing while dragging, one event on release
+    assert!(events.borrow().is_empty());
+    vcx.simulate_mouse_up(
+        Point::new(x, splitter_y + px(60.0)),
+        MouseButton::Left,
+        Default::default(),
+    );
+
+    let events = events.borrow();
+    assert_eq!(events.len(), 1, "{events:?}");
+    let ChartEvent::PanesResized {
+        weights,
+        fixed_heights,
+    } = &events[0]
+    else {
+        panic!("{events:?}");
+    };
+    assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5, "{weights:?}");
+    assert!(weights[0] > weights[1], "{weights:?}");
+    assert_eq!(fixed_heights, &vec![None, None]);
+    chart_entity.read_with(&vcx, |c, _| {
+        let total = c.panes[0].weight + c.panes[1].weight;
+        assert!((c.panes[0].weight / total - weights[0]).abs() < 1e-5);
+    });
+}
+
+#[gpui::test]
+fn test_navigator_preview_follows_data(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{
+        ColorOp, PlotData, PlotDataSource, PlotPoint, StreamingDataSource,
+    };
+    use gpui_chart::{LinePlot, NavigatorView, Series};
+
+    let point = |i: usize| {
+        PlotData::Point(PlotPoint {
+            x: i as f64,
+            y: (i as f64 * 0.001).sin(),
+            color_op: ColorOp::None,
+        })
+    };
+    let mut source = StreamingDataSource::new(200_000);
+    source.set_data((0..100_000).map(point).collect());
+    let plot = LinePlot::with_source(Box::new(source));
+    let series = Series::new("nav", plot);
+    let plot = series.plot.clone();
+    let window = cx.add_window(|_window, cx| {
+        let x = cx.new(|_| AxisRange::new(0.0, 1_000.0));
+        let y = cx.new(|_| AxisRange::new(-1.0, 1.0));
+        let state = cx.new(|_| SharedPlotState::default());
+        let mut nav = NavigatorView::new(x, y, state, vec![series], cx);
+        nav.config.preview_points = Some(300);
+        nav
+    });
+    cx.run_until_parked();
+
+    window
+        .update(cx, |nav, _window, _cx| {
+            let line = &nav.preview()[0];
+            assert!(!line.is_empty() && line.len() <= 302, "{}", line.len());
+            assert_eq!(line.first().map(|p| p.0), Some(0.0));
+            assert_eq!(line.last().map(|p| p.0), Some(99_999.0));
+        })
+        .unwrap();
+
+    // New data shows up on the next render
+    plot.write().data_source_mut().unwrap().add_data(point(150_000));
+    window.update(cx, |_nav, _window, cx| cx.notify()).unwrap();
+    cx.run_until_parked();
+    window
+        .update(cx, |nav, _window, _cx| {
+            assert_eq!(nav.preview()[0].last().map(|p| p.0), Some(150_000.0));
+        })
+        .unwrap();
+}
+
+#[gpui::test]
+fn test_navigator_click_centers_view_through_its_scale(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{ColorOp, PlotPoint};
+    use gpui_chart::{LinePlot, NavigatorView, Series};
+
+    let points = (0..=1_000)
+        .map(|i| PlotPoint {
+            x: i as f64,
+            y: i as f64,
+            color_op: ColorOp::None,
+        })
+        .collect();
+    let series = Series::new("nav", LinePlot::new(points));
+    let x_axis = cx.update(|cx| cx.new(|_| AxisRange::new(0.0, 100.0)));
+    let window = cx.add_window({
+        let x_axis = x_axis.clone();
+        |_window, cx| {
+            let y = cx.new(|_| AxisRange::new(0.0, 1_000.0));
+            let state = cx.new(|_| SharedPlotState::default());
+            NavigatorView::new(x_axis, y, state, vec![series], cx)
+        }
+    });
+    let mut vcx = gpui::VisualTestContext::from_window(window.into(), cx);
+    vcx.run_until_parked();
+    let size = window.update(&mut vcx, |_, window, _| window.viewport_size()).unwrap();
+
+    let at = Point::new(size.width * 0.25, size.height * 0.5);
+    vcx.simulate_mouse_down(at, MouseButton::Left, gpui::Modifiers::none());
+    vcx.simulate_mouse_up(at, MouseButton::Left, gpui::Modifiers::none());
+    x_axis.read_with(&vcx, |x, _| {
+        // Centered on the value under the click, keeping the span
+        let center = (x.min + x.max) / 2.0;
+        assert!((center - 250.0).abs() < 5.0, "{center}");
+        assert!((x.span() - 100.0).abs() < 1e-9);
+    });
+}
+
+#[gpui::test]
+fn test_clear_series_resets_chart(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, ColorOp, PlotPoint};
+    use gpui_chart::{AxisState, ChartEvent, LinePlot, PaneState, Series};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 1.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let removed = Rc::new(RefCell::new(Vec::new()));
+    cx.update(|cx| {
+        let removed = removed.clone();
+        cx.subscribe(&chart_entity, move |_, event: &ChartEvent, _| {
+            if let ChartEvent::SeriesRemoved { series_id, .. } = event {
+                removed.borrow_mut().push(series_id.clone());
+            }
+        })
+        .detach();
+    });
+
+    let line = |offset: f64| {
+        LinePlot::new(
+            (0..=100)
+                .map(|i| PlotPoint {
+                    x: i as f64,
+                    y: offset + i as f64,
+                    color_op: ColorOp::None,
+                })
+                .collect(),
+        )
+    };
+    let kept = Series::new("kept", line(0.0));
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.y_axes.push(AxisState::new(
+            cx.new(|_| AxisRange::new(0.0, 1.0)),
+            AxisEdge::Right,
+            px(60.0),
+            "Y".into(),
+        ));
+        pane.series.push(Series::new("a", line(1_000.0)));
+        pane.series.push(kept.clone());
+        pane.hidden_series.insert("a".into());
+        c.panes.push(pane);
+        c.set_auto_fit_on_first_data(true, cx);
+        c.data_appended(cx);
+        c.clear_series(cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        assert!(c.panes[0].series.is_empty() && c.panes[0].hidden_series.is_empty());
+        assert!(!c.panes[0].has_data());
+        let x = c.shared_x_axis.read(cx);
+        assert_eq!((x.min, x.max), (0.0, 1.0));
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert_eq!((y.min, y.max), (0.0, 1.0));
+    });
+    assert_eq!(*removed.borrow(), vec!["a".to_string(), "kept".to_string()]);
+
+    // The detached series can be emptied on its own
+    kept.clear();
+    assert!(kept.plot.read().get_min_max().is_none());
+
+    // The next dataset is fitted again
+    chart_entity.update(cx, |c, cx| {
+        c.panes[0].series.push(Series::new("b", line(500.0)));
+        c.data_appended(cx);
+    });
+    chart_entity.read_with(cx, |c, cx| {
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert!(y.min < 500.0 && y.max > 600.0, "{:?}", (y.min, y.max));
+    });
+}
+
+#[gpui::test]
+fn test_grid_visibility(cx: &mut TestAppContext) {
+    use gpui_chart::{ChartTheme, GridVisibility};
+
+    assert_eq!(ChartTheme::default().grid(), GridVisibility::Both);
+    let lines = |g: GridVisibility| (g.horizontal(), g.vertical());
+    assert_eq!(lines(GridVisibility::Both), (true, true));
+    assert_eq!(lines(GridVisibility::HorizontalOnly), (true, false));
+    assert_eq!(lines(GridVisibility::VerticalOnly), (false, true));
+    assert_eq!(lines(GridVisibility::None), (false, false));
+
+    let chart = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart.update(cx, |c, cx| {
+        c.set_theme(ChartTheme::light(), cx);
+        c.set_grid_visibility(GridVisibility::HorizontalOnly, cx);
+    });
+    chart.read_with(cx, |c, cx| {
+        assert_eq!(c.theme.grid(), GridVisibility::HorizontalOnly);
+        assert_eq!(c.theme.background, ChartTheme::light().background);
+        assert_eq!(c.shared_state.read(cx).theme.grid(), GridVisibility::HorizontalOnly);
+    });
+}
+
+#[gpui::test]
+fn test_fit_y_frames_candle_wicks(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, Ohlcv};
+    use gpui_chart::{AxisState, CandlestickPlot, PaneState, Series};
+
+    // The wick of the candle starting at 5 (drawn at 5.5) is in view
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(5.3, 9.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    let candles = (0..10)
+        .map(|i| Ohlcv {
+            time: i as f64,
+            span: 1.0,
+            open: 10.0,
+            high: if i == 5 { 50.0 } else { 12.0 },
+            low: if i == 5 { 1.0 } else { 8.0 },
+            close: 11.0,
+            volume: 0.0,
+        })
+        .collect();
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        pane.y_axes.push(AxisState::new(
+            cx.new(|_| AxisRange::new(0.0, 1.0)),
+            AxisEdge::Right,
+            px(60.0),
+            "Y".into(),
+        ));
+        pane.series.push(Series::new("ohlc", CandlestickPlot::new(candles)));
+        c.panes.push(pane);
+        c.fit_y_to_visible_x(cx);
+    });
+
+    chart_entity.read_with(cx, |c, cx| {
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        // High and low reached, well beyond the closes
+        assert!(y.max >= 50.0 && y.min <= 1.0, "{} {}", y.min, y.max);
+    });
+}
+
+#[gpui::test]
+fn test_compute_autofit_bounds_previews_fit(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, ColorOp, PlotPoint};
+    use gpui_chart::{AutoFitBounds, AxisState, LinePlot, PaneState, Series};
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(40.0, 60.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        let mut pane = PaneState::new("p".into(), 1.0);
+        let mut y = AxisRange::new(0.0, 1.0);
+        y.set_limits(Some(-5.0), None);
+        pane.y_axes.push(AxisState::new(cx.new(|_| y), AxisEdge::Right, px(60.0), "Y".into()));
+        let points = (0..=100)
+            .map(|i| PlotPoint {
+                x: i as f64,
+                y: i as f64,
+                color_op: ColorOp::None,
+            })
+            .collect();
+        pane.series.push(Series::new("s", LinePlot::new(points)));
+        c.panes.push(pane);
+        c.set_autofit_padding(0.1, cx);
+    });
+
+    let preview = chart_entity.read_with(cx, |c, cx| c.compute_autofit_bounds(cx));
+    // Padded, and shifted to respect the Y limit
+    assert_eq!(
+        preview,
+        AutoFitBounds {
+            x: Some((-10.0, 110.0)),
+            pane_x: vec![],
+            y: vec![(0, 0, (-5.0, 115.0))],
+        }
+    );
+    // Nothing moved yet
+    chart_entity.read_with(cx, |c, cx| {
+        let x = c.shared_x_axis.read(cx);
+        assert_eq!((x.min, x.max), (40.0, 60.0));
+    });
+
+    chart_entity.update(cx, |c, cx| c.apply_autofit_bounds(&preview, cx));
+    chart_entity.read_with(cx, |c, cx| {
+        let x = c.shared_x_axis.read(cx);
+        assert_eq!((x.min, x.max), (-10.0, 110.0));
+        let y = c.panes[0].y_axes[0].entity.read(cx);
+        assert_eq!((y.min, y.max), (-5.0, 115.0));
+    });
+
+    // Same result as the fit itself
+    chart_entity.update(cx, |c, cx| {
+        c.shared_x_axis.update(cx, |x, _| x.pan(30.0));
+        c.fit_to_data(cx);
+    });
+    let after = chart_entity.read_with(cx, |c, cx| c.compute_autofit_bounds(cx));
+    assert_eq!(after, preview);
+}
+
+#[gpui::test]
+fn test_overview_x_axis_stacks_under_fine_axis(cx: &mut TestAppContext) {
+    use gpui_chart::data_types::{AxisEdge, AxisFormat, TickCount};
+    use gpui_chart::AxisState;
+
+    let chart_entity = cx.update(|cx| {
+        let shared_x = cx.new(|_| AxisRange::new(0.0, 100.0));
+        let shared_state = cx.new(|_| SharedPlotState::default());
+        cx.new(|cx| Chart::new(shared_x, shared_state, cx))
+    });
+    chart_entity.update(cx, |c, cx| {
+        c.x_axes.push(AxisState::new(
+            c.shared_x_axis.clone(),
+            AxisEdge::Bottom,
+            px(25.0),
+            "X".into(),
+        ));
+        c.add_pane_at(0, 1.0, cx);
+    });
+    let window = cx.add_window(|_window, cx| ChartView::new(chart_entity.clone(), cx));
+    cx.run_until_parked();
+
+    // Lowest row of the pane in its middle column
+    let pane_bottom = |cx: &mut TestAppContext| {
+        window
+            .update(cx, |view, window, cx| {
+                let size = window.viewport_size();
+                let x = size.width / 2.0;
+                (0..f32::from(size.height) as usize)
+                    .map(|y| y as f32 + 0.5)
+                    .filter(|&y| view.probe(Point::new(x, px(y)), cx).is_some())
+                    .fold(0.0f32, f32::max)
+            })
+            .unwrap()
+    };
+    let before = pane_bottom(cx);
+
+    let idx = chart_entity.update(cx, |c, cx| {
+        c.add_overview_x_axis(AxisEdge::Bottom, AxisFormat::Numeric, TickCount::Fixed(2), cx)
+    });
+    cx.run_until_parked();
+    assert_eq!(idx, 1);
+    chart_entity.read_with(cx, |c, _| {
+        let overview = &c.x_axes[1];
+        assert_eq!(overview.entity.entity_id(), c.shared_x_axis.entity_id());
+        assert_eq!(overview.tick_count, TickCount::Fixed(2));
+        assert_eq!(overview.edge, AxisEdge::Bottom);
+    });
+    // The pane makes room for both axes
+    assert_eq!(before - pane_bottom(cx), 20.0);
+}
@@ -0,0 +1,127 @@
+use gpui_chart::data_types::{
+    ColorOp, Ohlcv, PlotData, PlotDataSource, PlotPoint, StepMode, StreamingDataSource,
+};
+use gpui_chart::plot_types::PlotRenderer;
+use gpui_chart::{CandlestickPlot, LinePlot, StepLinePlot};
+
+fn pt(x: f64, y: f64) -> PlotPoint {
+    PlotPoint {
+        x,
+        y,
+        color_op: ColorOp::None,
+    }
+}
+
+#[test]
+fn test_line_value_at_interpolates() {
+    let plot = LinePlot::new(vec![pt(0.0, 0.0), pt(10.0, 100.0), pt(20.0, 50.0)]);
+
+    assert_eq!(plot.value_at(10.0), Some(100.0));
+    assert_eq!(plot.value_at(5.0), Some(50.0));
+    assert_eq!(plot.value_at(15.0), Some(75.0));
+    assert_eq!(plot.value_at(-1.0), None);
+    assert_eq!(plot.value_at(21.0), None);
+}
+
+#[test]
+fn test_value_at_on_streaming_source() {
+    // The streaming source does not pad `iter_range` with neighbors
+    let mut source = StreamingDataSource::new(16);
+    for i in 0..5 {
+        source.add_data(PlotData::Point(pt(i as f64 * 2.0, i as f64 * 10.0)));
+    }
+    let plot = LinePlot::with_source(Box::new(source));
+
+    assert_eq!(plot.value_at(3.0), Some(15.0));
+    assert_eq!(plot.value_at(8.0), Some(40.0));
+    assert_eq!(plot.value_at(9.0), None);
+}
+
+#[test]
+fn test_candlestick_value_at_uses_close() {
+    let candle = |time: f64, close: f64| Ohlcv {
+        time,
+        span: 1.0,
+        open: 0.0,
+        high: 200.0,
+        low: -200.0,
+        close,
+        volume: 0.0,
+    };
+    let plot = CandlestickPlot::new(vec![candle(0.0, 10.0), candle(1.0, 20.0)]);
+
+    assert_eq!(plot.value_at(0.0), Some(10.0));
+    assert_eq!(plot.value_at(0.5), Some(15.0));
+}
+
+#[test]
+fn test_step_line_value_at_follows_step_geometry() {
+    let data = vec![pt(0.0, 1.0), pt(10.0, 5.0), pt(20.0, 3.0)];
+    let step = |mode: StepMode| {
+        let mut plot = StepLinePlot::new(data.clone());
+        plot.config.mode = mode;
+        plot
+    };
+
+    // Post: horizontal at the previous value, vertical at the next point
+    let post = step(StepMode::Post);
+    assert_eq!(post.value_at(4.0), Some(1.0));
+    assert_eq!(post.value_at(9.9), Some(1.0));
+    assert_eq!(post.value_at(10.0), Some(5.0));
+    assert_eq!(post.value_at(15.0), Some(5.0));
+
+    // Pre: vertical at the previous point, then horizontal at the next value
+    let pre = step(StepMode::Pre);
+    assert_eq!(pre.value_at(0.0), Some(1.0));
+    assert_eq!(pre.value_at(0.1), Some(5.0));
+    assert_eq!(pre.value_at(15.0), Some(3.0));
+
+    // Mid: switches halfway between points
+    let mid = step(StepMode::Mid);
+    assert_eq!(mid.value_at(4.9), Some(1.0));
+    assert_eq!(mid.value_at(5.0), Some(5.0));
+    assert_eq!(mid.value_at(16.0), Some(3.0));
+
+    assert_eq!(post.value_at(25.0), None);
+}
+
+#[test]
+fn test_ohlcv_at_finds_covering_candle() {
+    let candle = |time: f64, close: f64| Ohlcv {
+        time,
+        span: 10.0,
+        open: close - 1.0,
+        high: close + 2.0,
+        low: close - 2.0,
+        close,
+        volume: 100.0,
+    };
+    let plot = CandlestickPlot::new(vec![candle(0.0, 10.0), candle(10.0, 20.0), candle(30.0, 40.0)]);
+
+    assert_eq!(plot.ohlcv_at(0.0).map(|c| c.close), Some(10.0));
+    assert_eq!(plot.ohlcv_at(9.9).map(|c| c.close), Some(10.0));
+    assert_eq!(plot.ohlcv_at(10.0).map(|c| c.close), Some(20.0));
+    assert_eq!(plot.ohlcv_at(35.0).map(|c| c.high), Some(42.0));
+    // Between candles, before and after the data
+    assert!(plot.ohlcv_at(25.0).is_none());
+    assert!(plot.ohlcv_at(-1.0).is_none());
+    assert!(plot.ohlcv_at(40.0).is_none());
+
+    // Line series have no candles
+    assert!(LinePlot::new(vec![pt(0.0, 1.0), pt(1.0, 2.0)]).ohlcv_at(0.5).is_none());
+}
+
+#[test]
+fn test_series_readout_format_overrides_axis_format() {
+    use gpui_chart::Series;
+
+    let axis_format = |v: f64| format!("{v:.1}");
+    let plain = Series::new("a", LinePlot::new(vec![pt(0.0, 1.0)]));
+    assert_eq!(plain.format_readout(1.23456, axis_format), "1.2");
+
+    let precise = Series::new("b", LinePlot::new(vec![pt(0.0, 1.0)]))
+        .with_readout_format(|v| format!("{v:.4} V"));
+    assert_eq!(precise.format_readout(1.23456, axis_format), "1.2346 V");
+    // Clones share the formatter
+    assert_eq!(precise.clone().format_readout(2.0, axis_format), "2.0000 V");
+}
@@ -21,6 +21,19 @@ fn test_pan_axis_y() {
     assert_eq!(range.max, 110.0);
 }
 
+#[test]
+fn test_pan_axis_ignores_limits_when_clamp_disabled() {
+    let mut range = AxisRange::new(0.0, 100.0);
+    range.max_limit = Some(100.0);
+    range.clamp_enabled = false;
+    ViewController::pan_axis(&mut range, -20.0, 100.0, false, None);
+    assert_eq!((range.min, range.max), (20.0, 120.0));
+
+    range.clamp_enabled = true;
+    ViewController::pan_axis(&mut range, -20.0, 100.0, false, None);
+    assert_eq!((range.min, range.max), (0.0, 100.0));
+}
+
 #[test]
 fn test_zoom_axis_at() {
     let mut range = AxisRange::new(0.0, 100.0);
@@ -30,6 +43,33 @@ fn test_zoom_axis_at() {
     assert_eq!(range.max, 75.0);
 }
 
+#[test]
+fn test_axis_and_pane_drag_zoom_share_pivot() {
+    // A Y axis gutter spans the same rows as its pane: y in [50, 250]
+    let (origin, height) = (50.0, 200.0);
+    let cursor_y = 100.0;
+    let factor = ViewController::compute_zoom_factor(30.0, 100.0);
+
+    let mut by_axis = AxisRange::new(0.0, 100.0);
+    let axis_pivot = ViewController::zoom_pivot_pct(cursor_y, origin, height, true);
+    ViewController::zoom_axis_at(&mut by_axis, axis_pivot, factor, None);
+
+    let mut by_pane = AxisRange::new(0.0, 100.0);
+    let pane_pivot = ViewController::zoom_pivot_pct(cursor_y, origin, height, true);
+    ViewController::zoom_axis_at(&mut by_pane, pane_pivot, factor, None);
+
+    assert_eq!((by_axis.min, by_axis.max), (by_pane.min, by_pane.max));
+    // The value under the cursor (a quarter from the top: 75) stays put
+    assert_eq!(axis_pivot, 0.75);
+    let under_cursor = by_axis.min + by_axis.span() * axis_pivot;
+    assert!((under_cursor - 75.0).abs() < 1e-9, "{under_cursor}");
+
+    // Clamped to the axis, X not flipped
+    assert_eq!(ViewController::zoom_pivot_pct(-10.0, 0.0, 100.0, false), 0.0);
+    assert_eq!(ViewController::zoom_pivot_pct(25.0, 0.0, 100.0, false), 0.25);
+    assert_eq!(ViewController::zoom_pivot_pct(500.0, 0.0, 100.0, true), 0.0);
+}
+
 #[test]
 fn test_resize_panes() {
     let mut weights = vec![1.0, 1.0];
@@ -56,6 +96,35 @@ fn test_compute_auto_fit() {
     assert_eq!(max, 21.0);
 }
 
+#[test]
+fn test_auto_fit_widens_degenerate_domain() {
+    // Flat Y series around 5.0, even without margin
+    let mut y = AxisRange::new(0.0, 1.0);
+    ViewController::auto_fit_axis(&mut y, 5.0, 5.0, 0.0);
+    assert!(y.span() > 0.0);
+    assert_eq!((y.min + y.max) / 2.0, 5.0);
+
+    // Flat series at zero
+    let (min, max) = ViewController::compute_auto_fit(0.0, 0.0, 0.05);
+    assert!(min < 0.0 && max > 0.0);
+    assert_eq!(min, -max);
+
+    // Single point on a millisecond timestamp X axis: the span must survive
+    // f64 precision at that magnitude
+    let t = 1.7e12;
+    let mut x = AxisRange::new(0.0, 1.0);
+    ViewController::auto_fit_axis(&mut x, t, t, 0.05);
+    assert!(x.min < t && x.max > t);
+    assert!(((x.min + x.max) / 2.0 - t).abs() < 1e-3);
+
+    // Nothing to fit
+    assert_eq!(
+        ViewController::compute_auto_fit(f64::INFINITY, f64::NEG_INFINITY, 0.05),
+        (0.0, 100.0)
+    );
+    assert_eq!(ViewController::compute_auto_fit(f64::NAN, 1.0, 0.05), (0.0, 100.0));
+}
+
 #[test]
 fn test_move_to_center() {
     let mut range = AxisRange::new(40.0, 60.0); // span 20
@@ -72,3 +141,130 @@ fn test_move_to_center_clamped() {
     assert_eq!(range.min, 0.0);
     assert_eq!(range.max, 20.0);
 }
+
+#[test]
+fn test_zoom_out_snaps_to_data_extent() {
+    let mut range = AxisRange::new(40.0, 60.0);
+    range.data_extent = Some((0.0, 100.0));
+
+    // Zooming out within the extent is unaffected
+    ViewController::zoom_axis_at(&mut range, 0.5, 2.0, None);
+    assert_eq!((range.min, range.max), (30.0, 70.0));
+
+    // Zooming out past the extent stops at "all data visible"
+    ViewController::zoom_axis_at(&mut range, 0.9, 10.0, None);
+    assert_eq!((range.min, range.max), (0.0, 100.0));
+
+    // Without an extent zoom is free
+    range.data_extent = None;
+    ViewController::zoom_axis_at(&mut range, 0.5, 2.0, None);
+    assert_eq!((range.min, range.max), (-50.0, 150.0));
+}
+
+#[test]
+fn test_zoom_config_wheel_factor() {
+    use gpui_chart::data_types::ZoomConfig;
+
+    let default = ZoomConfig::default();
+    assert!((default.wheel_factor(10.0) - 0.9).abs() < 1e-9);
+    assert!((default.wheel_factor(-10.0) - 1.1).abs() < 1e-9);
+    assert_eq!(default.wheel_factor(500.0), 0.1);
+
+    let gentle = ZoomConfig {
+        sensitivity: 0.001,
+        min_factor: 0.5,
+        max_factor: 2.0,
+    };
+    assert!((gentle.wheel_factor(10.0) - 0.99).abs() < 1e-9);
+    assert_eq!(gentle.wheel_factor(1_000.0), 0.5);
+    assert_eq!(gentle.wheel_factor(-5_000.0), 2.0);
+}
+
+#[test]
+fn test_clamp_velocity_keeps_direction() {
+    assert_eq!(ViewController::clamp_velocity(300.0, -400.0, 1000.0), (300.0, -400.0));
+    let (vx, vy) = ViewController::clamp_velocity(3000.0, -4000.0, 1000.0);
+    assert!((vx - 600.0).abs() < 1e-9 && (vy + 800.0).abs() < 1e-9);
+    assert_eq!(ViewController::clamp_velocity(0.0, 0.0, 0.0), (0.0, 0.0));
+}
+
+#[test]
+fn test_pan_clamped_reports_walls() {
+    let mut range = AxisRange::new(0.0, 10.0);
+    range.min_limit = Some(0.0);
+    range.max_limit = Some(100.0);
+
+    assert!(ViewController::pan_clamped(&mut range, 5.0));
+    assert_eq!((range.min, range.max), (5.0, 15.0));
+
+    // Stopped short by the max limit
+    assert!(!ViewController::pan_clamped(&mut range, 200.0));
+    assert_eq!((range.min, range.max), (90.0, 100.0));
+
+    range.clamp_enabled = false;
+    assert!(ViewController::pan_clamped(&mut range, 50.0));
+}
+
+#[test]
+fn test_pane_fractions() {
+    assert_eq!(ViewController::pane_fractions(&[2.0, 1.0, 1.0]), vec![0.5, 0.25, 0.25]);
+    assert_eq!(ViewController::pane_fractions(&[0.0, 0.0]), vec![0.5, 0.5]);
+    assert!(ViewController::pane_fractions(&[]).is_empty());
+}
+
+#[test]
+fn test_value_to_offset_px() {
+    assert_eq!(ViewController::value_to_offset_px(0.0, (-50.0, 50.0), 200.0), Some(100.0));
+    assert_eq!(ViewController::value_to_offset_px(50.0, (-50.0, 50.0), 200.0), Some(0.0));
+    assert_eq!(ViewController::value_to_offset_px(0.0, (10.0, 50.0), 200.0), None);
+    assert_eq!(ViewController::value_to_offset_px(0.0, (0.0, 0.0), 200.0), None);
+}
+
+#[test]
+fn test_pane_heights_with_fixed_panes() {
+    let heights = ViewController::pane_heights(&[2.0, 1.0, 5.0], &[None, None, Some(120.0)], 420.0);
+    assert_eq!(heights, vec![200.0, 100.0, 120.0]);
+    // Fixed panes larger than the chart are scaled down, weighted ones get nothing
+    let heights = ViewController::pane_heights(&[1.0, 1.0], &[Some(300.0), Some(100.0)], 200.0);
+    assert_eq!(heights, vec![150.0, 50.0]);
+    // Only fixed panes: the rest stays empty
+    assert_eq!(
+        ViewController::pane_heights(&[1.0], &[Some(50.0)], 400.0),
+        vec![50.0]
+    );
+    // No fixed panes: same split as the weights
+    assert_eq!(
+        ViewController::pane_heights(&[1.0, 3.0], &[None, None], 400.0),
+        vec![100.0, 300.0]
+    );
+}
+
+#[test]
+fn test_resize_mixed_panes() {
+    // Splitter above a fixed pane moves it in pixels, weights are untouched
+    let mut weights = vec![1.0, 1.0];
+    let mut fixed = vec![None, Some(120.0)];
+    ViewController::resize_mixed_panes(&mut weights, &mut fixed, 0, -30.0, 400.0);
+    assert_eq!(fixed, vec![None, Some(150.0)]);
+    assert_eq!(weights, vec![1.0, 1.0]);
+
+    // The weighted neighbor keeps a minimum height
+    ViewController::resize_mixed_panes(&mut weights, &mut fixed, 0, -1000.0, 400.0);
+    assert_eq!(
+        fixed[1],
+        Some(400.0 - gpui_chart::view_controller::MIN_PANE_HEIGHT)
+    );
+
+    // Two weighted panes share the height left by the fixed one
+    let mut weights = vec![1.0, 1.0, 1.0];
+    let mut fixed = vec![None, None, Some(200.0)];
+    ViewController::resize_mixed_panes(&mut weights, &mut fixed, 0, 50.0, 400.0);
+    assert_eq!(weights, vec![1.5, 0.5, 1.0]);
+    assert_eq!(fixed[2], Some(200.0));
+
+    // Two fixed panes trade pixels
+    let mut weights = vec![1.0, 1.0];
+    let mut fixed = vec![Some(100.0), Some(100.0)];
+    ViewController::resize_mixed_panes(&mut weights, &mut fixed, 0, 30.0, 400.0);
+    assert_eq!(fixed, vec![Some(130.0), Some(70.0)]);
+}